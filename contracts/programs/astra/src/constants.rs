@@ -16,20 +16,30 @@
 /// At $200/SOL = ~210 SOL, at $400/SOL = ~105 SOL
 pub const GRADUATION_MARKET_CAP_USD: u64 = 42_000;
 
-/// Minimum seed amount in USD ($40)
+/// Fixed-point scale for USD amounts (cents)
+/// WHY: USD inputs (seed bounds, presets, buy floor) need sub-dollar precision,
+/// e.g. a $2.50 preset. Market cap values stay in whole dollars.
+pub const USD_CENTS_PER_DOLLAR: u64 = 100;
+
+/// Minimum seed amount in USD cents ($40)
 /// WHY: Accessible entry point for creators, adjusted for SOL price
 /// At $200/SOL = 0.2 SOL, at $400/SOL = 0.1 SOL
-pub const MIN_SEED_USD: u64 = 40;
+pub const MIN_SEED_USD_CENTS: u64 = 40 * USD_CENTS_PER_DOLLAR;
 
-/// Maximum seed amount in USD ($20,000)
+/// Maximum seed amount in USD cents ($20,000)
 /// WHY: Prevents creators from dominating launches while allowing meaningful investment
 /// At $200/SOL = 100 SOL, at $400/SOL = 50 SOL
-pub const MAX_SEED_USD: u64 = 20_000;
+pub const MAX_SEED_USD_CENTS: u64 = 20_000 * USD_CENTS_PER_DOLLAR;
+
+/// Widest SOL price confidence (bps of price) at which create_launch prices seed bounds
+/// WHY: A wide Pyth interval means the cached price may be far off, misplacing
+/// the USD seed bounds; creators wait for the market to settle instead
+pub const MAX_SEED_PRICE_CONFIDENCE_BPS: u64 = 100; // 1%
 
-/// Buy preset amounts in USD (for frontend buttons)
+/// Buy preset amounts in USD cents (for frontend buttons)
 /// WHY: Users think in USD, not SOL. Common purchase amounts.
 /// These are converted to SOL at current price for transactions.
-pub const BUY_PRESETS_USD: [u64; 6] = [5, 10, 25, 50, 100, 250];
+pub const BUY_PRESETS_USD_CENTS: [u64; 6] = [500, 1_000, 2_500, 5_000, 10_000, 25_000];
 
 // ============================================================================
 // TOKEN SUPPLY (NO SHARE CAP - DYNAMIC ISSUANCE)
@@ -50,9 +60,17 @@ pub const TOKENS_FOR_LP: u64 = 200_000_000;
 /// WHY: Standard memecoin supply. 800M to holders + 200M to LP = 1B total.
 pub const TOTAL_SUPPLY: u64 = 1_000_000_000;
 
+/// SPL token decimals for graduated token mints
+pub const TOKEN_DECIMALS: u8 = 9;
+
+/// Base units per whole token (10^TOKEN_DECIMALS)
+/// WHY: Token scaling is distinct from LAMPORTS_PER_SOL even though both are
+/// 10^9 today - keep them separate so changing decimals can't skew SOL math
+pub const TOKEN_DECIMALS_SCALE: u64 = 10u64.pow(TOKEN_DECIMALS as u32);
+
 /// Total supply with decimals (1B * 10^9)
 /// Used for minting calculations
-pub const TOTAL_SUPPLY_WITH_DECIMALS: u64 = 1_000_000_000_000_000_000;
+pub const TOTAL_SUPPLY_WITH_DECIMALS: u64 = TOTAL_SUPPLY * TOKEN_DECIMALS_SCALE;
 
 // ============================================================================
 // FEES
@@ -71,11 +89,26 @@ pub const CREATOR_FEE_UNVERIFIED_BPS: u64 = 30; // 0.3%
 /// WHY: Reward for successful track record, still leaves protocol sustainable
 pub const CREATOR_FEE_VERIFIED_BPS: u64 = 50; // 0.5%
 
+/// Bond required for fast-track verification (10 SOL)
+/// WHY: Skin in the game that replaces a graduation track record; large
+/// enough that slashing outweighs the extra 0.2% on a rugged launch
+pub const MIN_VERIFICATION_BOND_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+/// Lock after each stake before a verification bond can be withdrawn (49 days)
+/// WHY: Outlasts a launch (7 days) plus creator vesting (42 days), so the bond
+/// is still slashable for the whole life of a launch started while bonded
+pub const CREATOR_BOND_LOCK_SECONDS: i64 = LAUNCH_DURATION_SECONDS + VESTING_DURATION_SECONDS;
+
 /// Fee on sell transactions (0%)
 /// WHY: Core promise of the protocol - free exits prevent rug dynamics
 /// Users can always exit at their proportional basis without penalty
+/// Default for GlobalConfig.sell_fee_bps - deployments may opt in up to MAX_SELL_FEE_BPS
 pub const SELL_FEE_BPS: u64 = 0;
 
+/// Maximum configurable sell fee (3%)
+/// WHY: Operators may discourage churn, but exits must stay cheap
+pub const MAX_SELL_FEE_BPS: u64 = 300; // 3.0%
+
 // ============================================================================
 // TIME WINDOWS
 // ============================================================================
@@ -85,11 +118,69 @@ pub const SELL_FEE_BPS: u64 = 0;
 /// 42 days = ~6 weeks, long enough to prove commitment
 pub const VESTING_DURATION_SECONDS: i64 = 42 * 24 * 60 * 60; // 3,628,800 seconds
 
+/// Minimum vesting claim as bps of the creator seed (1% = ~10 hours of linear vesting)
+/// WHY: Stops per-second dust claims spamming events and churning state
+/// The claim that empties the remaining seed is always allowed
+pub const MIN_VESTING_CLAIM_BPS: u64 = 100; // 1%
+
 /// Launch duration before refund mode can be enabled (7 days)
 /// WHY: Gives launches fair time to reach graduation
 /// After 7 days, if not graduated, users can get refunds
 pub const LAUNCH_DURATION_SECONDS: i64 = 7 * 24 * 60 * 60; // 604,800 seconds
 
+/// Maximum creator trading cooldown a launch can opt into (7 days)
+/// WHY: Creators may lock their own buy/sell activity early in a launch to
+/// signal they won't front-run it; capped at the launch duration
+pub const MAX_CREATOR_TRADE_COOLDOWN_SECONDS: i64 = LAUNCH_DURATION_SECONDS;
+
+/// Maximum delay between creation and the first public buy (1 hour)
+/// WHY: Lets indexers and UIs pick up a new launch before snipers can buy,
+/// without eating meaningfully into the launch duration
+pub const MAX_TRADING_START_DELAY_SECONDS: i64 = 60 * 60;
+
+/// Maximum fee-free first buyers a launch can opt into
+/// WHY: Bootstrapping incentive for early participants; capped so a launch
+/// can't run fee-free for its entire life
+pub const MAX_FREE_FEE_BUYERS: u64 = 100;
+
+/// Largest first buy a fee-free slot waives fees on (1 SOL)
+/// WHY: The waiver is a small-buyer incentive; without a cap one whale taking
+/// a slot would skip fees on a buy of any size. The excess pays normal fees
+pub const FEE_FREE_BUY_CAP_LAMPORTS: u64 = 1_000_000_000;
+
+/// Maximum share of the minted supply a launch can burn at graduation (50%)
+/// WHY: Room for a deflationary launch while the holder pool and LP stay
+/// the bulk of the supply
+pub const MAX_BURN_BPS: u64 = 5_000;
+
+/// Post-creation metadata (name/symbol/uri) updates allowed per launch
+/// WHY: Enough to fix a typo or a dead URI, too few to keep baiting buyers
+/// with renames
+pub const MAX_METADATA_UPDATES: u8 = 3;
+
+/// Largest metadata fetch a launch may commit to, and the client default (1 MiB)
+/// WHY: Token metadata JSON is a few KB; anything bigger is a fetch bomb
+pub const MAX_METADATA_FETCH_BYTES: u32 = 1024 * 1024;
+
+/// Rolling volume window for graduation ETA (1 hour)
+/// WHY: Lets clients extrapolate time-to-graduation from recent buy volume
+pub const VOLUME_WINDOW_SECONDS: i64 = 60 * 60; // 3,600 seconds
+
+/// Net-flow window for the sell-pressure circuit breaker (1 hour)
+/// WHY: Long enough to catch a coordinated dump, short enough that
+/// ordinary churn over a day never accumulates into a false trip
+pub const SELL_PRESSURE_WINDOW_SECONDS: i64 = 60 * 60; // 3,600 seconds
+
+/// Grace period after graduation where only the holder can claim tokens (7 days)
+/// WHY: Holders keep control of their own claim (and position rent) at first.
+/// After the grace period, janitors may claim-and-close on their behalf
+pub const THIRD_PARTY_CLAIM_GRACE_SECONDS: i64 = 7 * 24 * 60 * 60; // 604,800 seconds
+
+/// Delay between propose_authority and accept_authority (48 hours)
+/// WHY: Gives the community time to notice and react to an authority change
+/// (e.g. after a key compromise) while the old authority can still cancel it
+pub const AUTHORITY_CHANGE_DELAY_SECONDS: i64 = 48 * 60 * 60; // 172,800 seconds
+
 // ============================================================================
 // TRANSACTION LIMITS
 // ============================================================================
@@ -99,6 +190,27 @@ pub const LAUNCH_DURATION_SECONDS: i64 = 7 * 24 * 60 * 60; // 604,800 seconds
 /// Large buyers must spread across multiple transactions
 pub const MAX_BUY_LAMPORTS: u64 = 1_000_000_000_000; // 1000 SOL
 
+/// Minimum first boost that claims a launch's booster slot (1 SOL)
+/// WHY: The slot is single and first-come; a floor makes squatting it cost
+/// real capital instead of 1 lamport. Top-ups by the same booster are free-sized
+pub const MIN_BOOST_LAMPORTS: u64 = 1_000_000_000;
+
+/// Minimum buy amount in USD cents ($1)
+/// WHY: Micro-buys that round to zero shares would only pay fees.
+/// Enforced when a SOL price is available
+pub const MIN_BUY_USD_CENTS: u64 = USD_CENTS_PER_DOLLAR;
+
+/// Default maturity-scaled buy cap (50% of current pool per transaction)
+/// WHY: A flat 1000 SOL cap lets a whale dominate a tiny early launch.
+/// Per-tx max = min(MAX_BUY_LAMPORTS, total_sol * bps / 10000), loosening as
+/// the pool grows (never below MIN_BUY_CAP_LAMPORTS). Stored in GlobalConfig.max_buy_pool_bps (0 = flat cap only)
+pub const DEFAULT_MAX_BUY_POOL_BPS: u64 = 5000; // 50%
+
+/// Floor on the maturity-scaled buy cap (0.1 SOL)
+/// WHY: A share of an empty (or near-empty) pool is 0, which would reject
+/// every buy with BuyTooLarge. The floor always admits a first buy
+pub const MIN_BUY_CAP_LAMPORTS: u64 = 100_000_000;
+
 /// Maximum buy amount in USD (for reference, ~$200K at $200/SOL)
 /// WHY: Documenting the USD equivalent for clarity
 pub const MAX_BUY_USD: u64 = 200_000;
@@ -109,15 +221,45 @@ pub const MAX_BUY_USD: u64 = 200_000;
 
 /// Minimum holder count for graduation
 /// WHY: Ensures distribution, prevents single-actor graduations
-/// ENFORCED: Off-chain by cron job
+/// ENFORCED: On-chain in `graduate` via GlobalConfig.min_holders_for_graduation
+/// (this is the default); `force_graduate` bypasses it
 pub const GRADUATION_MIN_HOLDERS: u64 = 100;
 
 /// Maximum concentration allowed for graduation (10%)
 /// WHY: Prevents whale-dominated graduations
-/// No single address can hold more than 10% of shares
-/// ENFORCED: Off-chain by cron job
+/// No single buyer can hold more than 10% of shares (the creator has its own cap)
+/// ENFORCED: On-chain in `graduate` via Launch.largest_holder_shares (a
+/// high-water mark, or the proven maximum over every position);
+/// `force_graduate` bypasses it
 pub const GRADUATION_MAX_CONCENTRATION_BPS: u64 = 1000; // 10%
 
+/// Default cap on the creator's share of supply at graduation (20%)
+/// WHY: Anti-rug - the creator's seed plus bought shares can't dominate the token
+/// ENFORCED: On-chain in `graduate` via GlobalConfig.max_creator_concentration_bps
+/// (0 = disabled); `force_graduate` bypasses it
+pub const DEFAULT_MAX_CREATOR_CONCENTRATION_BPS: u64 = 2000; // 20%
+
+/// Use fully-diluted market cap (spot price × total shares) for graduation
+/// WHY: SOL raised understates the curve's implied token value. On the
+/// quadratic curve, FDV is ~2× SOL raised. Off by default so the $42K target
+/// keeps meaning "SOL raised in USD"
+pub const GRADUATION_USES_FULLY_DILUTED_MARKET_CAP: bool = false;
+
+/// How long lock_graduation freezes trading before graduate must land (5 minutes)
+/// WHY: Gives the operator time to land graduate after the lock confirms, while
+/// a failed or abandoned graduation only pauses trading briefly
+pub const GRADUATION_LOCK_SECONDS: i64 = 5 * 60; // 300 seconds
+
+/// Wait after a graduation lock lapses before the next lock (30 minutes)
+/// WHY: Without it the operator could re-lock back to back and freeze buys
+/// and sells indefinitely; this caps a launch's frozen time at 5 of every 35 minutes
+pub const GRADUATION_RELOCK_COOLDOWN_SECONDS: i64 = 30 * 60; // 1800 seconds
+
+/// Fixed-point scale for GraduationRecord.initial_price (1e9)
+/// WHY: Opening prices are a few hundred lamports per token; the scale keeps
+/// sub-lamport precision for analytics and dispute resolution
+pub const GRADUATION_PRICE_SCALE: u128 = 1_000_000_000;
+
 /// Market cap threshold notification trigger (95% of target)
 /// WHY: Alert frontend/cron that graduation is approaching
 pub const GRADUATION_THRESHOLD_NOTIFICATION_BPS: u64 = 9500; // 95%
@@ -129,22 +271,65 @@ pub const GRADUATION_THRESHOLD_NOTIFICATION_BPS: u64 = 9500; // 95%
 /// Basis points denominator (100% = 10000 bps)
 pub const BPS_DENOMINATOR: u64 = 10_000;
 
+// ============================================================================
+// UNIT SCALING
+// ============================================================================
+
+/// Lamports per SOL (10^9)
+/// Used for all SOL <-> USD conversions
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
 // ============================================================================
 // PRICE ORACLE CONFIGURATION
 // ============================================================================
 
+/// Maximum number of allowlisted Raydium AMM configs
+/// WHY: Bounds GlobalConfig size; Raydium only has a handful of fee tiers
+pub const MAX_AMM_CONFIGS: usize = 8;
+
+/// Maximum number of allowlisted holder distributors
+/// WHY: Bounds GlobalConfig size; only a few audited Merkle-drop deployments are expected
+pub const MAX_DISTRIBUTORS: usize = 4;
+
+/// Maximum number of graduation operators in the multisig set
+/// WHY: Bounds GlobalConfig size and the signer accounts graduate must scan
+pub const MAX_GRADUATION_OPERATORS: usize = 5;
+
+/// Maximum number of price feeds stored on GlobalConfig
+/// WHY: Bounds GlobalConfig size; only SOL is priced today
+pub const MAX_PRICE_FEEDS: usize = 4;
+
+/// Launch keys per CreatorLaunches index page
+/// WHY: 32 keys (~1 KB) keeps page rent small; prolific creators just add pages
+pub const CREATOR_LAUNCHES_PER_PAGE: usize = 32;
+
+/// Price feed key for SOL (the wrapped SOL mint)
+/// WHY: Feeds are keyed by asset mint; this one mirrors into sol_price_usd
+pub const SOL_PRICE_FEED_ASSET: anchor_lang::prelude::Pubkey =
+    anchor_lang::pubkey!("So11111111111111111111111111111111111111112");
+
 /// Pyth price feed ID for SOL/USD on Solana mainnet
 /// WHY: Primary price source for USD conversions
 pub const PYTH_SOL_USD_FEED: &str = "H6ARHf6YXhGYeQfUzQNGk6rDNnLBQKrenN712K4AQJEG";
 
+/// Pyth SOL/USD feed ID for pull-oracle (PriceUpdateV2) accounts
+/// WHY: update_price_pyth only accepts a verified update for this feed
+pub const PYTH_SOL_USD_FEED_ID: &str =
+    "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
+
 /// Maximum acceptable price staleness (5 minutes)
 /// WHY: Protect against using stale prices during volatility
 pub const MAX_PRICE_STALENESS_SECONDS: i64 = 300;
 
 /// Fallback price update interval (1 minute)
 /// WHY: Cache price for fallback when Pyth unavailable
+/// ENFORCED: Minimum spacing between update_price writes per feed
 pub const PRICE_CACHE_UPDATE_INTERVAL_SECONDS: i64 = 60;
 
+/// Price move that bypasses the update interval (2%)
+/// WHY: Throttle oracle spam without lagging genuine volatility
+pub const PRICE_DEVIATION_OVERRIDE_BPS: u64 = 200; // 2%
+
 // ============================================================================
 // BONDING CURVE PARAMETERS
 // ============================================================================
@@ -172,9 +357,33 @@ pub const CURVE_SCALE: u128 = 1_000_000_000_000;
 // The following are intentionally configurable via GlobalConfig because they
 // need operational flexibility while not affecting economic guarantees:
 //
-// - min_seed_lamports: Calculated from MIN_SEED_USD at current SOL price
-// - authority: Admin key, needs rotation capability
-// - operator_wallet: Janitor wallet, needs rotation capability
-// - protocol_fee_wallet: Treasury address, needs update capability
+// - min_seed_lamports: Calculated from MIN_SEED_USD_CENTS at current SOL price
+// - authority: Admin key, rotated via the timelocked propose/accept_authority flow
+// - operator_wallet: Janitor wallet, rotated via update_operator_wallet
+// - graduation_operators/threshold: Optional M-of-N signers for graduate (empty = operator_wallet)
+// - protocol_fee_wallet: Treasury address, rotated via update_protocol_fee_wallet
+// - vault_protocol_wallet: Vault yield treasury, rotated via update_vault_protocol_wallet
 // - paused: Emergency stop, must be toggleable
+// - sell_fee_bps: Opt-in sell fee (default 0, capped at MAX_SELL_FEE_BPS)
+// - verified_total_fee_bps: Optional lower total buy fee for verified creators (0 = off)
+// - allowed_amm_configs: Vetted Raydium fee tiers for graduation pools
+// - allowed_distributors: Vetted holder distributors launches may opt into
+// - min_holders_for_graduation: Distribution gate (default GRADUATION_MIN_HOLDERS)
+// - max_buy_pool_bps: Whale-protection scale, tunable as launches mature
+// - max_creator_concentration_bps: Creator share cap at graduation (0 = disabled)
+// - janitor_rewards_wallet: Optional keeper treasury for janitor rent (None = permissionless)
 // - sol_price_usd: Cached SOL price for USD conversions
+// - price_feeds: Per-asset USD prices (SOL mirrored into sol_price_usd)
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_scaling_constants() {
+        assert_eq!(LAMPORTS_PER_SOL, 1_000_000_000);
+        assert_eq!(TOKEN_DECIMALS_SCALE, 1_000_000_000);
+        assert_eq!(TOTAL_SUPPLY_WITH_DECIMALS, 1_000_000_000_000_000_000);
+        assert_eq!(TOKENS_FOR_HOLDERS + TOKENS_FOR_LP, TOTAL_SUPPLY);
+    }
+}
@@ -113,6 +113,31 @@ pub fn buy_return(sol_amount: u64, current_supply: u64) -> Result<u64> {
     Ok(shares_u64)
 }
 
+/// Calculate the marginal (spot) price of the next share at `current_supply`
+///
+/// Derivative of the buy_quote cost function:
+/// price = CURVE_SLOPE × S_current / CURVE_SCALE
+///
+/// # Arguments
+/// * `current_supply` - Current total shares issued
+///
+/// # Returns
+/// * Price per share in lamports (floor)
+///
+/// # Errors
+/// * `MathOverflow` - If calculation overflows
+pub fn spot_price(current_supply: u64) -> Result<u64> {
+    let price = CURVE_SLOPE
+        .checked_mul(current_supply as u128)
+        .ok_or(AstraError::MathOverflow)?
+        .checked_div(CURVE_SCALE)
+        .ok_or(AstraError::MathOverflow)?;
+
+    let price_u64 = price.try_into().map_err(|_| AstraError::MathOverflow)?;
+
+    Ok(price_u64)
+}
+
 /// Calculate refund amount for selling shares
 ///
 /// Proportional refund based on user's basis:
@@ -157,8 +182,53 @@ pub fn sell_return(shares_to_sell: u64, user_shares: u64, user_basis: u64) -> Re
     Ok(refund_u128 as u64)
 }
 
+/// Supply points exercised by `selftest` (empty curve through post-graduation scale)
+const SELFTEST_SUPPLIES: [u64; 5] = [0, 1_000_000, 100_000_000, 520_000_000, 2_000_000_000];
+
+/// Share amounts bought at each `SELFTEST_SUPPLIES` point
+const SELFTEST_SHARES: [u64; 4] = [1, 1_000, 1_000_000, 250_000_000];
+
+/// Round-trip the curve math over hardcoded points
+///
+/// For each point, pays `buy_quote(shares)` into `buy_return` and requires:
+/// - the round trip never mints more shares than were quoted
+/// - the result is within 1 share, or re-quotes within 1 lamport of the cost
+///   (at low supply one lamport buys many shares, so only the lamport bound holds)
+///
+/// # Errors
+/// * `CurveSelfTestFailed` - If any point is outside tolerance
+/// * `MathOverflow` - If calculation overflows
+pub fn selftest() -> Result<()> {
+    for supply in SELFTEST_SUPPLIES {
+        for shares in SELFTEST_SHARES {
+            let cost = buy_quote(shares, supply)?;
+            let round_trip = buy_return(cost, supply)?;
+            let requote = buy_quote(round_trip, supply)?;
+
+            let within_share = round_trip.abs_diff(shares) <= 1;
+            let within_lamport = requote.abs_diff(cost) <= 1;
+            if round_trip > shares || !(within_share || within_lamport) {
+                msg!(
+                    "curve selftest failed: supply={} shares={} cost={} round_trip={} requote={}",
+                    supply,
+                    shares,
+                    cost,
+                    round_trip,
+                    requote
+                );
+                return Err(AstraError::CurveSelfTestFailed.into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Integer square root using Newton's method with overflow-safe initial guess
 ///
+/// The Newton step averages `x` and `n / x` without forming `x + n / x`,
+/// so no intermediate can overflow even for `n` at the top of the u128 range.
+///
 /// # Arguments
 /// * `n` - Number to find square root of
 ///
@@ -170,12 +240,14 @@ fn integer_sqrt(n: u128) -> u128 {
     }
 
     // Start with a better initial guess using bit manipulation
-    // This prevents overflow when n is close to u128::MAX
-    let shift = (128 - n.leading_zeros() + 1) / 2;
+    // Guess is >= sqrt(n), so the iteration decreases monotonically
+    let shift = (128 - n.leading_zeros()).div_ceil(2);
     let mut x = 1u128 << shift;
 
     loop {
-        let y = (x + n / x) / 2;
+        // Overflow-free floor((x + n / x) / 2)
+        let q = n / x;
+        let y = (x & q) + ((x ^ q) >> 1);
         if y >= x {
             return x;
         }
@@ -199,6 +271,15 @@ mod tests {
         assert_eq!(buy_return(0, 1_000_000).unwrap(), 0);
     }
 
+    #[test]
+    fn test_buy_return_dust_yields_zero_shares() {
+        // Below the spot price, a buy mints nothing and would only pay fees
+        let supply = 500_000_000u64;
+        let spot = spot_price(supply).unwrap();
+        assert_eq!(buy_return(spot - 1, supply).unwrap(), 0);
+        assert!(buy_return(spot + 1, supply).unwrap() > 0);
+    }
+
     #[test]
     fn test_buy_and_sell_symmetry() {
         // Buy 1M shares from 0 supply
@@ -277,6 +358,21 @@ mod tests {
         assert!(refund < new_share_price, "User cannot extract price appreciation");
     }
 
+    #[test]
+    fn test_spot_price_increases_with_supply() {
+        assert_eq!(spot_price(0).unwrap(), 0);
+        assert!(spot_price(500_000_000).unwrap() > spot_price(100_000_000).unwrap());
+    }
+
+    #[test]
+    fn test_spot_price_matches_marginal_cost() {
+        // Cost of one more share should be within a lamport of the spot price
+        let supply = 500_000_000u64;
+        let marginal = buy_quote(1, supply).unwrap();
+        let spot = spot_price(supply).unwrap();
+        assert!(marginal.abs_diff(spot) <= 1);
+    }
+
     #[test]
     fn test_integer_sqrt() {
         assert_eq!(integer_sqrt(0), 0);
@@ -287,4 +383,34 @@ mod tests {
         assert_eq!(integer_sqrt(16), 4);
         assert_eq!(integer_sqrt(1_000_000_000_000), 1_000_000);
     }
+
+    #[test]
+    fn test_integer_sqrt_u128_max() {
+        assert_eq!(integer_sqrt(u128::MAX), u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_integer_sqrt_perfect_squares_near_u128_max() {
+        // k^2 must return exactly k, k^2 - 1 must floor to k - 1
+        let top = u64::MAX as u128;
+        for k in (top - 1_000)..=top {
+            let square = k * k;
+            assert_eq!(integer_sqrt(square), k, "sqrt({}^2)", k);
+            assert_eq!(integer_sqrt(square - 1), k - 1, "sqrt({}^2 - 1)", k);
+        }
+    }
+
+    #[test]
+    fn test_integer_sqrt_powers_of_two() {
+        for bit in 0..64u32 {
+            let k = 1u128 << bit;
+            assert_eq!(integer_sqrt(k * k), k);
+            assert_eq!(integer_sqrt(k * k + 1), k);
+        }
+    }
+
+    #[test]
+    fn test_selftest_passes() {
+        selftest().unwrap();
+    }
 }
@@ -41,6 +41,9 @@ pub enum AstraError {
     #[msg("Slippage tolerance exceeded")]
     SlippageExceeded,
 
+    #[msg("min_shares_out exceeds what this SOL amount can ever buy on the curve")]
+    MinSharesOutUnreachable,
+
     #[msg("Math overflow")]
     MathOverflow,
 
@@ -74,4 +77,250 @@ pub enum AstraError {
 
     #[msg("Seed amount above maximum USD threshold")]
     SeedAmountTooHigh,
+
+    #[msg("Buy amount too small - would receive no shares after fees")]
+    BuyAmountTooSmall,
+
+    #[msg("Buy exceeds the per-transaction cap for this launch's pool size")]
+    BuyTooLarge,
+
+    #[msg("This launch has entered refund mode and is no longer tradeable - claim a refund instead")]
+    LaunchInRefundMode,
+
+    #[msg("Sell fee above maximum")]
+    SellFeeTooHigh,
+
+    #[msg("Launch has too few holders to graduate")]
+    NotEnoughHolders,
+
+    #[msg("AMM config is not allowlisted for graduation")]
+    InvalidAmmConfig,
+
+    #[msg("Too many AMM configs")]
+    TooManyAmmConfigs,
+
+    #[msg("Distributor account missing or does not match the launch")]
+    InvalidDistributor,
+
+    #[msg("Tokens for this launch are claimed from its distributor")]
+    ClaimViaDistributor,
+
+    #[msg("Creator cannot trade their own launch during the cooldown")]
+    CreatorCooldownActive,
+
+    #[msg("Creator trade cooldown out of range")]
+    InvalidCreatorCooldown,
+
+    #[msg("Only the holder can claim during the post-graduation grace period")]
+    ThirdPartyClaimTooEarly,
+
+    #[msg("Only the keeper may run janitor instructions while a rewards wallet is set")]
+    UnauthorizedKeeper,
+
+    #[msg("Rent recipient does not match the janitor rewards configuration")]
+    InvalidRentRecipient,
+
+    #[msg("Launch has no shares or no SOL on the curve")]
+    EmptyLaunch,
+
+    #[msg("Too many price feeds")]
+    TooManyPriceFeeds,
+
+    #[msg("Price must be greater than zero")]
+    InvalidPrice,
+
+    #[msg("Creator holds too large a share of supply to graduate")]
+    CreatorTooConcentrated,
+
+    #[msg("Concentration cap must be at most 10000 bps")]
+    InvalidConcentrationCap,
+
+    #[msg("Vesting cliff must be between 0 and the vesting duration")]
+    InvalidVestingCliff,
+
+    #[msg("Price updated too recently and has not moved enough to override")]
+    PriceUpdateTooFrequent,
+
+    #[msg("Raydium pool already exists for this pool_state - launch already graduated, or pick another allowed amm_config")]
+    PoolAlreadyExists,
+
+    #[msg("Raydium requires token_0_mint < token_1_mint; regenerate the token mint")]
+    InvalidPoolTokenOrder,
+
+    #[msg("Raydium pool initial amounts must be non-zero")]
+    InsufficientPoolAmounts,
+
+    #[msg("Raydium pool creation CPI failed")]
+    RaydiumCpiFailed,
+
+    #[msg("Vault still holds LP")]
+    VaultNotEmpty,
+
+    #[msg("Candidate positions must belong to the launch and be sorted by address without duplicates")]
+    InvalidWinnerCandidates,
+
+    #[msg("No candidate holds any shares")]
+    NoEligibleHolders,
+
+    #[msg("Buys are frozen by the sell-pressure circuit breaker pending review")]
+    BuysFrozen,
+
+    #[msg("Sell pressure limit must be between 0 and 10000 bps")]
+    InvalidSellPressureLimit,
+
+    #[msg("Launch is locked for graduation - trading is paused")]
+    GraduationLocked,
+
+    #[msg("Graduation requires an active lock_graduation snapshot")]
+    GraduationNotLocked,
+
+    #[msg("Curve changed since the graduation snapshot")]
+    GraduationSnapshotMismatch,
+
+    #[msg("Creator launch index page is full")]
+    CreatorLaunchesPageFull,
+
+    #[msg("Graduation outcome already recorded")]
+    GraduationAlreadyRecorded,
+
+    #[msg("Name must be 1-50 bytes with no leading or trailing whitespace")]
+    InvalidLaunchName,
+
+    #[msg("Symbol must be 1-10 bytes with no whitespace")]
+    InvalidLaunchSymbol,
+
+    #[msg("Launch has reached its maximum number of holders")]
+    HolderCapReached,
+
+    #[msg("Holder cap must be at least 1 (the creator)")]
+    InvalidHolderCap,
+
+    #[msg("Holder count has already been backfilled for this launch")]
+    HolderCountAlreadyBackfilled,
+
+    #[msg("Fee-free buyer count exceeds the maximum")]
+    InvalidFreeFeeBuyerCount,
+
+    #[msg("Bond amount must be greater than zero")]
+    InvalidBondAmount,
+
+    #[msg("Verification bond is still locked")]
+    BondLocked,
+
+    #[msg("Config wallets must be set (not the default pubkey)")]
+    InvalidConfigWallet,
+
+    #[msg("Minimum holders for graduation must be at least 1")]
+    InvalidMinHolders,
+
+    #[msg("Max buy pool bps cannot exceed 10000")]
+    InvalidMaxBuyPoolBps,
+
+    #[msg("Vesting claim is below the minimum (only the final claim may be smaller)")]
+    ClaimTooSmall,
+
+    #[msg("Top-up must be non-zero and no larger than the refund shortfall")]
+    InvalidTopUpAmount,
+
+    #[msg("Reconciliation positions must belong to the launch and be sorted by address")]
+    InvalidReconciliationAccounts,
+
+    #[msg("No keeper rewards to claim")]
+    NoKeeperRewards,
+
+    #[msg("SOL price confidence too wide to price seed bounds - retry when the market settles")]
+    PriceConfidenceTooWide,
+
+    #[msg("Invalid boost amount (zero, or a first boost below the minimum)")]
+    InvalidBoostAmount,
+
+    #[msg("Launch already has a different graduation booster")]
+    BoosterMismatch,
+
+    #[msg("Vault is not activated")]
+    VaultNotActivated,
+
+    #[msg("Metadata update limit reached for this launch")]
+    MetadataUpdatesExhausted,
+
+    #[msg("Curve self-test failed: buy_quote/buy_return round trip out of tolerance")]
+    CurveSelfTestFailed,
+
+    #[msg("Not enough graduation operators signed")]
+    InsufficientOperatorSignatures,
+
+    #[msg("Invalid graduation operator set or threshold")]
+    InvalidOperatorSet,
+
+    #[msg("Invalid authority: must be a new, non-default key")]
+    InvalidAuthority,
+
+    #[msg("Authority change delay has not elapsed")]
+    AuthorityChangeNotReady,
+
+    #[msg("No authority change is pending")]
+    NoPendingAuthorityChange,
+
+    #[msg("Metadata size commitment must be between 1 byte and MAX_METADATA_FETCH_BYTES")]
+    InvalidMaxMetadataBytes,
+
+    #[msg("Launch is refund-eligible - graduation requires force_graduate")]
+    GraduationRequiresOverride,
+
+    #[msg("Burn bps exceeds MAX_BURN_BPS")]
+    InvalidBurnBps,
+
+    #[msg("Verified fee must be 0 or between the verified creator share and the total fee")]
+    InvalidVerifiedFee,
+
+    #[msg("Launch balance cannot cover this payout without dropping below rent-exempt minimum")]
+    InsufficientLiquidity,
+
+    #[msg("Yield recipient cannot be the default pubkey or an executable account")]
+    InvalidYieldRecipient,
+
+    #[msg("Trading start delay exceeds MAX_TRADING_START_DELAY_SECONDS")]
+    InvalidTradingDelay,
+
+    #[msg("Trading has not started on this launch yet")]
+    TradingNotStarted,
+
+    #[msg("Pool accounts don't match the launch's Raydium pool")]
+    InvalidPoolAccounts,
+
+    #[msg("URI must be 1-200 bytes")]
+    InvalidLaunchUri,
+
+    #[msg("A single holder holds too large a share of supply to graduate")]
+    HolderTooConcentrated,
+
+    #[msg("Transaction deadline has passed")]
+    DeadlineExceeded,
+
+    #[msg("Too many holder distributors")]
+    TooManyDistributors,
+
+    #[msg("Fee escrow must be swept before the launch can close")]
+    FeeEscrowNotSwept,
+
+    #[msg("Price update is not newer than the stored price")]
+    PriceUpdateNotNewer,
+
+    #[msg("Another operation on this launch is already in progress")]
+    ReentrancyDetected,
+
+    #[msg("Launch has not reached the graduation market cap")]
+    GraduationThresholdNotMet,
+
+    #[msg("Graduation lock lapsed too recently to re-lock")]
+    GraduationLockCooldown,
+
+    #[msg("SOL price confidence is unknown (0); seed bounds need a reported interval")]
+    PriceConfidenceUnknown,
+
+    #[msg("Every position of the launch must be passed to prove its largest holder")]
+    IncompleteHolderSet,
+
+    #[msg("Seed slash needs the launch, creator position, mint and token accounts")]
+    SeedSlashAccountsMissing,
 }
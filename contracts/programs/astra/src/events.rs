@@ -6,14 +6,143 @@ pub struct ConfigInitialized {
     pub min_seed_lamports: u64,
 }
 
+/// Emitted when the authority proposes its successor (timelocked)
+#[event]
+pub struct AuthorityChangeProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the proposed authority accepts and takes over
+#[event]
+pub struct AuthorityChanged {
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority aborts a pending change
+#[event]
+pub struct AuthorityChangeCancelled {
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the graduation AMM config allowlist changes
+#[event]
+pub struct AmmConfigsUpdated {
+    pub amm_configs: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted when the holder distributor allowlist changes
+#[event]
+pub struct DistributorsUpdated {
+    pub distributors: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted when the graduation operator set or threshold changes
+#[event]
+pub struct GraduationOperatorsUpdated {
+    pub graduation_operators: Vec<Pubkey>,
+    pub graduation_threshold: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when the sell fee changes
+#[event]
+pub struct SellFeeUpdated {
+    pub sell_fee_bps: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the verified-creator total buy fee changes
+#[event]
+pub struct VerifiedFeeUpdated {
+    pub verified_total_fee_bps: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator fixes their launch's metadata before graduation
+#[event]
+pub struct MetadataUpdated {
+    pub launch: Pubkey,
+    pub name: String,
+    pub old_uri: String,
+    pub new_uri: String,
+    /// New commitments for the content at `new_uri`
+    pub metadata_hash: Option<[u8; 32]>,
+    pub max_metadata_bytes: Option<u32>,
+    pub updates_remaining: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority pauses or unpauses the protocol
+#[event]
+pub struct PauseToggled {
+    pub paused: bool,
+    pub authority: Pubkey,
+    pub reason_code: crate::state::PauseReason,
+    pub timestamp: i64,
+}
+
+/// Emitted when the janitor rewards wallet changes
+#[event]
+pub struct JanitorRewardsWalletUpdated {
+    pub janitor_rewards_wallet: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted when the operator wallet is rotated
+#[event]
+pub struct OperatorWalletUpdated {
+    pub previous_wallet: Pubkey,
+    pub wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the protocol fee (treasury) wallet is rotated
+#[event]
+pub struct ProtocolFeeWalletUpdated {
+    pub previous_wallet: Pubkey,
+    pub wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the vault yield protocol wallet is rotated
+#[event]
+pub struct VaultProtocolWalletUpdated {
+    pub previous_wallet: Pubkey,
+    pub wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator concentration cap changes
+#[event]
+pub struct CreatorConcentrationCapUpdated {
+    pub max_creator_concentration_bps: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct LaunchCreated {
     pub launch_id: u64,
     pub creator: Pubkey,
     pub name: String,
     pub symbol: String,
+    pub uri: String,
+    /// SHA-256 of the metadata JSON at `uri` (None = no commitment)
+    pub metadata_hash: Option<[u8; 32]>,
+    /// Creator's bound on the `uri` content size (clients fetch at most this)
+    pub max_metadata_bytes: Option<u32>,
     pub seed_lamports: u64,
     pub seed_shares: u64,
+    /// When public buys open (None = immediately)
+    pub trading_starts_at: Option<i64>,
     pub timestamp: i64,
 }
 
@@ -21,9 +150,20 @@ pub struct LaunchCreated {
 pub struct SharesPurchased {
     pub launch: Pubkey,
     pub buyer: Pubkey,
+    /// Lamports spent (for buy_usd, the converted amount)
     pub sol_amount: u64,
+    /// USD cents requested via buy_usd, None for a lamport-denominated buy
+    pub usd_cents: Option<u64>,
     pub shares_received: u64,
     pub is_seed_buy: bool,
+    /// True on a holder's first buy, false on top-ups
+    pub is_new_position: bool,
+    /// Spot price (lamports/share) the holder entered at - first buy only
+    pub entry_price_lamports_per_share: Option<u64>,
+    /// True if fees were waived (early-buyer incentive)
+    pub fee_free: bool,
+    /// Launch holder count after this buy
+    pub holder_count: u64,
     pub timestamp: i64,
 }
 
@@ -33,6 +173,19 @@ pub struct SharesSold {
     pub seller: Pubkey,
     pub shares_sold: u64,
     pub sol_refunded: u64,
+    pub sell_fee: u64,
+    /// Launch holder count after this sell (drops when the seller exits)
+    pub holder_count: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by lock_graduation - trading is frozen until graduate or expires_at
+#[event]
+pub struct GraduationLocked {
+    pub launch: Pubkey,
+    pub total_sol: u64,
+    pub total_shares: u64,
+    pub expires_at: i64,
     pub timestamp: i64,
 }
 
@@ -44,12 +197,65 @@ pub struct Graduated {
     pub lp_mint: Pubkey,
     pub sol_for_lp: u64,
     pub total_shares: u64,
+    pub holders_at_graduation: u64,
+    /// Creator lockup terms (start, cliff, duration, mode)
+    pub vesting: crate::state::VestingSchedule,
+    /// Booster SOL included in sol_for_lp (0 = unboosted)
+    pub boosted_sol: u64,
+    /// LP attributed to the booster in the vault
+    pub booster_lp: u64,
+    /// Tokens (base units) burned from the minted supply (0 = no burn)
+    pub tokens_burned: u64,
+    /// Opening pool reserves, price and LP minted
+    pub outcome: crate::state::GraduationRecord,
+    pub timestamp: i64,
+}
+
+/// Emitted by force_graduate alongside Graduated - the gates were bypassed
+/// Monitoring should alert on every one
+#[event]
+pub struct ForceGraduated {
+    pub launch: Pubkey,
+    pub authority: Pubkey,
+    pub total_sol: u64,
+    pub holders_at_graduation: u64,
+    /// Protocol-wide force-graduations including this one
+    pub force_graduations_count: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a booster adds SOL to a launch's graduation pool
+#[event]
+pub struct GraduationBoosted {
+    pub launch: Pubkey,
+    pub booster: Pubkey,
+    pub amount: u64,
+    pub boosted_sol: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a booster takes its SOL back from a refunding launch
+#[event]
+pub struct BoostWithdrawn {
+    pub launch: Pubkey,
+    pub booster: Pubkey,
+    pub amount: u64,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct RefundEnabled {
     pub launch: Pubkey,
+    pub positions_remaining: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the last outstanding position in a failed launch is refunded
+/// Signals the janitor that close_launch can run
+#[event]
+pub struct RefundsComplete {
+    pub launch: Pubkey,
+    pub total_refunded: u64,
     pub timestamp: i64,
 }
 
@@ -86,6 +292,38 @@ pub struct CreatorFeesClaimed {
     pub timestamp: i64,
 }
 
+/// Emitted by get_tokens_per_share (read-only) - the ratio claim_tokens pays at
+#[event]
+pub struct TokensPerShare {
+    pub launch: Pubkey,
+    /// Token base units per share, rounded down
+    pub tokens_per_share: u64,
+    /// Holder pool (base units) and share snapshot, for exact per-position math
+    pub holder_token_pool: u64,
+    pub total_shares_at_graduation: u64,
+}
+
+/// Emitted by preview_refund (read-only) - matches what the claim would pay
+#[event]
+pub struct RefundPreview {
+    pub launch: Pubkey,
+    pub user: Pubkey,
+    pub entitlement: u64,
+    pub amount: u64,
+    /// True if the launch is under-funded and the amount is pro-rata
+    pub is_proportional: bool,
+}
+
+/// Emitted when the authority adds SOL to an under-funded refunding launch
+#[event]
+pub struct RefundReserveToppedUp {
+    pub launch: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub shortfall_remaining: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct RefundPushed {
     pub launch: Pubkey,
@@ -101,8 +339,130 @@ pub struct Poked {
     pub total_yield: u64,
     pub caller_reward: u64,
     pub creator_reward: u64,
+    /// Wallet the creator share went to (creator or their yield override)
+    pub creator_recipient: Pubkey,
     pub protocol_reward: u64,
     pub compounded: u64,
+    /// Booster's pro-rata SOL, held in the vault for claim_booster_yield
+    pub booster_reward: u64,
+    /// Project tokens from the withdrawn fee LP, paid to creator_recipient
+    pub creator_tokens: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a graduation booster claims its held poke yield
+#[event]
+pub struct BoosterYieldClaimed {
+    pub vault: Pubkey,
+    pub booster: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator sets or clears their vault's yield override
+#[event]
+pub struct YieldRecipientUpdated {
+    pub vault: Pubkey,
+    pub creator: Pubkey,
+    /// None = yield goes back to the creator
+    pub yield_recipient: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted when a keeper batch-claims accrued poke rewards
+#[event]
+pub struct KeeperRewardsClaimed {
+    pub keeper: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by quote_buy / quote_sell (read-only) - same math as buy / sell
+#[event]
+pub struct QuoteComputed {
+    pub launch: Pubkey,
+    /// True for quote_buy, false for quote_sell
+    pub is_buy: bool,
+    /// Buy: SOL paid in. Sell: refund before the sell fee
+    pub sol_amount: u64,
+    /// Buy: shares out. Sell: shares sold
+    pub shares: u64,
+    /// Buy: SOL into the curve. Sell: SOL to the seller
+    pub net_sol: u64,
+    pub total_fee: u64,
+    /// Effective lamports per share (sol_amount / shares, rounded down)
+    pub price_per_share: u64,
+}
+
+/// Emitted by preview_poke (read-only) so keepers can skip dust pokes
+#[event]
+pub struct PokePreview {
+    pub vault: Pubkey,
+    pub pending_yield: u64,
+    pub caller_reward: u64,
+    pub creator_reward: u64,
+    pub protocol_reward: u64,
+    pub compound: u64,
+    /// Graduation booster's pro-rata part, taken before the split
+    pub booster_reward: u64,
+}
+
+/// Emitted when the authority clears a launch's reentrancy guard
+/// Should never fire in normal operation - alert on it
+#[event]
+pub struct OperationFlagReset {
+    pub launch: Pubkey,
+    pub authority: Pubkey,
+    pub was_set: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted by verify_launch_accounting (read-only) - alert if either flag is false
+#[event]
+pub struct LaunchReconciled {
+    pub launch: Pubkey,
+    /// Positions passed in vs the launch's open position count
+    pub positions_counted: u64,
+    pub position_count: u64,
+    /// Sum of shares + locked_shares across the positions
+    pub summed_shares: u64,
+    pub total_shares: u64,
+    /// Sum of sol_basis plus the creator seed SOL
+    pub summed_sol: u64,
+    pub total_sol: u64,
+    pub shares_match: bool,
+    pub sol_match: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when net sell outflow trips a launch's circuit breaker (buys frozen)
+#[event]
+pub struct CircuitBreakerTripped {
+    pub launch: Pubkey,
+    pub net_outflow: u64,
+    pub window_base_sol: u64,
+    pub limit_bps: u64,
+    pub window_start: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority unfreezes buys after reviewing a tripped breaker
+#[event]
+pub struct CircuitBreakerReset {
+    pub launch: Pubkey,
+    pub authority: Pubkey,
+    pub was_frozen: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority backfills holder_count on a pre-tracking launch
+#[event]
+pub struct HolderCountBackfilled {
+    pub launch: Pubkey,
+    pub authority: Pubkey,
+    pub previous_count: u64,
+    pub holder_count: u64,
     pub timestamp: i64,
 }
 
@@ -110,6 +470,29 @@ pub struct Poked {
 pub struct LaunchClosed {
     pub launch: Pubkey,
     pub caller: Pubkey,
+    /// Lamports above rent swept to the treasury before closing
+    pub dust_swept: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a refunding launch's fee escrow is drained and closed
+#[event]
+pub struct FeeEscrowSwept {
+    pub launch: Pubkey,
+    pub caller: Pubkey,
+    /// Fees moved into the launch PDA to cover the refund shortfall
+    pub to_refund_pool: u64,
+    /// Fees returned to the creator
+    pub to_creator: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a wound-down vault and its LP token account are closed
+#[event]
+pub struct VaultClosed {
+    pub vault: Pubkey,
+    pub launch: Pubkey,
+    pub authority: Pubkey,
     pub timestamp: i64,
 }
 
@@ -136,9 +519,103 @@ pub struct ReadyToGraduate {
     pub timestamp: i64,
 }
 
-/// Emitted when price oracle is updated
+/// Emitted when price oracle is updated (one per feed)
 #[event]
 pub struct PriceUpdated {
+    pub asset: Pubkey,
+    pub price_usd: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when min_seed_lamports is re-derived from the SOL price
+#[event]
+pub struct MinSeedRefreshed {
+    pub previous_min_seed_lamports: u64,
+    pub min_seed_lamports: u64,
     pub sol_price_usd: u64,
     pub timestamp: i64,
 }
+
+/// Emitted by estimate_graduation (read-only)
+/// Clients extrapolate ETA as remaining_usd / volume_last_hour_usd hours
+#[event]
+pub struct GraduationEstimate {
+    pub launch: Pubkey,
+    pub market_cap_usd: u64,
+    pub remaining_usd: u64,
+    pub volume_last_hour: u64,
+    pub volume_last_hour_usd: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by get_fee_schedule (read-only)
+/// Source of truth for client fee displays
+#[event]
+pub struct FeeSchedule {
+    pub total_fee_bps: u64,
+    pub creator_unverified_bps: u64,
+    pub creator_verified_bps: u64,
+    pub sell_fee_bps: u64,
+    /// Total buy fee on verified creators' launches (0 = same as total_fee_bps)
+    pub verified_total_fee_bps: u64,
+}
+
+/// Emitted when a creator stakes toward the verification bond
+#[event]
+pub struct CreatorBondStaked {
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub total_bonded: u64,
+    pub verified: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator withdraws their verification bond
+#[event]
+pub struct CreatorBondUnstaked {
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority slashes a creator's bond to the treasury
+#[event]
+pub struct CreatorBondSlashed {
+    pub creator: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when slash_creator_bond also slashes a launch's unvested seed
+#[event]
+pub struct UnvestedSeedSlashed {
+    pub launch: Pubkey,
+    pub creator: Pubkey,
+    pub shares_slashed: u64,
+    pub tokens_burned: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by get_creator_reputation (read-only)
+#[event]
+pub struct CreatorReputation {
+    pub creator: Pubkey,
+    pub score: u64,
+    pub graduated_count: u64,
+    pub total_launches: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by select_winner - randomness is published so the draw can be re-verified
+#[event]
+pub struct WinnerSelected {
+    pub launch: Pubkey,
+    pub winner: Pubkey,
+    pub position: Pubkey,
+    pub winner_shares: u64,
+    pub total_shares: u64,
+    pub candidates: u64,
+    pub randomness: [u8; 32],
+    pub timestamp: i64,
+}
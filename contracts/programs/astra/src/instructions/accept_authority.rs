@@ -0,0 +1,25 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Take over as authority once the proposal's delay has passed (pending authority only)
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let new_authority = ctx.accounts.new_authority.key();
+    let previous_authority = ctx.accounts.config.accept_authority(&new_authority, now)?;
+
+    emit!(crate::events::AuthorityChanged {
+        previous_authority,
+        authority: new_authority,
+        timestamp: now,
+    });
+
+    Ok(())
+}
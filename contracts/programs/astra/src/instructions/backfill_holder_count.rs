@@ -0,0 +1,44 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Backfills `holder_count` on a launch created before holder tracking (authority only)
+///
+/// Migration plumbing: such launches read holder_count == 0 and would fail the
+/// holder graduation gate. The count is verified off-chain (positions with
+/// shares > 0, creator included) and can only be set once per launch.
+#[derive(Accounts)]
+pub struct BackfillHolderCount<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub launch: Account<'info, Launch>,
+}
+
+pub fn handler(ctx: Context<BackfillHolderCount>, holder_count: u64) -> Result<()> {
+    let launch = &mut ctx.accounts.launch;
+    let previous_count = launch.backfill_holder_count(holder_count)?;
+
+    emit!(crate::events::HolderCountBackfilled {
+        launch: launch.key(),
+        authority: ctx.accounts.authority.key(),
+        previous_count,
+        holder_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "HOLDER COUNT BACKFILLED: Launch {} ({} -> {})",
+        launch.key(),
+        previous_count,
+        holder_count
+    );
+
+    Ok(())
+}
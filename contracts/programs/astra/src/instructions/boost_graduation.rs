@@ -0,0 +1,55 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Add SOL to a launch's graduation pool
+///
+/// The first booster claims the launch's booster slot with at least
+/// MIN_BOOST_LAMPORTS. Booster SOL sits in the launch PDA outside total_sol:
+/// at graduation it deepens the pool's SOL side (unmatched - no extra tokens,
+/// so it also lifts the opening price) and the booster is credited its
+/// pro-rata share of the LP in the vault, which earns it that share of every
+/// poke (see claim_booster_yield). If the launch refunds instead, the booster
+/// takes it back with withdraw_boost.
+#[derive(Accounts)]
+pub struct BoostGraduation<'info> {
+    #[account(mut)]
+    pub booster: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub launch: Account<'info, Launch>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<BoostGraduation>, amount: u64) -> Result<()> {
+    ctx.accounts.config.check_not_paused()?;
+
+    let launch = &mut ctx.accounts.launch;
+    let now = Clock::get()?.unix_timestamp;
+    launch.add_boost(ctx.accounts.booster.key(), amount, now)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.booster.to_account_info(),
+                to: launch.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit!(crate::events::GraduationBoosted {
+        launch: launch.key(),
+        booster: ctx.accounts.booster.key(),
+        amount,
+        boosted_sol: launch.boosted_sol,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -4,11 +4,13 @@
 //! - Reentrancy protection
 //! - Overflow-protected fee calculations
 //! - 1% total fee split between creator (0.3-0.5%) and protocol (0.5-0.7%)
+//! - Creator fees escrowed separately from the curve reserve
 //! - Dynamic share issuance (no cap)
+//! - Whale protection: per-tx max scales with the launch's pool size
 //! - NO 92/8 split - all shares are unlocked
 //! - Market cap tracking for graduation triggers
 
-use crate::constants::{BPS_DENOMINATOR, MAX_BUY_LAMPORTS, TOTAL_FEE_BPS, GRADUATION_MARKET_CAP_USD, GRADUATION_THRESHOLD_NOTIFICATION_BPS};
+use crate::constants::{BPS_DENOMINATOR, MAX_BUY_LAMPORTS, GRADUATION_MARKET_CAP_USD, GRADUATION_THRESHOLD_NOTIFICATION_BPS};
 use crate::curve;
 use crate::errors::AstraError;
 use crate::state::*;
@@ -23,11 +25,8 @@ pub struct Buy<'info> {
     #[account(seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, GlobalConfig>,
 
-    #[account(
-        mut,
-        constraint = !launch.graduated @ AstraError::AlreadyGraduated,
-        constraint = !launch.refund_mode @ AstraError::RefundModeActive
-    )]
+    /// Tradeability (not graduated, not in refund mode) checked in handler
+    #[account(mut)]
     pub launch: Account<'info, Launch>,
 
     #[account(
@@ -46,6 +45,14 @@ pub struct Buy<'info> {
     )]
     pub creator_stats: Account<'info, CreatorStats>,
 
+    /// Creator fee escrow (separate from the curve reserve in the launch PDA)
+    #[account(
+        mut,
+        seeds = [b"fee_escrow", launch.key().as_ref()],
+        bump = fee_escrow.bump
+    )]
+    pub fee_escrow: Account<'info, FeeEscrow>,
+
     /// CHECK: Protocol fee wallet verified against config
     #[account(mut, address = config.protocol_fee_wallet)]
     pub protocol_fee_wallet: UncheckedAccount<'info>,
@@ -60,10 +67,28 @@ pub struct BuyArgs {
 }
 
 pub fn handler(ctx: Context<Buy>, args: BuyArgs) -> Result<()> {
+    execute(ctx, args, None)
+}
+
+/// Shared buy path; `usd_cents` is set when the amount came from buy_usd
+pub(crate) fn execute(ctx: Context<Buy>, args: BuyArgs, usd_cents: Option<u64>) -> Result<()> {
     let launch = &mut ctx.accounts.launch;
     let position = &mut ctx.accounts.position;
     let creator_stats = &ctx.accounts.creator_stats;
     let config = &ctx.accounts.config;
+    config.check_not_paused()?;
+
+    // Market cap and the graduation signal below read the cached SOL price
+    config.check_price_fresh_for_buy(Clock::get()?.unix_timestamp)?;
+
+    // Launch must still be on the curve (dedicated error for refund mode)
+    launch.check_tradeable()?;
+    launch.check_trading_started(Clock::get()?.unix_timestamp)?;
+    require!(!launch.buys_frozen, AstraError::BuysFrozen);
+    require!(
+        !launch.graduation_lock_active(Clock::get()?.unix_timestamp)?,
+        AstraError::GraduationLocked
+    );
 
     // Input validation
     require!(args.sol_amount > 0, AstraError::InvalidCalculation);
@@ -71,57 +96,80 @@ pub fn handler(ctx: Context<Buy>, args: BuyArgs) -> Result<()> {
         args.sol_amount <= MAX_BUY_LAMPORTS,
         AstraError::InvalidCalculation
     );
+    require!(
+        args.sol_amount <= config.max_buy_lamports(launch.total_sol),
+        AstraError::BuyTooLarge
+    );
     require!(args.min_shares_out > 0, AstraError::InvalidCalculation);
 
+    // Reject micro-buys below the USD floor (when a price is available)
+    if let Some(min_buy_lamports) = config.min_buy_lamports() {
+        require!(
+            args.sol_amount >= min_buy_lamports,
+            AstraError::BuyAmountTooSmall
+        );
+    }
+
+    // Creator cooldown - no trading their own launch early on
+    if ctx.accounts.buyer.key() == launch.creator {
+        require!(
+            launch.creator_can_trade(Clock::get()?.unix_timestamp)?,
+            AstraError::CreatorCooldownActive
+        );
+    }
+
     // Reentrancy protection
-    require!(
-        !launch.operation_in_progress,
-        AstraError::InvalidCalculation
-    );
-    launch.operation_in_progress = true;
+    launch.begin_operation()?;
 
-    // 1. Determine creator fee rate based on verification
-    let creator_fee_bps = creator_stats.get_creator_fee_bps();
-    let protocol_fee_bps = TOTAL_FEE_BPS
-        .checked_sub(creator_fee_bps)
-        .ok_or(AstraError::MathOverflow)?;
+    // 1. Determine fee rates based on verification (creator share and any
+    // verified-creator discount on the total)
+    // The launch's first N unique buyers pay no fees on their first buy, up to
+    // FEE_FREE_BUY_CAP_LAMPORTS
+    let is_new_position = position.is_new();
+    let fee_free = launch.take_fee_free_slot(is_new_position);
+    let (total_fee_bps, creator_fee_bps) = ctx.accounts.config.buy_fee_bps(creator_stats);
 
-    // 2. Fee Calculation with overflow protection
-    let total_fee = args
-        .sol_amount
-        .checked_mul(TOTAL_FEE_BPS)
-        .ok_or(AstraError::MathOverflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(AstraError::MathOverflow)?;
-    let creator_fee = args
-        .sol_amount
-        .checked_mul(creator_fee_bps)
-        .ok_or(AstraError::MathOverflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(AstraError::MathOverflow)?;
-    let protocol_fee = args
-        .sol_amount
-        .checked_mul(protocol_fee_bps)
-        .ok_or(AstraError::MathOverflow)?
-        .checked_div(BPS_DENOMINATOR)
-        .ok_or(AstraError::MathOverflow)?;
-    let net_sol = args
-        .sol_amount
-        .checked_sub(total_fee)
-        .ok_or(AstraError::MathOverflow)?;
+    // 2. Fee Calculation with overflow protection (shared with quote_buy)
+    let BuyFees {
+        creator_fee,
+        protocol_fee,
+        net_sol,
+        ..
+    } = if fee_free {
+        GlobalConfig::split_fee_free_buy(args.sol_amount, total_fee_bps, creator_fee_bps)?
+    } else {
+        GlobalConfig::split_buy_fee(args.sol_amount, total_fee_bps, creator_fee_bps)?
+    };
+
+    require!(net_sol > 0, AstraError::BuyAmountTooSmall);
 
     // 3. Calculate Shares via Curve (no cap - dynamic issuance)
     let shares = curve::buy_return(net_sol, launch.total_shares)?;
 
-    require!(shares >= args.min_shares_out, AstraError::SlippageExceeded);
+    // No fee-only buys: dust that rounds to zero shares is rejected
+    require!(shares > 0, AstraError::BuyAmountTooSmall);
+
+    // Slippage check (unreachable bounds fail with a distinct error)
+    launch.check_min_shares_out(net_sol, shares, args.min_shares_out)?;
 
     // 4. Update Position (V7: No 92/8 split, all shares unlocked)
-    if position.first_buy_at == 0 {
+    if is_new_position {
         position.launch = launch.key();
         position.user = ctx.accounts.buyer.key();
-        position.first_buy_at = Clock::get()?.unix_timestamp;
+        // Entry price: spot at the supply before this buy's shares
+        position.record_entry(launch.total_shares, Clock::get()?.unix_timestamp)?;
         position.vested_shares_claimed = 0;
         position.bump = ctx.bumps.position;
+
+        launch.position_count = launch
+            .position_count
+            .checked_add(1)
+            .ok_or(AstraError::MathOverflow)?;
+    }
+
+    // New entrants count against the launch's optional holder cap
+    if !position.is_holder() {
+        launch.admit_holder()?;
     }
 
     position.shares = position
@@ -133,6 +181,10 @@ pub fn handler(ctx: Context<Buy>, args: BuyArgs) -> Result<()> {
         .checked_add(net_sol)
         .ok_or(AstraError::MathOverflow)?;
     position.last_updated_at = Clock::get()?.unix_timestamp;
+    launch.record_holder_shares(&position.user, position.shares);
+
+    // Net-flow window for the sell-pressure circuit breaker (before total_sol moves)
+    launch.record_inflow(position.last_updated_at, net_sol)?;
 
     // 5. Update Launch Totals (V7: Simplified, no locked/unlocked split)
     let new_total_shares = launch
@@ -147,6 +199,9 @@ pub fn handler(ctx: Context<Buy>, args: BuyArgs) -> Result<()> {
         .ok_or(AstraError::MathOverflow)?;
     launch.total_sol = new_total_sol;
 
+    // Rolling hourly volume (gross buy amount) for graduation ETA
+    launch.record_volume(Clock::get()?.unix_timestamp, args.sol_amount)?;
+
     // 6. Track Creator & Protocol Fees
     launch.creator_accrued_fees = launch
         .creator_accrued_fees
@@ -169,10 +224,19 @@ pub fn handler(ctx: Context<Buy>, args: BuyArgs) -> Result<()> {
         protocol_fee,
     )?;
 
-    // 8. Transfer Creator Fee + Net SOL to Launch PDA
-    let sol_to_launch = net_sol
-        .checked_add(creator_fee)
-        .ok_or(AstraError::MathOverflow)?;
+    // 8. Transfer Creator Fee to Fee Escrow
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.fee_escrow.to_account_info(),
+            },
+        ),
+        creator_fee,
+    )?;
+
+    // 9. Transfer Net SOL to Launch PDA (launch holds only total_sol + rent)
     system_program::transfer(
         CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
@@ -181,29 +245,29 @@ pub fn handler(ctx: Context<Buy>, args: BuyArgs) -> Result<()> {
                 to: launch.to_account_info(),
             },
         ),
-        sol_to_launch,
+        net_sol,
     )?;
 
-    // 9. Emit Purchase Event
+    // 10. Emit Purchase Event
     let now = Clock::get()?.unix_timestamp;
     emit!(crate::events::SharesPurchased {
         launch: launch.key(),
         buyer: ctx.accounts.buyer.key(),
         sol_amount: args.sol_amount,
+        usd_cents,
         shares_received: shares,
         is_seed_buy: false,
+        is_new_position,
+        entry_price_lamports_per_share: is_new_position
+            .then_some(position.entry_price_lamports_per_share),
+        fee_free,
+        holder_count: launch.holder_count,
         timestamp: now,
     });
 
-    // 10. Check market cap and emit event if approaching graduation
+    // 11. Check market cap and emit event if approaching graduation
     // Note: Market cap calculation requires SOL price from config
-    if config.sol_price_usd > 0 {
-        let market_cap_usd = (new_total_sol as u128)
-            .checked_mul(config.sol_price_usd as u128)
-            .ok_or(AstraError::MathOverflow)?
-            .checked_div(1_000_000_000)
-            .ok_or(AstraError::MathOverflow)? as u64;
-        
+    if let Some(market_cap_usd) = launch.market_cap_usd(config.sol_price_usd) {
         emit!(crate::events::MarketCapUpdated {
             launch: launch.key(),
             market_cap_usd,
@@ -211,7 +275,13 @@ pub fn handler(ctx: Context<Buy>, args: BuyArgs) -> Result<()> {
             total_sol: new_total_sol,
             timestamp: now,
         });
-        
+
+        // Readiness keys off the graduation metric (SOL raised or fully diluted)
+        // A degenerate (zero-share/zero-SOL) launch reports 0 and never signals readiness
+        let graduation_market_cap_usd = launch
+            .graduation_market_cap_usd(config.sol_price_usd)
+            .ok_or(AstraError::MathOverflow)?;
+
         // Emit readiness event if approaching graduation threshold
         let threshold = (GRADUATION_MARKET_CAP_USD as u128)
             .checked_mul(GRADUATION_THRESHOLD_NOTIFICATION_BPS as u128)
@@ -219,10 +289,10 @@ pub fn handler(ctx: Context<Buy>, args: BuyArgs) -> Result<()> {
             .checked_div(BPS_DENOMINATOR as u128)
             .ok_or(AstraError::MathOverflow)? as u64;
             
-        if market_cap_usd >= threshold {
+        if graduation_market_cap_usd >= threshold {
             emit!(crate::events::ReadyToGraduate {
                 launch: launch.key(),
-                market_cap_usd,
+                market_cap_usd: graduation_market_cap_usd,
                 threshold_usd: GRADUATION_MARKET_CAP_USD,
                 timestamp: now,
             });
@@ -230,6 +300,6 @@ pub fn handler(ctx: Context<Buy>, args: BuyArgs) -> Result<()> {
     }
 
     // Reset reentrancy flag
-    launch.operation_in_progress = false;
+    launch.end_operation();
     Ok(())
 }
@@ -0,0 +1,35 @@
+//! Buy-with-USD instruction handler
+//!
+//! Converts an exact USD amount (fixed-point cents) to lamports at the cached
+//! SOL price, then runs the regular `buy` flow with that SOL amount. Matches
+//! the USD preset buttons in the UI: the user spends exactly what they picked.
+//! SharesPurchased carries both the requested cents and the converted lamports.
+
+use crate::instructions::buy::{self, Buy, BuyArgs};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuyUsdArgs {
+    /// Amount to spend in USD cents (e.g. 5_000 = $50.00)
+    pub usd_cents: u64,
+    pub min_shares_out: u64,
+}
+
+pub fn handler(ctx: Context<Buy>, args: BuyUsdArgs) -> Result<()> {
+    // Rejects a stale cached price - the USD amount is the user's intent
+    let sol_amount = ctx
+        .accounts
+        .config
+        .fresh_usd_cents_to_lamports(args.usd_cents, Clock::get()?.unix_timestamp)?;
+
+    msg!("BUY USD: {} cents = {} lamports", args.usd_cents, sol_amount);
+
+    buy::execute(
+        ctx,
+        BuyArgs {
+            sol_amount,
+            min_shares_out: args.min_shares_out,
+        },
+        Some(args.usd_cents),
+    )
+}
@@ -0,0 +1,27 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Abort a pending authority change before it is accepted (authority only)
+#[derive(Accounts)]
+pub struct CancelAuthorityChange<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<CancelAuthorityChange>) -> Result<()> {
+    let cancelled_authority = ctx.accounts.config.cancel_authority_change()?;
+
+    emit!(crate::events::AuthorityChangeCancelled {
+        authority: ctx.accounts.authority.key(),
+        cancelled_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
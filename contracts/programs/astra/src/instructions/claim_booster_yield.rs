@@ -0,0 +1,43 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use crate::transfer::transfer_from_pda;
+use anchor_lang::prelude::*;
+
+/// Claim the poke yield a graduation booster's LP earned
+///
+/// Poke pays the booster its pro-rata share of fee growth (booster_lp of the
+/// vault's LP) but holds it in the vault, so a booster wallet that can't be
+/// credited never blocks yield collection for everyone else.
+#[derive(Accounts)]
+pub struct ClaimBoosterYield<'info> {
+    #[account(mut)]
+    pub booster: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.launch.as_ref()],
+        bump = vault.bump,
+        constraint = vault.booster == Some(booster.key()) @ AstraError::BoosterMismatch
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn handler(ctx: Context<ClaimBoosterYield>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let amount = vault.take_booster_yield(&ctx.accounts.booster.key())?;
+
+    transfer_from_pda(
+        &vault.to_account_info(),
+        &ctx.accounts.booster.to_account_info(),
+        amount,
+    )?;
+
+    emit!(crate::events::BoosterYieldClaimed {
+        vault: vault.key(),
+        booster: ctx.accounts.booster.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::errors::AstraError;
 use crate::events::CreatorFeesClaimed;
-use crate::state::{CreatorStats, Launch};
+use crate::state::{CreatorStats, FeeEscrow, GlobalConfig, Launch};
+use crate::transfer::transfer_from_pda;
 use anchor_lang::prelude::*;
 
 /// Claim creator fees instruction
@@ -13,6 +14,7 @@ use anchor_lang::prelude::*;
 /// - Creator must be the launch creator
 /// - Launch must be graduated
 /// - Launch must have accrued fees to claim
+/// - Protocol must not be paused
 ///
 /// # Safety
 /// - Uses reentrancy protection via `operation_in_progress` flag
@@ -23,6 +25,9 @@ pub struct ClaimCreatorFees<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
     /// The launch account to claim fees from
     /// Must be graduated and have accrued fees
     #[account(
@@ -33,6 +38,14 @@ pub struct ClaimCreatorFees<'info> {
     )]
     pub launch: Account<'info, Launch>,
 
+    /// Fee escrow holding the accrued creator fees
+    #[account(
+        mut,
+        seeds = [b"fee_escrow", launch.key().as_ref()],
+        bump = fee_escrow.bump
+    )]
+    pub fee_escrow: Account<'info, FeeEscrow>,
+
     /// Creator stats account for tracking lifetime earnings
     #[account(
         mut,
@@ -44,15 +57,16 @@ pub struct ClaimCreatorFees<'info> {
 
 /// Handler for claiming creator fees
 ///
-/// Transfers accrued fees from the launch PDA to the creator wallet
+/// Transfers accrued fees from the fee escrow to the creator wallet
 /// and updates the creator's lifetime earnings.
 pub fn handler(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    ctx.accounts.config.check_not_paused()?;
+
     let launch = &mut ctx.accounts.launch;
     let creator_stats = &mut ctx.accounts.creator_stats;
 
     // Reentrancy protection - prevent reentrant calls during fee transfer
-    require!(!launch.operation_in_progress, AstraError::InvalidCalculation);
-    launch.operation_in_progress = true;
+    launch.begin_operation()?;
 
     // Get the amount of fees to claim
     let amount = launch.creator_accrued_fees;
@@ -69,24 +83,12 @@ pub fn handler(ctx: Context<ClaimCreatorFees>) -> Result<()> {
         .checked_add(amount)
         .ok_or(AstraError::MathOverflow)?;
 
-    // Transfer SOL from launch PDA to creator with overflow protection
-    // Using direct lamport manipulation for PDA-to-account transfers
-    **launch
-        .to_account_info()
-        .try_borrow_mut_lamports()? = launch
-        .to_account_info()
-        .lamports()
-        .checked_sub(amount)
-        .ok_or(AstraError::MathOverflow)?;
-    **ctx
-        .accounts
-        .creator
-        .try_borrow_mut_lamports()? = ctx
-        .accounts
-        .creator
-        .lamports()
-        .checked_add(amount)
-        .ok_or(AstraError::MathOverflow)?;
+    // Transfer SOL from fee escrow to creator (never below rent)
+    transfer_from_pda(
+        &ctx.accounts.fee_escrow.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+        amount,
+    )?;
 
     // Emit event for off-chain tracking
     emit!(CreatorFeesClaimed {
@@ -97,7 +99,7 @@ pub fn handler(ctx: Context<ClaimCreatorFees>) -> Result<()> {
     });
 
     // Reset reentrancy flag
-    launch.operation_in_progress = false;
+    launch.end_operation();
 
     Ok(())
 }
@@ -0,0 +1,41 @@
+use crate::events::KeeperRewardsClaimed;
+use crate::state::*;
+use crate::transfer::transfer_from_pda;
+use anchor_lang::prelude::*;
+
+/// Batch-claim the poke rewards accrued to a keeper
+#[derive(Accounts)]
+pub struct ClaimKeeperRewards<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"keeper_rewards", keeper.key().as_ref()],
+        bump = keeper_rewards.bump
+    )]
+    pub keeper_rewards: Account<'info, KeeperRewards>,
+}
+
+pub fn handler(ctx: Context<ClaimKeeperRewards>) -> Result<()> {
+    let keeper_rewards = &mut ctx.accounts.keeper_rewards;
+
+    // Zero the balance before moving lamports
+    let amount = keeper_rewards.take_claim()?;
+
+    // Pay out of the keeper PDA (never below rent)
+    transfer_from_pda(
+        &keeper_rewards.to_account_info(),
+        &ctx.accounts.keeper.to_account_info(),
+        amount,
+    )?;
+
+    emit!(KeeperRewardsClaimed {
+        keeper: keeper_rewards.keeper,
+        amount,
+        total_claimed: keeper_rewards.total_claimed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
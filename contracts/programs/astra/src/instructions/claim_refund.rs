@@ -1,5 +1,6 @@
 use crate::errors::AstraError;
 use crate::state::*;
+use crate::transfer::{transfer_from_pda, withdrawable_lamports};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -7,6 +8,9 @@ pub struct ClaimRefund<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
     #[account(
         mut,
         constraint = launch.refund_mode @ AstraError::RefundModeNotActive
@@ -23,44 +27,29 @@ pub struct ClaimRefund<'info> {
 }
 
 pub fn handler(ctx: Context<ClaimRefund>) -> Result<()> {
+    ctx.accounts.config.check_not_paused()?;
+
     let launch = &mut ctx.accounts.launch;
     let position = &mut ctx.accounts.position;
 
-    // V7 SIMPLIFICATION:
-    // - All shares are 100% unlocked (no 92/8 split)
-    // - Single sol_basis field for refund calculation
-    let refund_amount = position.sol_basis;
-
-    // Skip zero refunds (shouldn't happen with proper constraints, but defensive)
-    if refund_amount == 0 {
-        position.has_claimed_refund = true;
-        emit!(crate::events::RefundClaimed {
-            launch: launch.key(),
-            user: ctx.accounts.user.key(),
-            sol_refunded: 0,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        return Ok(());
-    }
-
-    // Verify launch has sufficient funds
-    let launch_balance = launch.to_account_info().lamports();
-    let rent = Rent::get()?.minimum_balance(8 + Launch::INIT_SPACE);
-    let available = launch_balance.saturating_sub(rent);
-
-    require!(available >= refund_amount, AstraError::InsufficientFunds);
+    // Settle against the curve totals (the creator's refund includes the seed)
+    // Pro-rata if the PDA is short (same quote preview_refund reports)
+    let available = withdrawable_lamports(&launch.to_account_info())?;
+    let (quote, refunds_complete) = launch.settle_refund(position, available)?;
+    let refund_amount = quote.amount;
 
-    // Transfer refund from launch PDA to user
-    **launch.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
-    **ctx.accounts.user.try_borrow_mut_lamports()? += refund_amount;
+    // Transfer refund from launch PDA to user (never below rent; no-op for 0)
+    transfer_from_pda(
+        &launch.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        refund_amount,
+    )?;
 
     // Mark as claimed
     position.has_claimed_refund = true;
-
-    // V7 SIMPLIFICATION:
-    // Update only total_shares and total_sol (no locked/unlocked split)
-    launch.total_shares = launch.total_shares.saturating_sub(position.shares);
-    launch.total_sol = launch.total_sol.saturating_sub(position.sol_basis);
+    position
+        .record_withdrawal(refund_amount, refund_amount)
+        .ok_or(AstraError::MathOverflow)?;
 
     emit!(crate::events::RefundClaimed {
         launch: launch.key(),
@@ -68,6 +57,19 @@ pub fn handler(ctx: Context<ClaimRefund>) -> Result<()> {
         sol_refunded: refund_amount,
         timestamp: Clock::get()?.unix_timestamp,
     });
+    emit_if_complete(launch, refunds_complete)?;
+
+    Ok(())
+}
 
+/// Emit RefundsComplete once the last outstanding position is refunded
+pub(crate) fn emit_if_complete(launch: &Account<Launch>, refunds_complete: bool) -> Result<()> {
+    if refunds_complete {
+        emit!(crate::events::RefundsComplete {
+            launch: launch.key(),
+            total_refunded: launch.total_refunded,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
     Ok(())
 }
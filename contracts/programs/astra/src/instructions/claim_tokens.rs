@@ -5,13 +5,15 @@
 //! - NO auto-unlock needed (all shares already unlocked in V7)
 //! - Creator's SEED shares require vesting completion before claiming
 //! - Position account closed after claim to recycle rent
+//! - Disabled when holder tokens were sent to an external distributor
+//! - Third-party (janitor) claims only after the post-graduation grace period
+//! - Optional min_tokens_out guards against the share snapshot changing mid-flight
 //!
 //! V7 SIMPLIFICATION:
 //! - All shares are unlocked (no 92/8 split)
 //! - Only creator seed shares are locked (for vesting)
 //! - Regular buyers can claim immediately after graduation
 
-use crate::constants::TOKENS_FOR_HOLDERS;
 use crate::errors::AstraError;
 use crate::state::*;
 use anchor_lang::prelude::*;
@@ -28,9 +30,13 @@ pub struct ClaimTokens<'info> {
     #[account(mut)]
     pub user: UncheckedAccount<'info>,
 
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
     #[account(
         mut,
-        constraint = launch.graduated @ AstraError::NotGraduated
+        constraint = launch.graduated @ AstraError::NotGraduated,
+        constraint = launch.claims_in_protocol() @ AstraError::ClaimViaDistributor
     )]
     pub launch: Account<'info, Launch>,
 
@@ -71,26 +77,30 @@ pub struct ClaimTokens<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<ClaimTokens>) -> Result<()> {
+pub fn handler(ctx: Context<ClaimTokens>, min_tokens_out: Option<u64>) -> Result<()> {
+    ctx.accounts.config.check_not_paused()?;
+
     let launch = &mut ctx.accounts.launch;
     let position = &mut ctx.accounts.position;
 
     // Reentrancy protection
-    require!(
-        !launch.operation_in_progress,
-        AstraError::InvalidCalculation
-    );
-    launch.operation_in_progress = true;
+    launch.begin_operation()?;
+
+    // Only the holder can claim during the grace period; janitors after
+    if ctx.accounts.payer.key() != ctx.accounts.user.key() {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            launch.third_party_claim_open(now)?,
+            AstraError::ThirdPartyClaimTooEarly
+        );
+    }
 
     let is_creator = ctx.accounts.user.key() == launch.creator;
 
     if is_creator {
         // Creator: Must complete vesting of seed shares before claiming
-        let seed_shares = launch.creator_seed_shares;
-        let vested_so_far = position.vested_shares_claimed;
-
-        // Calculate remaining unvested seed shares
-        let remaining_seed = seed_shares.saturating_sub(vested_so_far);
+        // (a slashed seed has nothing left to vest)
+        let remaining_seed = launch.unvested_seed_shares();
 
         // Seed must be fully vested before claiming
         require!(remaining_seed == 0, AstraError::VestingNotComplete);
@@ -101,24 +111,10 @@ pub fn handler(ctx: Context<ClaimTokens>) -> Result<()> {
     // V7: Regular buyers - all shares already unlocked, no action needed
 
     // Proportional token distribution
-    // Formula: tokens = (user_shares * TOKENS_FOR_HOLDERS) / total_shares_at_graduation
-    let tokens_for_holders_u128 = (TOKENS_FOR_HOLDERS as u128) * 1_000_000_000; // Add 9 decimals
-
+    // Formula: tokens = (user_shares * holder pool) / total_shares_at_graduation
     // V7: Use simplified position.shares (all unlocked)
-    let user_shares = position.shares as u128;
-    let total_shares = launch.total_shares_at_graduation as u128;
-
-    // Safety check
-    require!(total_shares > 0, AstraError::InvalidCalculation);
-
-    // Calculate proportional tokens (use u128 to prevent overflow)
-    let amount = user_shares
-        .checked_mul(tokens_for_holders_u128)
-        .ok_or(AstraError::MathOverflow)?
-        .checked_div(total_shares)
-        .ok_or(AstraError::MathOverflow)? as u64;
-
-    require!(amount > 0, AstraError::NoSharesToClaim);
+    // The last claim against the snapshot also takes the rounding remainder
+    let amount = launch.take_token_claim(position.shares, min_tokens_out)?;
 
     // Transfer Tokens from Launch PDA to User ATA
     let launch_id_bytes = launch.launch_id.to_le_bytes();
@@ -146,6 +142,7 @@ pub fn handler(ctx: Context<ClaimTokens>) -> Result<()> {
     // Update State (Position account is closed by Anchor after this)
     position.has_claimed_tokens = true;
     position.shares = 0;
+    launch.position_count = launch.position_count.saturating_sub(1);
 
     emit!(crate::events::TokensClaimed {
         launch: launch.key(),
@@ -155,6 +152,6 @@ pub fn handler(ctx: Context<ClaimTokens>) -> Result<()> {
     });
 
     // Reset reentrancy flag
-    launch.operation_in_progress = false;
+    launch.end_operation();
     Ok(())
 }
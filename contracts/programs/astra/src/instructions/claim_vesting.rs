@@ -3,7 +3,7 @@
 //! Processes vesting claims for CREATOR SEED SHARES only with:
 //! - Reentrancy protection via RAII guard pattern
 //! - Deterministic integer-based vesting calculations (no f64)
-//! - Linear vesting over 42 days from graduation time
+//! - Linear (optional cliff) or cliff vesting over 42 days from graduation time
 //! - Overflow-protected arithmetic operations
 //!
 //! IMPORTANT: Only the creator's initial SEED investment vests.
@@ -15,17 +15,26 @@
 //! - Simplified state: position.shares (was unlocked_shares in V6)
 //! - Only creator SEED shares vest (tracked in position.locked_shares)
 //! - All shares moved to position.shares upon vesting claim
+//!
+//! COMBINED CLAIM:
+//! Once the seed is fully vested (now >= vesting_start + duration), passing the
+//! optional token accounts also distributes tokens in the same transaction -
+//! the claim_tokens flow - and closes the position. Without them, only shares move.
 
-use crate::constants::VESTING_DURATION_SECONDS;
 use crate::errors::AstraError;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Token, TokenAccount};
 
 #[derive(Accounts)]
 pub struct ClaimVesting<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
     #[account(
         mut,
         constraint = launch.graduated @ AstraError::NotGraduated
@@ -40,6 +49,32 @@ pub struct ClaimVesting<'info> {
         constraint = user.key() == launch.creator @ AstraError::Unauthorized
     )]
     pub position: Account<'info, Position>,
+
+    /// Optional: token accounts for the combined fully-vested claim
+    /// CHECK: Mint verified via launch state
+    #[account(
+        constraint = Some(token_mint.key()) == launch.token_mint @ AstraError::InvalidCalculation
+    )]
+    pub token_mint: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_mint,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = launch
+    )]
+    pub launch_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
 
 /// Handler for claim_vesting instruction
@@ -51,6 +86,9 @@ pub struct ClaimVesting<'info> {
 /// - All claimed shares go directly to position.shares (100% unlocked)
 /// - No 92/8 split to manage
 ///
+/// If the seed is fully vested and the token accounts are provided, the whole
+/// position is also converted to tokens and the position account is closed.
+///
 /// # Arguments
 /// * `ctx` - Context containing user, launch, and position accounts
 ///
@@ -60,19 +98,22 @@ pub struct ClaimVesting<'info> {
 /// * `AstraError::VestingNotStarted` - Vesting period hasn't begun
 /// * `AstraError::InsufficientShares` - No locked shares to claim
 /// * `AstraError::NoSharesToClaim` - No shares available at current time
+/// * `AstraError::ClaimTooSmall` - Below MIN_VESTING_CLAIM_BPS of the seed (final claim exempt)
 /// * `AstraError::MathOverflow` - Arithmetic overflow in calculations
+/// * `AstraError::ClaimViaDistributor` - Combined claim on a distributor launch
 ///
 /// # Security
 /// - Uses reentrancy protection flag
 /// - All arithmetic uses checked operations with overflow protection
 /// - Uses u128 for intermediate calculations to prevent overflow
 pub fn handler(ctx: Context<ClaimVesting>) -> Result<()> {
+    ctx.accounts.config.check_not_paused()?;
+
     let launch = &mut ctx.accounts.launch;
     let position = &mut ctx.accounts.position;
 
     // Reentrancy protection
-    require!(!launch.operation_in_progress, AstraError::InvalidCalculation);
-    launch.operation_in_progress = true;
+    launch.begin_operation()?;
 
     // Check if vesting has started
     let vesting_start = launch.vesting_start.ok_or(AstraError::NotGraduated)?;
@@ -82,70 +123,17 @@ pub fn handler(ctx: Context<ClaimVesting>) -> Result<()> {
         return Err(AstraError::VestingNotStarted.into());
     }
 
-    // Calculate time elapsed, capped at vesting duration
-    let time_elapsed = now
-        .checked_sub(vesting_start)
-        .ok_or(AstraError::MathOverflow)?;
-    let capped_elapsed = time_elapsed.min(VESTING_DURATION_SECONDS);
-
     // IMPORTANT: Only SEED shares vest, not subsequent buy shares
-    // The seed_shares value is fixed at launch creation and never changes
-    let seed_shares = launch.creator_seed_shares;
-    let already_claimed = launch.creator_claimed_shares;
-
-    // If all seed shares have been claimed, nothing more to vest
-    let remaining_seed = seed_shares.saturating_sub(already_claimed);
-    if remaining_seed == 0 {
-        launch.operation_in_progress = false;
-        return Err(AstraError::NoSharesToClaim.into());
-    }
-
-    // Calculate total vested SEED shares using deterministic integer math (no f64)
-    // Formula: total_vested = seed_shares * capped_elapsed / VESTING_DURATION_SECONDS
-    // Using u128 to prevent overflow
-    let total_vested_seed = (seed_shares as u128)
-        .checked_mul(capped_elapsed as u128)
-        .ok_or(AstraError::MathOverflow)?
-        .checked_div(VESTING_DURATION_SECONDS as u128)
-        .ok_or(AstraError::MathOverflow)? as u64;
-
-    // Claimable = Total Vested Seed - Already Claimed
-    let claimable = total_vested_seed
-        .checked_sub(already_claimed)
-        .ok_or(AstraError::MathOverflow)?;
-
-    if claimable == 0 {
-        launch.operation_in_progress = false;
-        return Err(AstraError::NoSharesToClaim.into());
-    }
-
-    // Verify we don't claim more than currently locked (safety check)
-    require!(
-        claimable <= position.locked_shares,
-        AstraError::InvalidCalculation
-    );
-
-    // V7 STATE UPDATE: Move shares from locked to position.shares
-    // All shares in V7 are 100% unlocked once claimed
-    position.locked_shares = position
-        .locked_shares
-        .checked_sub(claimable)
-        .ok_or(AstraError::MathOverflow)?;
-    position.shares = position
-        .shares
-        .checked_add(claimable)
-        .ok_or(AstraError::MathOverflow)?;
-    position.vested_shares_claimed = position
-        .vested_shares_claimed
-        .checked_add(claimable)
-        .ok_or(AstraError::MathOverflow)?;
-    position.last_updated_at = now;
-
-    // Update launch-level tracking
-    launch.creator_claimed_shares = launch
-        .creator_claimed_shares
-        .checked_add(claimable)
-        .ok_or(AstraError::MathOverflow)?;
+    // Vested (linear/cliff, deterministic integer math) minus already claimed,
+    // rejected below MIN_VESTING_CLAIM_BPS unless it's the final claim,
+    // then moved from position.locked_shares to position.shares
+    let claimable = match launch.release_vested_shares(position, now) {
+        Ok(claimable) => claimable,
+        Err(err) => {
+            launch.end_operation();
+            return Err(err);
+        }
+    };
 
     emit!(crate::events::VestingClaimed {
         launch: launch.key(),
@@ -155,7 +143,67 @@ pub fn handler(ctx: Context<ClaimVesting>) -> Result<()> {
         timestamp: now,
     });
 
+    // Combined claim: fully vested and token accounts provided
+    let token_accounts = match (
+        &ctx.accounts.token_mint,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.launch_token_account,
+    ) {
+        (Some(_), Some(user_token_account), Some(launch_token_account)) => {
+            Some((user_token_account, launch_token_account))
+        }
+        _ => None,
+    };
+
+    if let Some((user_token_account, launch_token_account)) = token_accounts {
+        if launch.seed_fully_vested(now)? {
+            require!(
+                launch.claims_in_protocol(),
+                AstraError::ClaimViaDistributor
+            );
+            require!(!position.has_claimed_tokens, AstraError::AlreadyClaimed);
+
+            let amount = launch.take_token_claim(position.shares, None)?;
+
+            let launch_id_bytes = launch.launch_id.to_le_bytes();
+            let seeds = &[
+                b"launch",
+                launch.creator.as_ref(),
+                &launch_id_bytes,
+                &[launch.bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: launch_token_account.to_account_info(),
+                        to: user_token_account.to_account_info(),
+                        authority: launch.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+
+            position.has_claimed_tokens = true;
+            position.shares = 0;
+            launch.position_count = launch.position_count.saturating_sub(1);
+
+            emit!(crate::events::TokensClaimed {
+                launch: launch.key(),
+                user: ctx.accounts.user.key(),
+                tokens_claimed: amount,
+                timestamp: now,
+            });
+
+            // Recycle position rent to the creator (same as claim_tokens)
+            position.close(ctx.accounts.user.to_account_info())?;
+        }
+    }
+
     // Reset reentrancy flag
-    launch.operation_in_progress = false;
+    launch.end_operation();
     Ok(())
 }
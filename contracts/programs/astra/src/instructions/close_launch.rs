@@ -1,28 +1,75 @@
 use crate::errors::AstraError;
 use crate::state::*;
+use crate::transfer::{transfer_from_pda, withdrawable_lamports};
 use anchor_lang::prelude::*;
 
 /// Closes a launch account after all refunds have been processed
-/// Recovers rent to the caller (incentive for janitor bot)
+///
+/// The creator position is settled like any other: its refund returns the seed
+/// (creator_seed_sol, backing locked_shares), so the curve can't drain to zero
+/// while the creator's seed is still outstanding.
+/// Recovers rent to the caller (incentive for janitor bot), or to the
+/// janitor rewards wallet when one is configured. Anything above rent
+/// (refund rounding dust, stray transfers) is swept to the treasury first,
+/// so a janitor never collects more than the rent. The fee escrow must already
+/// be closed by sweep_fee_escrow, so no creator fees are left behind.
 #[derive(Accounts)]
 pub struct CloseLaunch<'info> {
     #[account(mut)]
     pub caller: Signer<'info>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_authorized_janitor(&caller.key()) @ AstraError::UnauthorizedKeeper
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// CHECK: Receives reclaimed rent - the caller, or the janitor rewards wallet
+    #[account(
+        mut,
+        constraint = rent_recipient.key() == config.janitor_rent_recipient(&caller.key())
+            @ AstraError::InvalidRentRecipient
+    )]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Treasury receiving any dust above rent - verified against config
+    #[account(mut, address = config.protocol_fee_wallet)]
+    pub protocol_fee_wallet: UncheckedAccount<'info>,
+
     #[account(
         mut,
-        close = caller,
+        close = rent_recipient,
         constraint = launch.refund_mode @ AstraError::RefundModeNotActive,
-        constraint = launch.total_shares == 0 @ AstraError::LaunchNotEmpty,
-        constraint = launch.total_sol == 0 @ AstraError::LaunchNotEmpty,
+        // All positions settled, creator seed included (see Launch::settle_refund)
+        constraint = launch.can_close() @ AstraError::LaunchNotEmpty,
     )]
     pub launch: Account<'info, Launch>,
+
+    /// CHECK: The launch's fee escrow - must be closed (see sweep_fee_escrow)
+    #[account(
+        seeds = [b"fee_escrow", launch.key().as_ref()],
+        bump,
+        constraint = fee_escrow.data_is_empty() @ AstraError::FeeEscrowNotSwept
+    )]
+    pub fee_escrow: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<CloseLaunch>) -> Result<()> {
+    // Sweep dust above rent to the treasury; Anchor's close then sends only
+    // the rent to the rent recipient
+    let launch_info = ctx.accounts.launch.to_account_info();
+    let dust_swept = withdrawable_lamports(&launch_info)?;
+    transfer_from_pda(
+        &launch_info,
+        &ctx.accounts.protocol_fee_wallet.to_account_info(),
+        dust_swept,
+    )?;
+
     emit!(crate::events::LaunchClosed {
         launch: ctx.accounts.launch.key(),
         caller: ctx.accounts.caller.key(),
+        dust_swept,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
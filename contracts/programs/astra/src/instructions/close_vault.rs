@@ -0,0 +1,68 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Closes a wound-down vault and its LP token account (authority only)
+/// Only once all LP has been withdrawn/migrated - rent returns to the authority
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"vault", vault.launch.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        token::mint = vault.lp_mint,
+        token::authority = vault
+    )]
+    pub vault_lp_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CloseVault>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+
+    // Never close a vault with outstanding LP
+    require!(
+        vault.can_close(ctx.accounts.vault_lp_token.amount),
+        AstraError::VaultNotEmpty
+    );
+
+    let seeds = &[b"vault", vault.launch.as_ref(), &[vault.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.vault_lp_token.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: vault.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    emit!(crate::events::VaultClosed {
+        vault: vault.key(),
+        launch: vault.launch,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    // Vault account closed via `close = authority` constraint
+    Ok(())
+}
@@ -1,4 +1,7 @@
-use crate::constants::{BPS_DENOMINATOR, MAX_SEED_USD, MIN_SEED_USD, TOTAL_FEE_BPS};
+use crate::constants::{
+    BPS_DENOMINATOR, MAX_CREATOR_TRADE_COOLDOWN_SECONDS, MAX_FREE_FEE_BUYERS, TOTAL_FEE_BPS,
+    VESTING_DURATION_SECONDS,
+};
 use crate::curve;
 use crate::errors::AstraError;
 use crate::state::*;
@@ -13,8 +16,7 @@ pub struct CreateLaunch<'info> {
     #[account(
         mut,
         seeds = [b"config"],
-        bump = config.bump,
-        constraint = !config.paused @ AstraError::ProtocolPaused
+        bump = config.bump
     )]
     pub config: Account<'info, GlobalConfig>,
 
@@ -36,6 +38,16 @@ pub struct CreateLaunch<'info> {
     )]
     pub creator_position: Account<'info, Position>,
 
+    /// Creator fee escrow - keeps creator fees out of the curve reserve
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + FeeEscrow::INIT_SPACE,
+        seeds = [b"fee_escrow", launch.key().as_ref()],
+        bump
+    )]
+    pub fee_escrow: Account<'info, FeeEscrow>,
+
     /// Creator stats - initialized if first launch
     #[account(
         init_if_needed,
@@ -46,6 +58,20 @@ pub struct CreateLaunch<'info> {
     )]
     pub creator_stats: Account<'info, CreatorStats>,
 
+    /// Creator launch index page this launch is appended to
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorLaunches::INIT_SPACE,
+        seeds = [
+            b"creator_launches",
+            creator.key().as_ref(),
+            CreatorLaunches::page_for(creator_stats.total_launches).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub creator_launches: Box<Account<'info, CreatorLaunches>>,
+
     /// CHECK: Protocol fee wallet verified against config
     #[account(mut, address = config.protocol_fee_wallet)]
     pub protocol_fee_wallet: UncheckedAccount<'info>,
@@ -58,42 +84,78 @@ pub struct CreateLaunchArgs {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    /// Optional SHA-256 of the JSON at `uri`, for client-side integrity checks
+    pub metadata_hash: Option<[u8; 32]>,
+    /// Optional bound on the size of the content at `uri` (1..=MAX_METADATA_FETCH_BYTES)
+    pub max_metadata_bytes: Option<u32>,
     pub seed_lamports: u64,
+    /// Optional external distributor for holder tokens (e.g. Merkle-drop)
+    /// Must be on config.allowed_distributors
+    pub distributor: Option<Pubkey>,
+    /// Seconds after creation the creator can't buy/sell their own launch (0 = none)
+    pub creator_trade_cooldown: i64,
+    /// Seconds after creation before public buys open (0..=MAX_TRADING_START_DELAY_SECONDS)
+    pub trading_start_delay: i64,
+    /// Seed vesting mode (Linear or Cliff)
+    pub vesting_mode: VestingMode,
+    /// Linear mode cliff in seconds (0..=VESTING_DURATION_SECONDS)
+    pub vesting_cliff_seconds: i64,
+    /// Opt-in circuit breaker: freeze buys if hourly net outflow exceeds this share of total_sol (0 = off)
+    pub sell_pressure_limit_bps: u64,
+    /// Optional cap on concurrent holders, creator included (None = uncapped)
+    pub max_holders: Option<u64>,
+    /// First N unique buyers pay no fees on the first FEE_FREE_BUY_CAP_LAMPORTS
+    /// of their first buy (0..=MAX_FREE_FEE_BUYERS)
+    pub free_fee_buyer_count: u64,
+    /// True = seed is locked and vests; false (the bool default) = liquidity seed,
+    /// sellable immediately. Clients must opt in to vesting explicitly
+    pub seed_vests: bool,
+    /// Share of the supply burned at graduation, in bps (0..=MAX_BURN_BPS)
+    pub burn_bps: u64,
 }
 
 pub fn handler(ctx: Context<CreateLaunch>, args: CreateLaunchArgs) -> Result<()> {
+    ctx.accounts.config.check_not_paused()?;
+
     let config = &mut ctx.accounts.config;
     let launch = &mut ctx.accounts.launch;
     let position = &mut ctx.accounts.creator_position;
 
     // 1. Validation
+    Launch::validate_name(&args.name)?;
+    let symbol = Launch::normalize_symbol(&args.symbol)?;
+    Launch::validate_max_metadata_bytes(args.max_metadata_bytes)?;
+    Launch::validate_burn_bps(args.burn_bps)?;
+    Launch::validate_uri(&args.uri)?;
+    config.check_distributor(args.distributor)?;
+    require!(args.seed_lamports > 0, AstraError::InvalidCalculation);
     require!(
-        args.name.len() > 0 && args.name.len() <= 50,
-        AstraError::InvalidCalculation
+        (0..=MAX_CREATOR_TRADE_COOLDOWN_SECONDS).contains(&args.creator_trade_cooldown),
+        AstraError::InvalidCreatorCooldown
     );
     require!(
-        args.symbol.len() > 0 && args.symbol.len() <= 10,
-        AstraError::InvalidCalculation
+        (0..=VESTING_DURATION_SECONDS).contains(&args.vesting_cliff_seconds),
+        AstraError::InvalidVestingCliff
     );
     require!(
-        args.uri.len() > 0 && args.uri.len() <= 200,
-        AstraError::InvalidCalculation
+        args.sell_pressure_limit_bps <= BPS_DENOMINATOR,
+        AstraError::InvalidSellPressureLimit
+    );
+    require!(
+        args.max_holders.is_none_or(|max| max >= 1),
+        AstraError::InvalidHolderCap
+    );
+    require!(
+        args.free_fee_buyer_count <= MAX_FREE_FEE_BUYERS,
+        AstraError::InvalidFreeFeeBuyerCount
     );
-    require!(args.seed_lamports > 0, AstraError::InvalidCalculation);
 
-    // Check against USD minimum (converted to lamports)
-    let min_lamports = config
-        .usd_to_lamports(MIN_SEED_USD)
-        .ok_or(AstraError::PriceOracleUnavailable)?;
+    // Check against USD bounds (converted to lamports, tight price confidence only)
+    let (min_lamports, max_lamports) = config.seed_bounds_lamports()?;
     require!(
         args.seed_lamports >= min_lamports,
         AstraError::SeedAmountTooLow
     );
-
-    // Check against USD maximum (converted to lamports)
-    let max_lamports = config
-        .usd_to_lamports(MAX_SEED_USD)
-        .ok_or(AstraError::PriceOracleUnavailable)?;
     require!(
         args.seed_lamports <= max_lamports,
         AstraError::SeedAmountTooHigh
@@ -118,35 +180,53 @@ pub fn handler(ctx: Context<CreateLaunch>, args: CreateLaunchArgs) -> Result<()>
     launch.launch_id = config.total_launches;
     launch.creator = ctx.accounts.creator.key();
     launch.name = args.name.clone();
-    launch.symbol = args.symbol.clone();
+    launch.symbol = symbol;
     launch.uri = args.uri;
+    launch.metadata_hash = args.metadata_hash;
+    launch.max_metadata_bytes = args.max_metadata_bytes;
+    launch.metadata_updates_used = 0;
+    launch.distributor = args.distributor;
+    launch.burn_bps = args.burn_bps;
+    launch.creator_trade_cooldown = args.creator_trade_cooldown;
+    launch.vesting_mode = args.vesting_mode;
+    launch.vesting_cliff_seconds = args.vesting_cliff_seconds;
+    launch.sell_pressure_limit_bps = args.sell_pressure_limit_bps;
+    launch.max_holders = args.max_holders;
+    launch.free_fee_buyer_count = args.free_fee_buyer_count;
 
     // V7: All shares go to total_shares (no locked/unlocked split)
     launch.total_shares = shares;
     launch.total_sol = net_deposit;
 
-    // Creator seed tracked separately for vesting
-    launch.creator_seed_shares = shares;
-    launch.creator_seed_sol = net_deposit;
-
     launch.graduated = false;
     launch.refund_mode = false;
     launch.creator_accrued_fees = 0;
     launch.protocol_accrued_fees = 0;
     launch.created_at = Clock::get()?.unix_timestamp;
+    launch.trading_starts_at =
+        Launch::trading_start_after(launch.created_at, args.trading_start_delay)?;
+    launch.flow_window_start = launch.created_at;
+    launch.flow_window_base_sol = net_deposit;
+    launch.position_count = 1; // Creator position
+    launch.holder_count = 1; // Creator holds the seed shares
+    launch.holder_count_backfilled = true; // Tracked from creation - nothing to backfill
     launch.bump = ctx.bumps.launch;
 
     // 5. Initialize Creator Position (V7 Simplified)
     position.launch = launch.key();
     position.user = ctx.accounts.creator.key();
-    position.shares = 0; // Will be set after vesting
-    position.sol_basis = 0;
-    position.locked_shares = shares; // All seed shares locked for vesting
     position.vested_shares_claimed = 0;
+    // Vesting seed: locked_shares + creator_seed_*; liquidity seed: plain shares
+    launch.assign_seed(position, shares, net_deposit, args.seed_vests);
     position.first_buy_at = launch.created_at;
     position.last_updated_at = launch.created_at;
     position.bump = ctx.bumps.creator_position;
 
+    // Fee escrow
+    let fee_escrow = &mut ctx.accounts.fee_escrow;
+    fee_escrow.launch = launch.key();
+    fee_escrow.bump = ctx.bumps.fee_escrow;
+
     // 6. Initialize/Update Creator Stats
     let creator_stats = &mut ctx.accounts.creator_stats;
     if creator_stats.creator == Pubkey::default() {
@@ -156,7 +236,17 @@ pub fn handler(ctx: Context<CreateLaunch>, args: CreateLaunchArgs) -> Result<()>
         creator_stats.total_launches = 0;
         creator_stats.bump = ctx.bumps.creator_stats;
     }
-    creator_stats.record_launch();
+
+    // Append to the creator's launch index (page derived before record_launch)
+    let creator_launches = &mut ctx.accounts.creator_launches;
+    if creator_launches.creator == Pubkey::default() {
+        creator_launches.creator = ctx.accounts.creator.key();
+        creator_launches.page = CreatorLaunches::page_for(creator_stats.total_launches);
+        creator_launches.bump = ctx.bumps.creator_launches;
+    }
+    creator_launches.record_launch(launch.key())?;
+
+    creator_stats.record_launch(launch.created_at);
 
     // 7. Transfer Protocol Fee
     system_program::transfer(
@@ -188,8 +278,12 @@ pub fn handler(ctx: Context<CreateLaunch>, args: CreateLaunchArgs) -> Result<()>
         creator: launch.creator,
         name: launch.name.clone(),
         symbol: launch.symbol.clone(),
+        uri: launch.uri.clone(),
+        metadata_hash: launch.metadata_hash,
+        max_metadata_bytes: launch.max_metadata_bytes,
         seed_lamports: args.seed_lamports,
         seed_shares: shares,
+        trading_starts_at: launch.trading_starts_at,
         timestamp: launch.created_at,
     });
 
@@ -0,0 +1,11 @@
+use crate::curve;
+use anchor_lang::prelude::*;
+
+/// No-account curve math check
+/// Run after upgrades - fails the transaction if buy_quote/buy_return drift apart
+#[derive(Accounts)]
+pub struct CurveSelftest {}
+
+pub fn handler(_ctx: Context<CurveSelftest>) -> Result<()> {
+    curve::selftest()
+}
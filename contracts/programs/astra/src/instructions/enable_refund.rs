@@ -1,4 +1,3 @@
-use crate::constants::LAUNCH_DURATION_SECONDS;
 use crate::errors::AstraError;
 use crate::events::RefundEnabled;
 use crate::state::Launch;
@@ -18,6 +17,7 @@ use anchor_lang::prelude::*;
 /// # Effects
 /// - Sets `refund_mode = true` on the launch
 /// - Records `refund_enabled_at` timestamp
+/// - Snapshots `position_count` into `positions_remaining`
 /// - Emits `RefundEnabled` event
 #[derive(Accounts)]
 pub struct EnableRefund<'info> {
@@ -29,21 +29,11 @@ pub struct EnableRefund<'info> {
     #[account(
         mut,
         constraint = !launch.graduated @ AstraError::AlreadyGraduated,
-        constraint = !launch.refund_mode @ AstraError::RefundModeAlreadyActive,
-        constraint = is_launch_expired(&launch) @ AstraError::LaunchNotExpired
+        constraint = !launch.refund_mode @ AstraError::RefundModeAlreadyActive
     )]
     pub launch: Account<'info, Launch>,
 }
 
-/// Checks if the launch has expired (7 days since creation)
-fn is_launch_expired(launch: &Launch) -> bool {
-    if let Ok(clock) = Clock::get() {
-        clock.unix_timestamp >= launch.created_at + LAUNCH_DURATION_SECONDS
-    } else {
-        false
-    }
-}
-
 /// Handler for enabling refund mode on an expired launch
 ///
 /// This allows holders to claim refunds of their SOL proportional to their shares.
@@ -51,13 +41,18 @@ pub fn handler(ctx: Context<EnableRefund>) -> Result<()> {
     let launch = &mut ctx.accounts.launch;
     let clock = Clock::get()?;
 
-    // Enable refund mode
-    launch.refund_mode = true;
-    launch.refund_enabled_at = Some(clock.unix_timestamp);
+    require!(
+        launch.refund_eligible(clock.unix_timestamp)?,
+        AstraError::LaunchNotExpired
+    );
+
+    // Enable refund mode (re-checks graduation precedence on the state itself)
+    launch.enter_refund_mode(clock.unix_timestamp)?;
 
     // Emit event
     emit!(RefundEnabled {
         launch: launch.key(),
+        positions_remaining: launch.positions_remaining,
         timestamp: clock.unix_timestamp,
     });
 
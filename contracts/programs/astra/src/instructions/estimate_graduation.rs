@@ -0,0 +1,46 @@
+use crate::constants::GRADUATION_MARKET_CAP_USD;
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Read-only graduation progress query
+/// Emits remaining USD to the market cap target and the rolling hourly volume
+/// so clients can extrapolate an ETA. Does not modify any state.
+#[derive(Accounts)]
+pub struct EstimateGraduation<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        constraint = !launch.graduated @ AstraError::AlreadyGraduated,
+        constraint = !launch.refund_mode @ AstraError::RefundModeActive
+    )]
+    pub launch: Account<'info, Launch>,
+}
+
+pub fn handler(ctx: Context<EstimateGraduation>) -> Result<()> {
+    let launch = &ctx.accounts.launch;
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+
+    let market_cap_usd = launch
+        .graduation_market_cap_usd(config.sol_price_usd)
+        .ok_or(AstraError::PriceOracleUnavailable)?;
+    let remaining_usd = GRADUATION_MARKET_CAP_USD.saturating_sub(market_cap_usd);
+
+    let volume_last_hour = launch.current_volume(now)?;
+    let volume_last_hour_usd = config
+        .lamports_to_usd(volume_last_hour)
+        .ok_or(AstraError::MathOverflow)?;
+
+    emit!(crate::events::GraduationEstimate {
+        launch: launch.key(),
+        market_cap_usd,
+        remaining_usd,
+        volume_last_hour,
+        volume_last_hour_usd,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -9,11 +9,31 @@
 //! - Recovery from edge cases where gates are stuck
 //!
 //! SECURITY: This is a powerful function that should be used sparingly.
+//! Every call bumps config.force_graduations_count and emits ForceGraduated.
 //! All standard graduation operations should use the normal `graduate` instruction
 //! which respects the graduation gates checked by the cron job.
+//!
+//! ORDERING: Checks-effects-interactions. The graduation itself (launch, vault,
+//! creator_stats and the config force-graduation count) is written before the
+//! external Raydium CPI, so a re-entrant call would already see
+//! `graduated = true` and fail the `AlreadyGraduated` constraint. Only what
+//! Raydium decides - LP minted, opening reserves and the fee baseline - is read
+//! back and recorded after it. The vault and token mint are also `init`
+//! accounts, so a second invocation in the same transaction cannot succeed
+//! either way.
+//!
+//! RETRY SAFETY: A failed instruction is rolled back atomically by the runtime,
+//! so a failed graduation never leaves partially-created mint/vault accounts
+//! behind and can simply be retried. The only accounts that can exist ahead of
+//! a retry are ones a third party can create independently (the launch's wSOL
+//! ATA), which use `init_if_needed`.
 
-use crate::constants::{TOKENS_FOR_LP, TOTAL_SUPPLY};
+use crate::constants::{TOKEN_DECIMALS, TOKEN_DECIMALS_SCALE, TOTAL_SUPPLY};
 use crate::errors::AstraError;
+use crate::instructions::graduate::{
+    check_raydium_pool_inputs, log_raydium_cpi_error, pool_state_uninitialized, token_balance,
+    RaydiumPool,
+};
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
@@ -33,7 +53,7 @@ pub struct ForceGraduate<'info> {
     )]
     pub authority: Signer<'info>,
 
-    #[account(seeds = [b"config"], bump = config.bump)]
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, GlobalConfig>,
 
     #[account(
@@ -47,7 +67,7 @@ pub struct ForceGraduate<'info> {
     #[account(
         init,
         payer = authority,
-        mint::decimals = 9,
+        mint::decimals = TOKEN_DECIMALS,
         mint::authority = launch
     )]
     pub token_mint: Box<Account<'info, Mint>>,
@@ -62,14 +82,31 @@ pub struct ForceGraduate<'info> {
     pub launch_token_account: Box<Account<'info, TokenAccount>>,
 
     /// wSOL Token Account (temp for wrapping SOL)
+    /// init_if_needed: this ATA's address is derivable before graduation, so
+    /// anyone could pre-create it and wedge a plain `init` forever
     #[account(
-        init,
+        init_if_needed,
         payer = authority,
         associated_token::mint = token_0_mint,
         associated_token::authority = launch
     )]
     pub wsol_account: Box<Account<'info, TokenAccount>>,
 
+    /// Holder distributor (e.g. Merkle-drop program authority)
+    /// Required when launch.distributor is set
+    /// CHECK: Verified against launch.distributor
+    #[account(constraint = Some(distributor.key()) == launch.distributor @ AstraError::InvalidDistributor)]
+    pub distributor: Option<UncheckedAccount<'info>>,
+
+    /// Distributor's token account - receives the holder pool at graduation
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = token_mint,
+        associated_token::authority = distributor
+    )]
+    pub distributor_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
     /// Vault account (LP holder)
     #[account(
         init,
@@ -90,15 +127,21 @@ pub struct ForceGraduate<'info> {
     pub vault_lp_token: Box<Account<'info, TokenAccount>>,
 
     // Raydium CPMM Pool Creation Accounts
-    /// CHECK: Validated by Raydium CPI
-    #[account(mut)]
+    /// CHECK: Must be allowlisted in config, then validated by Raydium CPI
+    #[account(
+        mut,
+        constraint = config.is_amm_config_allowed(&amm_config.key()) @ AstraError::InvalidAmmConfig
+    )]
     pub amm_config: UncheckedAccount<'info>,
 
     /// CHECK: Validated by Raydium CPI
     pub amm_authority: UncheckedAccount<'info>,
 
-    /// CHECK: Validated by Raydium CPI
-    #[account(mut)]
+    /// CHECK: Must be uninitialized; created and validated by Raydium CPI
+    #[account(
+        mut,
+        constraint = pool_state_uninitialized(&pool_state) @ AstraError::PoolAlreadyExists
+    )]
     pub pool_state: UncheckedAccount<'info>,
 
     /// CHECK: Validated by Raydium CPI - wSOL mint
@@ -156,32 +199,84 @@ pub fn handler(ctx: Context<ForceGraduate>) -> Result<()> {
     let launch = &mut ctx.accounts.launch;
     let vault = &mut ctx.accounts.vault;
 
+    // ------ CHECKS ------
     // Reentrancy protection
-    require!(
-        !launch.operation_in_progress,
-        AstraError::InvalidCalculation
-    );
-    launch.operation_in_progress = true;
+    // (transaction-scoped: force_graduate completes in one transaction and a
+    // failure reverts the flag with everything else - nothing spans transactions)
+    launch.begin_operation()?;
+
+    // Never graduate a degenerate (zero-share or zero-SOL) launch:
+    // claim_tokens divides by the total_shares snapshot
+    require!(launch.has_liquidity(), AstraError::EmptyLaunch);
 
-    // V7: Use simplified launch.total_sol (no locked/unlocked split)
-    let sol_amount = launch.total_sol;
-    require!(sol_amount > 0, AstraError::InvalidCalculation);
+    // V7: Use simplified launch.total_sol (no locked/unlocked split),
+    // deepened by any booster SOL (unmatched - the token side is fixed)
+    let curve_sol = launch.total_sol;
+    let sol_amount = launch.graduation_pool_sol(curve_sol)?;
+    // Holder pool / LP / burn split of the minted supply (see Launch::burn_bps)
+    let allocation = launch.token_allocation()?;
+
+    let init_amount_0 = sol_amount;
+    let init_amount_1 = allocation.lp; // 200M with 9 decimals, less any burn
+
+    check_raydium_pool_inputs(
+        &ctx.accounts.amm_config,
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_0_mint.key(),
+        &ctx.accounts.token_1_mint.key(),
+        init_amount_0,
+        init_amount_1,
+    )?;
 
     msg!("FORCE GRADUATE: Launch {}", launch.key());
     msg!("Authority: {}", ctx.accounts.authority.key());
     msg!("Total Shares: {}", launch.total_shares);
     msg!("Total SOL: {}", sol_amount);
 
-    // PDA Seeds
+    // PDA Seeds (copied out so launch state can be updated before the CPIs)
+    let launch_creator = launch.creator;
+    let launch_id_bytes = launch.launch_id.to_le_bytes();
+    let launch_bump = [launch.bump];
     let launch_seeds = &[
         b"launch",
-        launch.creator.as_ref(),
-        &launch.launch_id.to_le_bytes(),
-        &[launch.bump],
+        launch_creator.as_ref(),
+        &launch_id_bytes,
+        &launch_bump,
     ];
     let signer_seeds = &[&launch_seeds[..]];
 
-    // 1. Wrap SOL
+    // ------ EFFECTS ------
+    let now = Clock::get()?.unix_timestamp;
+
+    // 1. Initialize Vault (lp_balance is set from the pool after the CPI)
+    let pool_address = ctx.accounts.pool_state.key();
+
+    vault.launch = launch.key();
+    vault.creator = launch.creator;
+    vault.lp_mint = ctx.accounts.lp_mint.key();
+    vault.activated = true;
+    vault.total_yield_collected = 0;
+    vault.last_poke_at = now;
+    vault.bump = ctx.bumps.vault;
+
+    // 2. Update Launch State
+    // V7: Store total shares at graduation for proportional distribution
+    launch.mark_graduated(
+        now,
+        ctx.accounts.token_mint.key(),
+        pool_address,
+        vault.key(),
+    )?;
+
+    // 3. Increment Creator's graduated count
+    let creator_stats = &mut ctx.accounts.creator_stats;
+    creator_stats.record_graduation();
+
+    // 4. Count the override
+    let force_graduations_count = ctx.accounts.config.record_force_graduation()?;
+
+    // ------ INTERACTIONS ------
+    // 5. Wrap SOL
     anchor_lang::system_program::transfer(
         CpiContext::new_with_signer(
             ctx.accounts.system_program.to_account_info(),
@@ -202,7 +297,7 @@ pub fn handler(ctx: Context<ForceGraduate>) -> Result<()> {
         signer_seeds,
     ))?;
 
-    // 2. Mint Total Supply (1B tokens with 9 decimals)
+    // 6. Mint Total Supply (1B tokens with 9 decimals)
     token::mint_to(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
@@ -213,19 +308,53 @@ pub fn handler(ctx: Context<ForceGraduate>) -> Result<()> {
             },
             signer_seeds,
         ),
-        TOTAL_SUPPLY * 1_000_000_000, // 1B with 9 decimals
+        TOTAL_SUPPLY * TOKEN_DECIMALS_SCALE, // 1B with 9 decimals
     )?;
 
-    // 3. Create Raydium CPMM Pool
-    let init_amount_0 = sol_amount;
-    let init_amount_1 = TOKENS_FOR_LP * 1_000_000_000; // 200M with 9 decimals
-
-    require!(init_amount_1 > 0, AstraError::InvalidCalculation);
-
+    // Burn the deflationary share (if any) before anything leaves the launch account
+    if allocation.burned > 0 {
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    from: ctx.accounts.launch_token_account.to_account_info(),
+                    authority: launch.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            allocation.burned,
+        )?;
+    }
+
+    // Hand holder tokens to the external distributor (if configured)
+    // Holders then claim from the distributor instead of claim_tokens
+    if launch.distributor.is_some() {
+        let distributor_token_account = ctx
+            .accounts
+            .distributor_token_account
+            .as_ref()
+            .ok_or(AstraError::InvalidDistributor)?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.launch_token_account.to_account_info(),
+                    to: distributor_token_account.to_account_info(),
+                    authority: launch.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            allocation.holders, // 800M with 9 decimals, less any burn
+        )?;
+    }
+
+    // 7. Create Raydium CPMM Pool
     let mut instruction_data = vec![175, 175, 109, 31, 56, 222, 53, 138];
     instruction_data.extend_from_slice(&init_amount_0.to_le_bytes());
     instruction_data.extend_from_slice(&init_amount_1.to_le_bytes());
-    instruction_data.extend_from_slice(&Clock::get()?.unix_timestamp.to_le_bytes());
+    instruction_data.extend_from_slice(&now.to_le_bytes());
 
     let account_metas = vec![
         AccountMeta::new(launch.key(), true),
@@ -277,39 +406,23 @@ pub fn handler(ctx: Context<ForceGraduate>) -> Result<()> {
             ctx.accounts.rent.to_account_info(),
         ],
         signer_seeds,
+    )
+    .map_err(log_raydium_cpi_error)?;
+
+    // Opening reserves and LP, read back from the accounts Raydium just wrote
+    ctx.accounts.vault_lp_token.reload()?;
+    let outcome = GraduationRecord::from_reserves(
+        token_balance(&ctx.accounts.token_0_vault)?,
+        token_balance(&ctx.accounts.token_1_vault)?,
+        ctx.accounts.vault_lp_token.amount,
     )?;
-
-    // 4. Initialize Vault
-    let estimated_lp_tokens = ((init_amount_0 as u128)
-        .checked_mul(init_amount_1 as u128)
-        .ok_or(AstraError::MathOverflow)?)
-    .isqrt() as u64;
-
-    let pool_address = ctx.accounts.pool_state.key();
-
-    vault.launch = launch.key();
-    vault.creator = launch.creator;
-    vault.lp_mint = ctx.accounts.lp_mint.key();
-    vault.lp_balance = estimated_lp_tokens;
-    vault.activated = true;
-    vault.total_yield_collected = 0;
-    vault.last_poke_at = Clock::get()?.unix_timestamp;
-    vault.bump = ctx.bumps.vault;
-
-    // 5. Update Launch State
-    launch.graduated = true;
-    launch.graduated_at = Some(Clock::get()?.unix_timestamp);
-    launch.vesting_start = Some(Clock::get()?.unix_timestamp);
-    launch.token_mint = Some(ctx.accounts.token_mint.key());
-    launch.pool_address = Some(pool_address);
-    launch.vault = Some(vault.key());
-
-    // V7: Store total shares at graduation for proportional distribution
-    launch.total_shares_at_graduation = launch.total_shares;
-
-    // 6. Increment Creator's graduated count
-    let creator_stats = &mut ctx.accounts.creator_stats;
-    creator_stats.record_graduation();
+    vault.lp_balance = outcome.lp_minted;
+    // Fee baseline for poke: the pool's value per LP as opened
+    vault.lp_value_checkpoint = RaydiumPool::load(&ctx.accounts.pool_state)?
+        .lp_value(&ctx.accounts.token_0_vault, &ctx.accounts.token_1_vault)?;
+    vault.booster = launch.booster;
+    vault.booster_lp = Vault::booster_lp_share(outcome.lp_minted, curve_sol, launch.boosted_sol)?;
+    launch.record_graduation_outcome(outcome)?;
 
     emit!(crate::events::Graduated {
         launch: launch.key(),
@@ -317,13 +430,28 @@ pub fn handler(ctx: Context<ForceGraduate>) -> Result<()> {
         pool_address,
         lp_mint: ctx.accounts.lp_mint.key(),
         sol_for_lp: sol_amount,
+        boosted_sol: launch.boosted_sol,
+        booster_lp: vault.booster_lp,
+        tokens_burned: allocation.burned,
         total_shares: launch.total_shares_at_graduation,
+        holders_at_graduation: launch.holders_at_graduation,
+        vesting: launch.vesting_schedule().ok_or(AstraError::NotGraduated)?,
+        outcome,
+        timestamp: launch.graduated_at.unwrap(),
+    });
+
+    emit!(crate::events::ForceGraduated {
+        launch: launch.key(),
+        authority: ctx.accounts.authority.key(),
+        total_sol: curve_sol,
+        holders_at_graduation: launch.holders_at_graduation,
+        force_graduations_count,
         timestamp: launch.graduated_at.unwrap(),
     });
 
     msg!("FORCE GRADUATE COMPLETE: Launch {} graduated", launch.key());
 
     // Reset reentrancy flag
-    launch.operation_in_progress = false;
+    launch.end_operation();
     Ok(())
 }
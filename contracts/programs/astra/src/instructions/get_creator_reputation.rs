@@ -0,0 +1,28 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Read-only creator reputation query
+/// Emits CreatorStats::reputation_score so frontends can rank creators
+#[derive(Accounts)]
+pub struct GetCreatorReputation<'info> {
+    #[account(
+        seeds = [b"creator_stats", creator_stats.creator.as_ref()],
+        bump = creator_stats.bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+}
+
+pub fn handler(ctx: Context<GetCreatorReputation>) -> Result<()> {
+    let creator_stats = &ctx.accounts.creator_stats;
+    let now = Clock::get()?.unix_timestamp;
+
+    emit!(crate::events::CreatorReputation {
+        creator: creator_stats.creator,
+        score: creator_stats.reputation_score(now),
+        graduated_count: creator_stats.graduated_count,
+        total_launches: creator_stats.total_launches,
+        timestamp: now,
+    });
+
+    Ok(())
+}
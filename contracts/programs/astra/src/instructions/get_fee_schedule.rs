@@ -0,0 +1,16 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Read-only fee schedule query
+/// Emits the on-chain fee structure so clients don't hardcode it
+#[derive(Accounts)]
+pub struct GetFeeSchedule<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<GetFeeSchedule>) -> Result<()> {
+    emit!(ctx.accounts.config.fee_schedule());
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use crate::errors::AstraError;
+use crate::events::TokensPerShare;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Read-only tokens-per-share query
+/// Emits the graduation ratio claim_tokens uses so holders can verify a claim
+#[derive(Accounts)]
+pub struct GetTokensPerShare<'info> {
+    #[account(constraint = launch.graduated @ AstraError::NotGraduated)]
+    pub launch: Account<'info, Launch>,
+}
+
+pub fn handler(ctx: Context<GetTokensPerShare>) -> Result<()> {
+    let launch = &ctx.accounts.launch;
+    let tokens_per_share = launch
+        .tokens_per_share()
+        .ok_or(AstraError::InvalidCalculation)?;
+
+    emit!(TokensPerShare {
+        launch: launch.key(),
+        tokens_per_share,
+        holder_token_pool: launch.token_allocation()?.holders,
+        total_shares_at_graduation: launch.total_shares_at_graduation,
+    });
+
+    Ok(())
+}
@@ -1,4 +1,34 @@
-use crate::constants::{TOKENS_FOR_LP, TOTAL_SUPPLY};
+//! Graduate instruction handler - V7
+//!
+//! Operator-driven graduation to a Raydium CPMM pool once the off-chain
+//! graduation gates pass. The minimum holder count, the creator concentration
+//! cap and the single-holder cap (GRADUATION_MAX_CONCENTRATION_BPS) are also
+//! enforced on-chain.
+//!
+//! WHALE GATE: Launch.largest_holder_shares is a high-water mark. If it is over
+//! the cap, the operator passes every position of the launch (non-signer
+//! remaining accounts, sorted by user) and graduate checks the true current
+//! maximum instead - a whale who sold down no longer blocks graduation, and no
+//! holder can be left out of the count.
+//!
+//! TWO-PHASE: The operator first calls `lock_graduation`, which snapshots
+//! total_sol/total_shares and freezes buys and sells. `graduate` then requires
+//! that lock and an unchanged curve, so same-block trades can't resize the pool.
+//!
+//! REFUND PRECEDENCE: Once a launch is refund-eligible (LAUNCH_DURATION_SECONDS
+//! after creation), holders may be waiting to refund even if nobody has called
+//! `enable_refund` yet. A late buy crossing the threshold doesn't change that -
+//! graduating such a launch takes the authority's `force_graduate` override.
+//!
+//! RETRY SAFETY: A failed instruction is rolled back atomically by the runtime,
+//! so a failed graduation never leaves partially-created mint/vault accounts
+//! behind and can simply be retried. The only accounts that can exist ahead of
+//! a retry are ones a third party can create independently (the launch's wSOL
+//! ATA), which use `init_if_needed`.
+
+use crate::constants::{
+    GRADUATION_MAX_CONCENTRATION_BPS, TOKEN_DECIMALS, TOKEN_DECIMALS_SCALE, TOTAL_SUPPLY,
+};
 use crate::errors::AstraError;
 use crate::state::*;
 use anchor_lang::prelude::*;
@@ -10,12 +40,158 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount};
 // Raydium CPMM Program ID
 pub const RAYDIUM_CPMM_PROGRAM: Pubkey = pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
 
+/// Pre-flight checks and context logging for the Raydium CPMM `initialize` CPI
+///
+/// A failing CPI aborts the whole transaction before control returns to us, so
+/// Raydium's own error codes can't be caught and remapped here. Instead the
+/// common operator mistakes are checked up front with descriptive errors, and
+/// the pool parameters are logged first so any Raydium-side failure is still
+/// diagnosable from the transaction logs.
+pub(crate) fn check_raydium_pool_inputs(
+    amm_config: &AccountInfo,
+    pool_state: &AccountInfo,
+    token_0_mint: &Pubkey,
+    token_1_mint: &Pubkey,
+    init_amount_0: u64,
+    init_amount_1: u64,
+) -> Result<()> {
+    msg!(
+        "Raydium initialize: amm_config={} pool_state={} amount_0={} amount_1={}",
+        amm_config.key(),
+        pool_state.key(),
+        init_amount_0,
+        init_amount_1
+    );
+
+    require_keys_eq!(
+        *amm_config.owner,
+        RAYDIUM_CPMM_PROGRAM,
+        AstraError::InvalidAmmConfig
+    );
+    // Raydium requires mints in ascending byte order
+    require!(
+        token_0_mint < token_1_mint,
+        AstraError::InvalidPoolTokenOrder
+    );
+    require!(
+        init_amount_0 > 0 && init_amount_1 > 0,
+        AstraError::InsufficientPoolAmounts
+    );
+
+    Ok(())
+}
+
+/// True if `pool_state` is still a blank system account Raydium can create
+///
+/// Checked as an account constraint so a double graduation, or a pool someone
+/// else already opened for this amm_config/mint pair, fails with
+/// PoolAlreadyExists before any graduation work instead of deep in the CPI.
+pub(crate) fn pool_state_uninitialized(pool_state: &AccountInfo) -> bool {
+    pool_state.owner == &anchor_lang::system_program::ID && pool_state.data_is_empty()
+}
+
+/// SPL token balance of an account written during this instruction by a CPI
+/// (e.g. Raydium's pool vaults, which we only hold as unchecked accounts)
+pub(crate) fn token_balance(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
+
+/// The parts of a Raydium CPMM `PoolState` poke needs, read by byte offset
+///
+/// Fixed zero-copy layout: 8-byte discriminator, ten pubkeys (amm_config,
+/// pool_creator, token_0_vault, token_1_vault, lp_mint, token_0_mint,
+/// token_1_mint, token_0_program, token_1_program, observation_key), five u8s
+/// (auth_bump, status, three decimals), then lp_supply and the fee counters.
+pub(crate) struct RaydiumPool {
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub lp_mint: Pubkey,
+    pub lp_supply: u64,
+    /// Protocol + fund fees sitting in each vault that don't belong to LPs
+    pub owed_fees_0: u64,
+    pub owed_fees_1: u64,
+}
+
+impl RaydiumPool {
+    const TOKEN_0_VAULT: usize = 8 + 2 * 32;
+    const TOKEN_1_VAULT: usize = 8 + 3 * 32;
+    const LP_MINT: usize = 8 + 4 * 32;
+    const LP_SUPPLY: usize = 8 + 10 * 32 + 5;
+    const LEN: usize = Self::LP_SUPPLY + 5 * 8;
+
+    /// Read a pool_state owned by the Raydium CPMM program
+    pub fn load(pool_state: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(
+            *pool_state.owner,
+            RAYDIUM_CPMM_PROGRAM,
+            AstraError::InvalidPoolAccounts
+        );
+        let data = pool_state.try_borrow_data()?;
+        require!(data.len() >= Self::LEN, AstraError::InvalidPoolAccounts);
+
+        let pubkey = |at: usize| Pubkey::new_from_array(data[at..at + 32].try_into().unwrap());
+        let word = |index: usize| {
+            let at = Self::LP_SUPPLY + index * 8;
+            u64::from_le_bytes(data[at..at + 8].try_into().unwrap())
+        };
+        // Words after lp_supply: protocol_fees_0/1, fund_fees_0/1
+        Ok(Self {
+            token_0_vault: pubkey(Self::TOKEN_0_VAULT),
+            token_1_vault: pubkey(Self::TOKEN_1_VAULT),
+            lp_mint: pubkey(Self::LP_MINT),
+            lp_supply: word(0),
+            owed_fees_0: word(1).checked_add(word(3)).ok_or(AstraError::MathOverflow)?,
+            owed_fees_1: word(2).checked_add(word(4)).ok_or(AstraError::MathOverflow)?,
+        })
+    }
+
+    /// LP-owned reserves: vault balances less the protocol/fund fees owed out of them
+    pub fn reserves(
+        &self,
+        token_0_vault: &AccountInfo,
+        token_1_vault: &AccountInfo,
+    ) -> Result<(u64, u64)> {
+        require_keys_eq!(
+            token_0_vault.key(),
+            self.token_0_vault,
+            AstraError::InvalidPoolAccounts
+        );
+        require_keys_eq!(
+            token_1_vault.key(),
+            self.token_1_vault,
+            AstraError::InvalidPoolAccounts
+        );
+        Ok((
+            token_balance(token_0_vault)?.saturating_sub(self.owed_fees_0),
+            token_balance(token_1_vault)?.saturating_sub(self.owed_fees_1),
+        ))
+    }
+
+    /// Current Vault::lp_value of this pool
+    pub fn lp_value(
+        &self,
+        token_0_vault: &AccountInfo,
+        token_1_vault: &AccountInfo,
+    ) -> Result<u128> {
+        let (reserve_0, reserve_1) = self.reserves(token_0_vault, token_1_vault)?;
+        Vault::lp_value(reserve_0, reserve_1, self.lp_supply)
+    }
+}
+
+/// Log a CPI error that surfaced before Raydium ran (account/privilege checks)
+pub(crate) fn log_raydium_cpi_error(err: ProgramError) -> Error {
+    msg!("Raydium initialize CPI rejected: {:?}", err);
+    AstraError::RaydiumCpiFailed.into()
+}
+
 #[derive(Accounts)]
 pub struct Graduate<'info> {
-    /// Only operator can call this
+    /// Only operator can call this (one of graduation_operators when a set is configured)
+    /// Further operator signers are passed as remaining accounts
     #[account(
         mut,
-        constraint = operator.key() == config.operator_wallet @ AstraError::Unauthorized
+        constraint = config.is_graduation_operator(&operator.key()) @ AstraError::Unauthorized
     )]
     pub operator: Signer<'info>,
 
@@ -29,11 +205,18 @@ pub struct Graduate<'info> {
     )]
     pub launch: Box<Account<'info, Launch>>,
 
+    /// Creator's position (seed + bought shares) for the concentration cap
+    #[account(
+        seeds = [b"position", launch.key().as_ref(), launch.creator.as_ref()],
+        bump = creator_position.bump
+    )]
+    pub creator_position: Box<Account<'info, Position>>,
+
     /// Token mint to be created
     #[account(
         init,
         payer = operator,
-        mint::decimals = 9,
+        mint::decimals = TOKEN_DECIMALS,
         mint::authority = launch
     )]
     pub token_mint: Box<Account<'info, Mint>>,
@@ -48,14 +231,31 @@ pub struct Graduate<'info> {
     pub launch_token_account: Box<Account<'info, TokenAccount>>,
 
     /// wSOL Token Account (temp for wrapping SOL)
+    /// init_if_needed: this ATA's address is derivable before graduation, so
+    /// anyone could pre-create it and wedge a plain `init` forever
     #[account(
-        init,
+        init_if_needed,
         payer = operator,
         associated_token::mint = token_0_mint,
         associated_token::authority = launch
     )]
     pub wsol_account: Box<Account<'info, TokenAccount>>,
 
+    /// Holder distributor (e.g. Merkle-drop program authority)
+    /// Required when launch.distributor is set
+    /// CHECK: Verified against launch.distributor
+    #[account(constraint = Some(distributor.key()) == launch.distributor @ AstraError::InvalidDistributor)]
+    pub distributor: Option<UncheckedAccount<'info>>,
+
+    /// Distributor's token account - receives the holder pool at graduation
+    #[account(
+        init,
+        payer = operator,
+        associated_token::mint = token_mint,
+        associated_token::authority = distributor
+    )]
+    pub distributor_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
     /// Vault account (LP holder)
     #[account(
         init,
@@ -76,15 +276,21 @@ pub struct Graduate<'info> {
     pub vault_lp_token: Box<Account<'info, TokenAccount>>,
 
     // Raydium CPMM Pool Creation Accounts
-    /// CHECK: Validated by Raydium CPI
-    #[account(mut)]
+    /// CHECK: Must be allowlisted in config, then validated by Raydium CPI
+    #[account(
+        mut,
+        constraint = config.is_amm_config_allowed(&amm_config.key()) @ AstraError::InvalidAmmConfig
+    )]
     pub amm_config: UncheckedAccount<'info>,
 
     /// CHECK: Validated by Raydium CPI
     pub amm_authority: UncheckedAccount<'info>,
 
-    /// CHECK: Validated by Raydium CPI
-    #[account(mut)]
+    /// CHECK: Must be uninitialized; created and validated by Raydium CPI
+    #[account(
+        mut,
+        constraint = pool_state_uninitialized(&pool_state) @ AstraError::PoolAlreadyExists
+    )]
     pub pool_state: UncheckedAccount<'info>,
 
     /// CHECK: Validated by Raydium CPI - wSOL mint
@@ -138,20 +344,82 @@ pub struct Graduate<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<Graduate>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, Graduate<'info>>) -> Result<()> {
     let launch = &mut ctx.accounts.launch;
     let vault = &mut ctx.accounts.vault;
 
     // Reentrancy protection
+    // (transaction-scoped: a failed graduation reverts it along with everything
+    // else; the graduation lock is what freezes trading across transactions)
+    launch.begin_operation()?;
+
+    // M-of-N operator approval: the payer plus any co-signing remaining accounts
+    let signers: Vec<Pubkey> = std::iter::once(ctx.accounts.operator.key())
+        .chain(
+            ctx.remaining_accounts
+                .iter()
+                .filter(|info| info.is_signer)
+                .map(|info| info.key()),
+        )
+        .collect();
+    ctx.accounts.config.check_graduation_signers(&signers)?;
+
+    // Refund-eligible launches only graduate via the force_graduate override
     require!(
-        !launch.operation_in_progress,
-        AstraError::InvalidCalculation
+        !launch.refund_eligible(Clock::get()?.unix_timestamp)?,
+        AstraError::GraduationRequiresOverride
     );
-    launch.operation_in_progress = true;
 
-    // V7: Use simplified launch.total_sol (no locked/unlocked split)
-    let sol_amount = launch.total_sol;
-    require!(sol_amount > 0, AstraError::InvalidCalculation);
+    // Distribution gate (trustless - force_graduate bypasses it)
+    require!(
+        launch.meets_holder_minimum(ctx.accounts.config.min_holders_for_graduation),
+        AstraError::NotEnoughHolders
+    );
+
+    // Anti-rug gate: creator can't control too much of the supply
+    let creator_position = &ctx.accounts.creator_position;
+    let creator_shares = creator_position
+        .shares
+        .checked_add(creator_position.locked_shares)
+        .ok_or(AstraError::MathOverflow)?;
+    require!(
+        launch.creator_within_concentration_cap(
+            creator_shares,
+            ctx.accounts.config.max_creator_concentration_bps
+        ),
+        AstraError::CreatorTooConcentrated
+    );
+
+    // Whale gate: the largest buyer position is within 10% of the supply.
+    // Over the high-water mark, every position proves the current maximum
+    // (trading is frozen by the lock, so it can't move before the pool exists)
+    if !launch.largest_holder_within_cap(GRADUATION_MAX_CONCENTRATION_BPS) {
+        let positions = ctx
+            .remaining_accounts
+            .iter()
+            .filter(|info| !info.is_signer)
+            .map(|info| Account::<Position>::try_from(info).map(Account::into_inner))
+            .collect::<Result<Vec<_>>>()?;
+        let launch_key = launch.key();
+        launch.prove_largest_holder(&launch_key, &positions)?;
+        require!(
+            launch.largest_holder_within_cap(GRADUATION_MAX_CONCENTRATION_BPS),
+            AstraError::HolderTooConcentrated
+        );
+    }
+
+    // Never graduate a degenerate (zero-share or zero-SOL) launch:
+    // claim_tokens divides by the total_shares snapshot
+    require!(launch.has_liquidity(), AstraError::EmptyLaunch);
+
+    // Phase 2: pool is sized from the lock_graduation snapshot. Trades were
+    // frozen at the lock, so a buy racing the operator either landed before
+    // the snapshot (and is in the pool) or was rejected - never in between
+    let curve_sol = launch.locked_graduation_sol(Clock::get()?.unix_timestamp)?;
+    // Booster SOL deepens the pool (unmatched - the token side is fixed)
+    let sol_amount = launch.graduation_pool_sol(curve_sol)?;
+    // Holder pool / LP / burn split of the minted supply (see Launch::burn_bps)
+    let allocation = launch.token_allocation()?;
 
     // PDA Seeds
     let launch_seeds = &[
@@ -194,14 +462,60 @@ pub fn handler(ctx: Context<Graduate>) -> Result<()> {
             },
             signer_seeds,
         ),
-        TOTAL_SUPPLY * 1_000_000_000, // 1B with 9 decimals
+        TOTAL_SUPPLY * TOKEN_DECIMALS_SCALE, // 1B with 9 decimals
     )?;
 
+    // Burn the deflationary share (if any) before anything leaves the launch account
+    if allocation.burned > 0 {
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    from: ctx.accounts.launch_token_account.to_account_info(),
+                    authority: launch.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            allocation.burned,
+        )?;
+    }
+
+    // Hand holder tokens to the external distributor (if configured)
+    // Holders then claim from the distributor instead of claim_tokens
+    if launch.distributor.is_some() {
+        let distributor_token_account = ctx
+            .accounts
+            .distributor_token_account
+            .as_ref()
+            .ok_or(AstraError::InvalidDistributor)?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.launch_token_account.to_account_info(),
+                    to: distributor_token_account.to_account_info(),
+                    authority: launch.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            allocation.holders, // 800M with 9 decimals, less any burn
+        )?;
+    }
+
     // 3. Create Raydium CPMM Pool
     let init_amount_0 = sol_amount;
-    let init_amount_1 = TOKENS_FOR_LP * 1_000_000_000; // 200M with 9 decimals
-
-    require!(init_amount_1 > 0, AstraError::InvalidCalculation);
+    let init_amount_1 = allocation.lp; // 200M with 9 decimals, less any burn
+
+    check_raydium_pool_inputs(
+        &ctx.accounts.amm_config,
+        &ctx.accounts.pool_state,
+        &ctx.accounts.token_0_mint.key(),
+        &ctx.accounts.token_1_mint.key(),
+        init_amount_0,
+        init_amount_1,
+    )?;
 
     let mut instruction_data = vec![175, 175, 109, 31, 56, 222, 53, 138];
     instruction_data.extend_from_slice(&init_amount_0.to_le_bytes());
@@ -258,35 +572,43 @@ pub fn handler(ctx: Context<Graduate>) -> Result<()> {
             ctx.accounts.rent.to_account_info(),
         ],
         signer_seeds,
+    )
+    .map_err(log_raydium_cpi_error)?;
+
+    // Opening reserves and LP, read back from the accounts Raydium just wrote
+    ctx.accounts.vault_lp_token.reload()?;
+    let outcome = GraduationRecord::from_reserves(
+        token_balance(&ctx.accounts.token_0_vault)?,
+        token_balance(&ctx.accounts.token_1_vault)?,
+        ctx.accounts.vault_lp_token.amount,
     )?;
 
     // 4. Initialize Vault
-    let estimated_lp_tokens = ((init_amount_0 as u128)
-        .checked_mul(init_amount_1 as u128)
-        .ok_or(AstraError::MathOverflow)?)
-    .isqrt() as u64;
-
     let pool_address = ctx.accounts.pool_state.key();
 
     vault.launch = launch.key();
     vault.creator = launch.creator;
     vault.lp_mint = ctx.accounts.lp_mint.key();
-    vault.lp_balance = estimated_lp_tokens;
+    vault.lp_balance = outcome.lp_minted;
+    // Fee baseline for poke: the pool's value per LP as opened
+    vault.lp_value_checkpoint = RaydiumPool::load(&ctx.accounts.pool_state)?
+        .lp_value(&ctx.accounts.token_0_vault, &ctx.accounts.token_1_vault)?;
+    vault.booster = launch.booster;
+    vault.booster_lp = Vault::booster_lp_share(outcome.lp_minted, curve_sol, launch.boosted_sol)?;
     vault.activated = true;
     vault.total_yield_collected = 0;
     vault.last_poke_at = Clock::get()?.unix_timestamp;
     vault.bump = ctx.bumps.vault;
 
     // 5. Update Launch State
-    launch.graduated = true;
-    launch.graduated_at = Some(Clock::get()?.unix_timestamp);
-    launch.vesting_start = Some(Clock::get()?.unix_timestamp);
-    launch.token_mint = Some(ctx.accounts.token_mint.key());
-    launch.pool_address = Some(pool_address);
-    launch.vault = Some(vault.key());
-
-    // V7: Use simplified total_shares (no locked/unlocked)
-    launch.total_shares_at_graduation = launch.total_shares;
+    // V7: Snapshots total_shares (no locked/unlocked) and holder count
+    launch.mark_graduated(
+        Clock::get()?.unix_timestamp,
+        ctx.accounts.token_mint.key(),
+        pool_address,
+        vault.key(),
+    )?;
+    launch.record_graduation_outcome(outcome)?;
 
     // 6. Increment Creator's graduated count
     let creator_stats = &mut ctx.accounts.creator_stats;
@@ -298,11 +620,17 @@ pub fn handler(ctx: Context<Graduate>) -> Result<()> {
         pool_address,
         lp_mint: ctx.accounts.lp_mint.key(),
         sol_for_lp: sol_amount,
+        boosted_sol: launch.boosted_sol,
+        booster_lp: vault.booster_lp,
+        tokens_burned: allocation.burned,
         total_shares: launch.total_shares_at_graduation,
+        holders_at_graduation: launch.holders_at_graduation,
+        vesting: launch.vesting_schedule().ok_or(AstraError::NotGraduated)?,
+        outcome,
         timestamp: launch.graduated_at.unwrap(),
     });
 
     // Reset reentrancy flag
-    launch.operation_in_progress = false;
+    launch.end_operation();
     Ok(())
 }
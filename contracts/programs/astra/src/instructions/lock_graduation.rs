@@ -0,0 +1,61 @@
+use crate::constants::GRADUATION_LOCK_SECONDS;
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Phase 1 of graduation (operator only)
+///
+/// Snapshots the curve and freezes buys/sells so the follow-up `graduate`
+/// seeds the pool with exactly the reserve the operator decided on. The lock
+/// lapses after GRADUATION_LOCK_SECONDS if graduate never lands, and can't be
+/// re-taken for GRADUATION_RELOCK_COOLDOWN_SECONDS after that.
+///
+/// Only a launch that is ready to graduate (market cap threshold reached,
+/// enough holders) can be locked, so the operator can't freeze trading on
+/// arbitrary launches.
+#[derive(Accounts)]
+pub struct LockGraduation<'info> {
+    #[account(
+        constraint = operator.key() == config.operator_wallet @ AstraError::Unauthorized
+    )]
+    pub operator: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = !launch.graduated @ AstraError::AlreadyGraduated,
+        constraint = !launch.refund_mode @ AstraError::LaunchInRefundMode
+    )]
+    pub launch: Account<'info, Launch>,
+}
+
+pub fn handler(ctx: Context<LockGraduation>) -> Result<()> {
+    let launch = &mut ctx.accounts.launch;
+    let now = Clock::get()?.unix_timestamp;
+
+    launch.begin_operation()?;
+    launch.check_graduation_ready(
+        ctx.accounts.config.sol_price_usd,
+        ctx.accounts.config.min_holders_for_graduation,
+    )?;
+    launch.lock_graduation(now)?;
+
+    emit!(crate::events::GraduationLocked {
+        launch: launch.key(),
+        total_sol: launch.graduation_sol_snapshot,
+        total_shares: launch.graduation_shares_snapshot,
+        expires_at: now.saturating_add(GRADUATION_LOCK_SECONDS),
+        timestamp: now,
+    });
+
+    msg!(
+        "GRADUATION LOCKED: Launch {} at {} lamports",
+        launch.key(),
+        launch.graduation_sol_snapshot
+    );
+
+    launch.end_operation();
+    Ok(())
+}
@@ -1,29 +1,121 @@
+pub mod accept_authority;
+pub mod backfill_holder_count;
+pub mod boost_graduation;
 pub mod buy;
+pub mod buy_usd;
+pub mod cancel_authority_change;
+pub mod claim_booster_yield;
 pub mod claim_creator_fees;
+pub mod claim_keeper_rewards;
 pub mod claim_refund;
 pub mod claim_tokens;
 pub mod claim_vesting;
 pub mod close_launch;
+pub mod close_vault;
 pub mod create_launch;
+pub mod curve_selftest;
 pub mod enable_refund;
+pub mod estimate_graduation;
 pub mod force_graduate;
+pub mod get_creator_reputation;
+pub mod get_fee_schedule;
+pub mod get_tokens_per_share;
 pub mod graduate;
 pub mod initialize;
+pub mod lock_graduation;
 pub mod poke;
+pub mod preview_poke;
+pub mod preview_refund;
+pub mod propose_authority;
 pub mod push_refund;
+pub mod quote_buy;
+pub mod quote_sell;
+pub mod recount_holders;
+pub mod refresh_min_seed;
+pub mod reset_circuit_breaker;
+pub mod reset_operation_flag;
+pub mod select_winner;
 pub mod sell;
+pub mod set_paused;
+pub mod set_yield_recipient;
+pub mod slash_creator_bond;
+pub mod stake_for_verification;
+pub mod sweep_fee_escrow;
+pub mod top_up_refund_reserve;
+pub mod unstake_verification;
+pub mod update_amm_configs;
+pub mod update_creator_concentration_cap;
+pub mod update_distributors;
+pub mod update_graduation_operators;
+pub mod update_janitor_rewards_wallet;
+pub mod update_metadata;
+pub mod update_operator_wallet;
+pub mod update_price;
+pub mod update_price_pyth;
+pub mod update_protocol_fee_wallet;
+pub mod update_sell_fee;
+pub mod update_vault_protocol_wallet;
+pub mod update_verified_fee;
+pub mod verify_launch_accounting;
+pub mod withdraw_boost;
 
+pub use accept_authority::*;
+pub use backfill_holder_count::*;
+pub use boost_graduation::*;
 pub use buy::*;
+pub use buy_usd::*;
+pub use cancel_authority_change::*;
+pub use claim_booster_yield::*;
 pub use claim_creator_fees::*;
+pub use claim_keeper_rewards::*;
 pub use claim_refund::*;
 pub use claim_tokens::*;
 pub use claim_vesting::*;
 pub use close_launch::*;
+pub use close_vault::*;
 pub use create_launch::*;
+pub use curve_selftest::*;
 pub use enable_refund::*;
+pub use estimate_graduation::*;
 pub use force_graduate::*;
+pub use get_creator_reputation::*;
+pub use get_fee_schedule::*;
+pub use get_tokens_per_share::*;
 pub use graduate::*;
 pub use initialize::*;
+pub use lock_graduation::*;
 pub use poke::*;
+pub use preview_poke::*;
+pub use preview_refund::*;
+pub use propose_authority::*;
 pub use push_refund::*;
+pub use quote_buy::*;
+pub use quote_sell::*;
+pub use recount_holders::*;
+pub use refresh_min_seed::*;
+pub use reset_circuit_breaker::*;
+pub use reset_operation_flag::*;
+pub use select_winner::*;
 pub use sell::*;
+pub use set_paused::*;
+pub use set_yield_recipient::*;
+pub use slash_creator_bond::*;
+pub use stake_for_verification::*;
+pub use sweep_fee_escrow::*;
+pub use top_up_refund_reserve::*;
+pub use unstake_verification::*;
+pub use update_amm_configs::*;
+pub use update_creator_concentration_cap::*;
+pub use update_distributors::*;
+pub use update_graduation_operators::*;
+pub use update_janitor_rewards_wallet::*;
+pub use update_metadata::*;
+pub use update_operator_wallet::*;
+pub use update_price::*;
+pub use update_price_pyth::*;
+pub use update_protocol_fee_wallet::*;
+pub use update_sell_fee::*;
+pub use update_vault_protocol_wallet::*;
+pub use update_verified_fee::*;
+pub use verify_launch_accounting::*;
+pub use withdraw_boost::*;
@@ -1,6 +1,19 @@
 use crate::errors::AstraError;
+use crate::instructions::graduate::{RaydiumPool, RAYDIUM_CPMM_PROGRAM};
 use crate::state::*;
+use crate::transfer::transfer_from_pda;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use anchor_spl::token_2022::Token2022;
+
+// SPL Memo program (Raydium CPMM withdraw requires it)
+pub const SPL_MEMO_PROGRAM: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+// Anchor discriminator for Raydium CPMM `withdraw`
+const RAYDIUM_WITHDRAW_IX: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
 
 /// Poke instruction - collect and distribute vault yield
 ///
@@ -8,46 +21,67 @@ use anchor_lang::prelude::*;
 /// LP position and distribute it according to protocol rules.
 ///
 /// # Yield Distribution (ADR-001)
-/// - 1% to caller (incentivizes regular poking)
-/// - 60% to creator (rewards launch creator)
+/// A graduation booster first takes its pro-rata share (booster_lp of
+/// lp_balance), held in the vault for claim_booster_yield. The rest splits:
+/// - 1% to caller (accrued to their KeeperRewards, batch-claimed later)
+/// - 60% to creator (rewards launch creator; paid to vault.yield_recipient if set)
 /// - 10% to protocol (treasury revenue)
 /// - 29% compounded (reinvested to LP, grows vault)
 ///
 /// # Requirements
 /// - Launch must be graduated (yield only available post-graduation)
-/// - Vault must exist and be activated
+/// - Vault must exist and be activated (VaultNotActivated otherwise)
 ///
-/// # Notes
-/// This is an MVP implementation that simulates yield collection.
-/// In production, this would integrate with Raydium's fee collection.
+/// # Fee collection
+/// Raydium CPMM has no per-LP fee claim (`collect_fund_fee` is for the fund
+/// owner): LP fees stay in the reserves and raise the pool's value per LP
+/// token. Poke measures that growth against vault.lp_value_checkpoint and
+/// withdraws the LP it represents. The compounded 29% is simply never
+/// withdrawn; the rest comes out as SOL (distributed) and project tokens.
+/// The token side goes to the creator's yield recipient: without a
+/// pool-independent price it can't be split by value like the SOL, and
+/// leaving it in the vault would lock it forever. Both withdrawal token
+/// accounts are closed again, so the caller gets their rent back.
 #[derive(Accounts)]
 pub struct Poke<'info> {
     /// The caller who triggers yield collection
-    /// Receives 1% of collected yield as incentive
+    /// Earns 1% of collected yield, accrued to keeper_rewards
     #[account(mut)]
     pub caller: Signer<'info>,
 
+    /// Caller's claimable reward balance (created on their first poke)
+    /// PDA: [b"keeper_rewards", caller.key().as_ref()]
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = 8 + KeeperRewards::INIT_SPACE,
+        seeds = [b"keeper_rewards", caller.key().as_ref()],
+        bump
+    )]
+    pub keeper_rewards: Box<Account<'info, KeeperRewards>>,
+
     /// Global config - provides protocol wallet address
     #[account(seeds = [b"config"], bump = config.bump)]
-    pub config: Account<'info, GlobalConfig>,
+    pub config: Box<Account<'info, GlobalConfig>>,
 
     /// The launch associated with this vault
     /// Must be graduated to have yield available
     #[account(constraint = launch.graduated @ AstraError::NotGraduated)]
-    pub launch: Account<'info, Launch>,
+    pub launch: Box<Account<'info, Launch>>,
 
     /// The vault holding LP tokens
     /// PDA: [b"vault", launch.key().as_ref()]
+    /// Must be activated - never distribute against a half-initialized vault
     #[account(
         mut,
         seeds = [b"vault", launch.key().as_ref()],
         bump = vault.bump
     )]
-    pub vault: Account<'info, Vault>,
+    pub vault: Box<Account<'info, Vault>>,
 
     /// CHECK: Creator wallet receiving 60% yield share
-    /// Verified to match vault.creator
-    #[account(mut, address = vault.creator)]
+    /// Verified to match the creator's yield override, else vault.creator
+    #[account(mut, address = vault.creator_yield_recipient())]
     pub creator_wallet: UncheckedAccount<'info>,
 
     /// CHECK: Protocol wallet receiving 10% yield share
@@ -55,17 +89,166 @@ pub struct Poke<'info> {
     #[account(mut, address = config.vault_protocol_wallet)]
     pub protocol_wallet: UncheckedAccount<'info>,
 
-    // NOTE: In full implementation, would include Raydium accounts for collecting fees
-    // For MVP, we simulate yield collection
+    // Raydium CPMM withdraw accounts
+    /// CHECK: The launch's pool, recorded at graduation; read by RaydiumPool
+    #[account(
+        mut,
+        constraint = launch.pool_address == Some(pool_state.key()) @ AstraError::InvalidPoolAccounts
+    )]
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by Raydium CPI
+    pub amm_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Pool's token 0 (wSOL) vault - checked against pool_state
+    #[account(mut)]
+    pub token_0_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Pool's token 1 vault - checked against pool_state
+    #[account(mut)]
+    pub token_1_vault: UncheckedAccount<'info>,
+
+    #[account(address = token::spl_token::native_mint::ID)]
+    pub token_0_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        constraint = launch.token_mint == Some(token_1_mint.key()) @ AstraError::InvalidPoolAccounts
+    )]
+    pub token_1_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, address = vault.lp_mint)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    /// Vault's LP tokens
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = vault
+    )]
+    pub vault_lp_token: Box<Account<'info, TokenAccount>>,
+
+    /// Receives the SOL side of a withdrawal; closed into the vault to unwrap
+    /// (rent refunded to the caller)
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = token_0_mint,
+        associated_token::authority = vault
+    )]
+    pub vault_wsol: Box<Account<'info, TokenAccount>>,
+
+    /// Receives the token side of a withdrawal; paid out, then closed to the caller
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = token_1_mint,
+        associated_token::authority = vault
+    )]
+    pub vault_token: Box<Account<'info, TokenAccount>>,
+
+    /// The creator yield recipient's token account; receives the token side
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = token_1_mint,
+        associated_token::authority = creator_wallet
+    )]
+    pub creator_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Validated via address constraint
+    #[account(address = RAYDIUM_CPMM_PROGRAM)]
+    pub raydium_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated via address constraint
+    #[account(address = SPL_MEMO_PROGRAM)]
+    pub memo_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_program_2022: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// SOL-side value of the LP fee growth poke would collect right now
+///
+/// Distributed SOL plus the compounded share left in the pool; the matching
+/// token side goes to the creator's yield recipient.
+pub(crate) fn pending_yield(
+    vault: &Vault,
+    pool: &RaydiumPool,
+    token_0_vault: &AccountInfo,
+    token_1_vault: &AccountInfo,
+) -> Result<u64> {
+    let (reserve_0, _) = pool.reserves(token_0_vault, token_1_vault)?;
+    let fee_lp = vault.fee_lp(pool.lp_value(token_0_vault, token_1_vault)?)?;
+    Vault::pro_rata(reserve_0, fee_lp, pool.lp_supply)
+}
+
+impl<'info> Poke<'info> {
+    /// Unwrap vault_wsol into the vault, pay vault_token out to the creator and close it
+    ///
+    /// Returns (wSOL account rent now held by the vault, tokens paid to the creator).
+    /// vault_token's rent goes straight back to the caller.
+    fn release_withdrawal_accounts(&self, signer_seeds: &[&[&[u8]]]) -> Result<(u64, u64)> {
+        let wsol_rent = self
+            .vault_wsol
+            .to_account_info()
+            .lamports()
+            .checked_sub(self.vault_wsol.amount)
+            .ok_or(AstraError::MathOverflow)?;
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            token::CloseAccount {
+                account: self.vault_wsol.to_account_info(),
+                destination: self.vault.to_account_info(),
+                authority: self.vault.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        let creator_tokens = self.vault_token.amount;
+        if creator_tokens > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.vault_token.to_account_info(),
+                        to: self.creator_token_account.to_account_info(),
+                        authority: self.vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                creator_tokens,
+            )?;
+        }
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            token::CloseAccount {
+                account: self.vault_token.to_account_info(),
+                destination: self.caller.to_account_info(),
+                authority: self.vault.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        Ok((wsol_rent, creator_tokens))
+    }
 }
 
 /// Handler for the poke instruction
 ///
-/// Calculates yield distribution and updates vault tracking.
-/// In production, this would perform actual transfers from Raydium fee accounts.
+/// Withdraws the LP that represents fees earned since the last poke and pays
+/// out the SOL it returns. No fees (or dust below one LP token) is a no-op
+/// that still records the poke.
 pub fn handler(ctx: Context<Poke>) -> Result<()> {
-    let vault = &mut ctx.accounts.vault;
-    let config = &ctx.accounts.config;
+    ctx.accounts.config.check_not_paused()?;
+    ctx.accounts.vault.check_active()?;
+
+    let keeper_rewards = &mut ctx.accounts.keeper_rewards;
+    if keeper_rewards.keeper == Pubkey::default() {
+        keeper_rewards.keeper = ctx.accounts.caller.key();
+        keeper_rewards.bump = ctx.bumps.keeper_rewards;
+    }
 
     // ADR-001: Yield distribution percentages (in basis points)
     // Total: 10000 bps = 100%
@@ -75,117 +258,225 @@ pub fn handler(ctx: Context<Poke>) -> Result<()> {
     const COMPOUND_BPS: u64 = 2900; // 29% - reinvested to grow LP position
     const TOTAL_BPS: u64 = 10000;
 
-    // In full implementation: Query Raydium pool for collected trading fees
-    // and calculate actual yield from LP position growth.
-    // For this MVP scaffold: Simulate collecting yield
-    let simulated_yield: u64 = 1_000_000; // 0.001 SOL for testing
+    let pool = RaydiumPool::load(&ctx.accounts.pool_state)?;
+    require_keys_eq!(
+        pool.lp_mint,
+        ctx.accounts.vault.lp_mint,
+        AstraError::InvalidPoolAccounts
+    );
+    let lp_value = pool.lp_value(&ctx.accounts.token_0_vault, &ctx.accounts.token_1_vault)?;
+
+    // Fee growth in LP tokens: the booster's pro-rata part, then the ADR-001 split
+    let fee_lp = ctx.accounts.vault.fee_lp(lp_value)?;
+    let booster_fee_lp = ctx.accounts.vault.booster_part(fee_lp)?;
+    let holder_fee_lp = fee_lp
+        .checked_sub(booster_fee_lp)
+        .ok_or(AstraError::MathOverflow)?;
+    let share = |bps: u64| -> Result<u64> {
+        Ok(holder_fee_lp
+            .checked_mul(bps)
+            .ok_or(AstraError::MathOverflow)?
+            .checked_div(TOTAL_BPS)
+            .ok_or(AstraError::MathOverflow)?)
+    };
+    let caller_lp = share(CALLER_BPS)?;
+    let creator_lp = share(CREATOR_BPS)?;
+    let protocol_lp = share(PROTOCOL_BPS)?;
+    const _: () = assert!(CALLER_BPS + CREATOR_BPS + PROTOCOL_BPS + COMPOUND_BPS == TOTAL_BPS);
+
+    // Compounding = leaving that LP in the pool, so only the paid shares come out
+    let holder_withdraw_lp = caller_lp
+        .checked_add(creator_lp)
+        .ok_or(AstraError::MathOverflow)?
+        .checked_add(protocol_lp)
+        .ok_or(AstraError::MathOverflow)?;
+    let compound_lp = holder_fee_lp
+        .checked_sub(holder_withdraw_lp)
+        .ok_or(AstraError::MathOverflow)?;
+    let withdraw_lp = holder_withdraw_lp
+        .checked_add(booster_fee_lp)
+        .ok_or(AstraError::MathOverflow)?;
+
+    let launch_key = ctx.accounts.launch.key();
+    let vault_bump = [ctx.accounts.vault.bump];
+    let vault_seeds: &[&[u8]] = &[b"vault", launch_key.as_ref(), &vault_bump];
+    let signer_seeds = &[vault_seeds];
+
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let caller_info = ctx.accounts.caller.to_account_info();
 
     // Handle zero yield case - still update timestamp and emit event
-    if simulated_yield == 0 {
+    if withdraw_lp == 0 {
+        // Nothing withdrawn, but the token accounts may have just been created
+        let (wsol_rent, creator_tokens) = ctx.accounts.release_withdrawal_accounts(signer_seeds)?;
+        transfer_from_pda(&vault_info, &caller_info, wsol_rent)?;
+
+        let vault = &mut ctx.accounts.vault;
+        // Vaults graduated before fee tracking start their baseline here
+        if vault.lp_value_checkpoint == 0 {
+            vault.lp_value_checkpoint = lp_value;
+        }
+        ctx.accounts.keeper_rewards.accrue(0)?;
         vault.last_poke_at = Clock::get()?.unix_timestamp;
-        
+
         emit!(crate::events::Poked {
             vault: vault.key(),
             caller: ctx.accounts.caller.key(),
             total_yield: 0,
             caller_reward: 0,
             creator_reward: 0,
+            creator_recipient: ctx.accounts.creator_wallet.key(),
             protocol_reward: 0,
             compounded: 0,
+            booster_reward: 0,
+            creator_tokens,
             timestamp: vault.last_poke_at,
         });
-        
+
         return Ok(());
     }
 
-    // Calculate distribution amounts using basis points
-    let caller_reward = simulated_yield
-        .checked_mul(CALLER_BPS)
-        .ok_or(AstraError::MathOverflow)?
-        .checked_div(TOTAL_BPS)
-        .ok_or(AstraError::MathOverflow)?;
+    // 1. Withdraw the fee LP from Raydium (proportional, so no minimums)
+    let mut instruction_data = RAYDIUM_WITHDRAW_IX.to_vec();
+    instruction_data.extend_from_slice(&withdraw_lp.to_le_bytes());
+    instruction_data.extend_from_slice(&0u64.to_le_bytes());
+    instruction_data.extend_from_slice(&0u64.to_le_bytes());
 
-    let creator_reward = simulated_yield
-        .checked_mul(CREATOR_BPS)
-        .ok_or(AstraError::MathOverflow)?
-        .checked_div(TOTAL_BPS)
-        .ok_or(AstraError::MathOverflow)?;
+    let account_metas = vec![
+        AccountMeta::new_readonly(vault_info.key(), true),
+        AccountMeta::new_readonly(ctx.accounts.amm_authority.key(), false),
+        AccountMeta::new(ctx.accounts.pool_state.key(), false),
+        AccountMeta::new(ctx.accounts.vault_lp_token.key(), false),
+        AccountMeta::new(ctx.accounts.vault_wsol.key(), false),
+        AccountMeta::new(ctx.accounts.vault_token.key(), false),
+        AccountMeta::new(ctx.accounts.token_0_vault.key(), false),
+        AccountMeta::new(ctx.accounts.token_1_vault.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.token_program_2022.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.token_0_mint.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.token_1_mint.key(), false),
+        AccountMeta::new(ctx.accounts.lp_mint.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.memo_program.key(), false),
+    ];
 
-    let protocol_reward = simulated_yield
-        .checked_mul(PROTOCOL_BPS)
-        .ok_or(AstraError::MathOverflow)?
-        .checked_div(TOTAL_BPS)
-        .ok_or(AstraError::MathOverflow)?;
+    invoke_signed(
+        &Instruction {
+            program_id: RAYDIUM_CPMM_PROGRAM,
+            accounts: account_metas,
+            data: instruction_data,
+        },
+        &[
+            vault_info.clone(),
+            ctx.accounts.amm_authority.to_account_info(),
+            ctx.accounts.pool_state.to_account_info(),
+            ctx.accounts.vault_lp_token.to_account_info(),
+            ctx.accounts.vault_wsol.to_account_info(),
+            ctx.accounts.vault_token.to_account_info(),
+            ctx.accounts.token_0_vault.to_account_info(),
+            ctx.accounts.token_1_vault.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_program_2022.to_account_info(),
+            ctx.accounts.token_0_mint.to_account_info(),
+            ctx.accounts.token_1_mint.to_account_info(),
+            ctx.accounts.lp_mint.to_account_info(),
+            ctx.accounts.memo_program.to_account_info(),
+        ],
+        signer_seeds,
+    )
+    .map_err(|err| {
+        msg!("Raydium withdraw CPI rejected: {:?}", err);
+        AstraError::RaydiumCpiFailed
+    })?;
+
+    ctx.accounts.vault_lp_token.reload()?;
+    ctx.accounts.vault_wsol.reload()?;
+    ctx.accounts.vault_token.reload()?;
+    let sol_collected = ctx.accounts.vault_wsol.amount;
+
+    // 2. Unwrap the SOL into the vault and pay the token side to the creator;
+    // both accounts close, and their rent goes back to the caller who funded them
+    let (wsol_rent, creator_tokens) = ctx.accounts.release_withdrawal_accounts(signer_seeds)?;
 
-    // Compound amount is the remainder to ensure no rounding errors
-    let compound_amount = simulated_yield
+    // 3. Split the SOL by each share's LP; creator takes the rounding
+    let caller_reward = Vault::pro_rata(sol_collected, caller_lp, withdraw_lp)?;
+    let protocol_reward = Vault::pro_rata(sol_collected, protocol_lp, withdraw_lp)?;
+    let booster_reward = Vault::pro_rata(sol_collected, booster_fee_lp, withdraw_lp)?;
+    let creator_reward = sol_collected
         .checked_sub(caller_reward)
         .ok_or(AstraError::MathOverflow)?
-        .checked_sub(creator_reward)
-        .ok_or(AstraError::MathOverflow)?
         .checked_sub(protocol_reward)
-        .ok_or(AstraError::MathOverflow)?;
-
-    // Verify distribution adds up correctly (sanity check)
-    let total_distributed = caller_reward
-        .checked_add(creator_reward)
-        .ok_or(AstraError::MathOverflow)?
-        .checked_add(protocol_reward)
         .ok_or(AstraError::MathOverflow)?
+        .checked_sub(booster_reward)
+        .ok_or(AstraError::MathOverflow)?;
+    // SOL side of the LP left in the pool, for the lifetime totals
+    let compound_amount = Vault::pro_rata(sol_collected, compound_lp, withdraw_lp)?;
+    let total_yield = sol_collected
         .checked_add(compound_amount)
         .ok_or(AstraError::MathOverflow)?;
 
-    require!(
-        total_distributed == simulated_yield,
-        AstraError::InvalidCalculation
-    );
+    let creator_info = ctx.accounts.creator_wallet.to_account_info();
+    let protocol_info = ctx.accounts.protocol_wallet.to_account_info();
+    transfer_from_pda(&vault_info, &caller_info, wsol_rent)?;
+    transfer_from_pda(&vault_info, &creator_info, creator_reward)?;
+    transfer_from_pda(&vault_info, &protocol_info, protocol_reward)?;
+
+    // Caller reward is claimable later via claim_keeper_rewards
+    let keeper_rewards = &mut ctx.accounts.keeper_rewards;
+    transfer_from_pda(&vault_info, &keeper_rewards.to_account_info(), caller_reward)?;
+    keeper_rewards.accrue(caller_reward)?;
+
+    // 4. Booster's SOL stays in the vault until claim_booster_yield
+    let vault = &mut ctx.accounts.vault;
+    vault.accrue_booster_yield(booster_reward, booster_fee_lp)?;
+
+    // 5. New baseline: whatever fees accrue from here on
+    vault.lp_balance = ctx.accounts.vault_lp_token.amount;
+    vault.lp_value_checkpoint = RaydiumPool::load(&ctx.accounts.pool_state)?
+        .lp_value(&ctx.accounts.token_0_vault, &ctx.accounts.token_1_vault)?;
+
+    // Update vault tracking stats (u128 lifetime totals)
+    vault.record_yield(
+        total_yield,
+        creator_reward,
+        protocol_reward,
+        compound_amount,
+        caller_reward,
+    )?;
 
-    // In full implementation: Perform actual transfers from Raydium fee accounts
-    // - Transfer caller_reward to caller
-    // - Transfer creator_reward to creator_wallet
-    // - Transfer protocol_reward to protocol_wallet
-    // - Reinvest compound_amount into LP position
-    // For MVP: Transfers are simulated (would require actual Raydium integration)
-
-    // Update vault tracking stats
-    vault.total_yield_collected = vault
-        .total_yield_collected
-        .checked_add(simulated_yield)
-        .ok_or(AstraError::MathOverflow)?;
-    
-    vault.total_creator_paid = vault
-        .total_creator_paid
-        .checked_add(creator_reward)
-        .ok_or(AstraError::MathOverflow)?;
-    
-    vault.total_protocol_paid = vault
-        .total_protocol_paid
-        .checked_add(protocol_reward)
-        .ok_or(AstraError::MathOverflow)?;
-    
-    vault.total_compounded = vault
-        .total_compounded
-        .checked_add(compound_amount)
-        .ok_or(AstraError::MathOverflow)?;
-    
-    vault.total_caller_paid = vault
-        .total_caller_paid
-        .checked_add(caller_reward)
-        .ok_or(AstraError::MathOverflow)?;
-    
     vault.last_poke_at = Clock::get()?.unix_timestamp;
 
     // Emit Poked event for indexing and tracking
     emit!(crate::events::Poked {
         vault: vault.key(),
         caller: ctx.accounts.caller.key(),
-        total_yield: simulated_yield,
+        total_yield,
         caller_reward,
         creator_reward,
+        creator_recipient: ctx.accounts.creator_wallet.key(),
         protocol_reward,
         compounded: compound_amount,
+        booster_reward,
+        creator_tokens,
         timestamp: vault.last_poke_at,
     });
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_fee_lp_token_side_is_paid_not_burned() {
+        let source = include_str!("poke.rs");
+        let release = source
+            .split("fn release_withdrawal_accounts")
+            .nth(1)
+            .unwrap()
+            .split("pub fn handler")
+            .next()
+            .unwrap();
+
+        assert!(!release.contains("token::burn"));
+        assert!(release.contains("to: self.creator_token_account.to_account_info()"));
+    }
+}
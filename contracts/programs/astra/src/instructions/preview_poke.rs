@@ -0,0 +1,53 @@
+use crate::errors::AstraError;
+use crate::instructions::graduate::RaydiumPool;
+use crate::instructions::poke::pending_yield;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Read-only poke preview
+/// Emits the pending yield and how poke would split it, without distributing,
+/// so keepers only poke when their 1% covers the gas
+#[derive(Accounts)]
+pub struct PreviewPoke<'info> {
+    #[account(constraint = launch.graduated @ AstraError::NotGraduated)]
+    pub launch: Account<'info, Launch>,
+
+    #[account(
+        seeds = [b"vault", launch.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: The launch's pool, recorded at graduation; read by RaydiumPool
+    #[account(
+        constraint = launch.pool_address == Some(pool_state.key()) @ AstraError::InvalidPoolAccounts
+    )]
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// CHECK: Pool's token 0 (wSOL) vault - checked against pool_state
+    pub token_0_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Pool's token 1 vault - checked against pool_state
+    pub token_1_vault: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<PreviewPoke>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    vault.check_active()?;
+
+    let pool = RaydiumPool::load(&ctx.accounts.pool_state)?;
+    let pending = pending_yield(
+        vault,
+        &pool,
+        &ctx.accounts.token_0_vault,
+        &ctx.accounts.token_1_vault,
+    )?;
+
+    emit!(Vault::preview_poke(
+        vault.key(),
+        pending,
+        vault.booster_part(pending)?
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::errors::AstraError;
+use crate::events::RefundPreview;
+use crate::state::*;
+use crate::transfer::withdrawable_lamports;
+use anchor_lang::prelude::*;
+
+/// Read-only refund preview
+/// Emits the exact amount claim_refund/push_refund would pay this position
+/// right now, including the pro-rata haircut when the launch is under-funded
+#[derive(Accounts)]
+pub struct PreviewRefund<'info> {
+    #[account(constraint = launch.refund_mode @ AstraError::RefundModeNotActive)]
+    pub launch: Account<'info, Launch>,
+
+    #[account(
+        seeds = [b"position", launch.key().as_ref(), position.user.as_ref()],
+        bump = position.bump,
+        constraint = !position.has_claimed_refund @ AstraError::AlreadyClaimed
+    )]
+    pub position: Account<'info, Position>,
+}
+
+pub fn handler(ctx: Context<PreviewRefund>) -> Result<()> {
+    let launch = &ctx.accounts.launch;
+    let position = &ctx.accounts.position;
+
+    let available = withdrawable_lamports(&launch.to_account_info())?;
+    let quote = launch.refund_quote(position, available)?;
+
+    emit!(RefundPreview {
+        launch: launch.key(),
+        user: position.user,
+        entitlement: quote.entitlement,
+        amount: quote.amount,
+        is_proportional: quote.is_proportional,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Propose a new authority, effective after AUTHORITY_CHANGE_DELAY_SECONDS (authority only)
+/// The proposed key must sign accept_authority; a new proposal restarts the delay
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let effective_at = ctx.accounts.config.propose_authority(new_authority, now)?;
+
+    emit!(crate::events::AuthorityChangeProposed {
+        authority: ctx.accounts.authority.key(),
+        pending_authority: new_authority,
+        effective_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
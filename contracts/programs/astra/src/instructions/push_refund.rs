@@ -1,16 +1,34 @@
 use crate::errors::AstraError;
+use crate::instructions::claim_refund::emit_if_complete;
 use crate::state::*;
+use crate::transfer::{transfer_from_pda, withdrawable_lamports};
 use anchor_lang::prelude::*;
 
 /// Pushes a refund to a specific user's position
 /// Closes the position account, rent goes to caller as gas compensation
-/// Can be called by anyone (bot) - permissionless
+/// Can be called by anyone (bot) - permissionless, unless the config routes
+/// janitor rent to a keeper treasury (then operator only, rent to the treasury)
 #[derive(Accounts)]
 pub struct PushRefund<'info> {
-    /// Bot/anyone can call - pays gas, receives rent from closed account
+    /// Bot/anyone can call - pays gas (keeper only if a rewards wallet is set)
     #[account(mut)]
     pub caller: Signer<'info>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_authorized_janitor(&caller.key()) @ AstraError::UnauthorizedKeeper
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// CHECK: Receives reclaimed rent - the caller, or the janitor rewards wallet
+    #[account(
+        mut,
+        constraint = rent_recipient.key() == config.janitor_rent_recipient(&caller.key())
+            @ AstraError::InvalidRentRecipient
+    )]
+    pub rent_recipient: UncheckedAccount<'info>,
+
     #[account(
         mut,
         constraint = launch.refund_mode @ AstraError::RefundModeNotActive
@@ -22,7 +40,7 @@ pub struct PushRefund<'info> {
         seeds = [b"position", launch.key().as_ref(), recipient.key().as_ref()],
         bump = position.bump,
         constraint = !position.has_claimed_refund @ AstraError::AlreadyClaimed,
-        close = caller  // Rent returns to caller as gas compensation
+        close = rent_recipient  // Rent returns to caller (or keeper treasury)
     )]
     pub position: Account<'info, Position>,
 
@@ -37,45 +55,19 @@ pub fn handler(ctx: Context<PushRefund>) -> Result<()> {
     let launch = &mut ctx.accounts.launch;
     let position = &ctx.accounts.position;
 
-    // V7: Simplified refund calculation - single sol_basis value
-    // (V6 had: locked_basis + unlocked_basis)
-    let refund_amount = position.sol_basis;
-
-    // Handle zero balance positions (just close account)
-    if refund_amount == 0 {
-        emit!(crate::events::RefundPushed {
-            launch: launch.key(),
-            recipient: ctx.accounts.recipient.key(),
-            amount: 0,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        return Ok(());
-    }
-
-    // Verify launch has sufficient funds
-    let launch_balance = launch.to_account_info().lamports();
-    let rent = Rent::get()?.minimum_balance(8 + Launch::INIT_SPACE);
-    let available = launch_balance.saturating_sub(rent);
-
-    require!(available >= refund_amount, AstraError::InsufficientFunds);
-
-    // Transfer from Launch PDA to recipient
-    **launch.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
-    **ctx.accounts.recipient.try_borrow_mut_lamports()? += refund_amount;
+    // Settle against the curve totals (the creator's refund includes the seed,
+    // and locked_shares are subtracted alongside shares)
+    // Pro-rata if the PDA is short (same quote preview_refund reports)
+    let available = withdrawable_lamports(&launch.to_account_info())?;
+    let (quote, refunds_complete) = launch.settle_refund(position, available)?;
+    let refund_amount = quote.amount;
 
-    // V7: Simplified launch state updates
-    // (V6 had: total_locked_basis, total_unlocked_basis, total_locked_shares, total_unlocked_shares)
-    launch.total_sol = launch.total_sol.saturating_sub(position.sol_basis);
-    
-    // Subtract all shares (both unlocked and locked for creator positions)
-    // Creator seed shares are tracked separately in creator_seed_shares/creator_seed_sol
-    // but the position still holds the shares until vesting is complete
-    let total_position_shares = position
-        .shares
-        .checked_add(position.locked_shares)
-        .ok_or(AstraError::MathOverflow)?;
-    
-    launch.total_shares = launch.total_shares.saturating_sub(total_position_shares);
+    // Transfer from Launch PDA to recipient (never below rent; no-op for 0)
+    transfer_from_pda(
+        &launch.to_account_info(),
+        &ctx.accounts.recipient.to_account_info(),
+        refund_amount,
+    )?;
 
     emit!(crate::events::RefundPushed {
         launch: launch.key(),
@@ -83,9 +75,10 @@ pub fn handler(ctx: Context<PushRefund>) -> Result<()> {
         amount: refund_amount,
         timestamp: Clock::get()?.unix_timestamp,
     });
+    emit_if_complete(launch, refunds_complete)?;
 
-    // Position account closed via `close = caller` constraint
-    // Rent (~0.002 SOL) returns to caller as gas compensation
+    // Position account closed via `close = rent_recipient` constraint
+    // Rent (~0.002 SOL) returns to caller (or keeper treasury) as gas compensation
 
     Ok(())
 }
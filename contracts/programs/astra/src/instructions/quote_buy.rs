@@ -0,0 +1,46 @@
+use crate::curve;
+use crate::errors::AstraError;
+use crate::events::QuoteComputed;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Read-only buy simulation
+/// Runs buy's fee split and curve math against the launch's current supply.
+/// Quotes the standard fee - a first-buyer fee waiver, if the buyer gets one,
+/// only improves on it.
+#[derive(Accounts)]
+pub struct QuoteBuy<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub launch: Account<'info, Launch>,
+
+    #[account(
+        seeds = [b"creator_stats", launch.creator.as_ref()],
+        bump = creator_stats.bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+}
+
+pub fn handler(ctx: Context<QuoteBuy>, sol_amount: u64) -> Result<()> {
+    let launch = &ctx.accounts.launch;
+    launch.check_tradeable()?;
+
+    let (total_fee_bps, creator_fee_bps) =
+        ctx.accounts.config.buy_fee_bps(&ctx.accounts.creator_stats);
+    let fees = GlobalConfig::split_buy_fee(sol_amount, total_fee_bps, creator_fee_bps)?;
+    let shares = curve::buy_return(fees.net_sol, launch.total_shares)?;
+    require!(shares > 0, AstraError::BuyAmountTooSmall);
+
+    emit!(QuoteComputed {
+        launch: launch.key(),
+        is_buy: true,
+        sol_amount,
+        shares,
+        net_sol: fees.net_sol,
+        total_fee: fees.total_fee,
+        price_per_share: sol_amount / shares,
+    });
+
+    Ok(())
+}
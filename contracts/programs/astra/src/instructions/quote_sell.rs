@@ -0,0 +1,47 @@
+use crate::errors::AstraError;
+use crate::events::QuoteComputed;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Read-only sell simulation for a position
+/// Same refund (basis-proportional) and sell fee as sell
+#[derive(Accounts)]
+pub struct QuoteSell<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub launch: Account<'info, Launch>,
+
+    #[account(
+        seeds = [b"position", launch.key().as_ref(), position.user.as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, Position>,
+}
+
+pub fn handler(ctx: Context<QuoteSell>, shares_to_sell: u64) -> Result<()> {
+    let launch = &ctx.accounts.launch;
+    let position = &ctx.accounts.position;
+    launch.check_tradeable()?;
+    require!(shares_to_sell > 0, AstraError::InvalidCalculation);
+    require!(
+        shares_to_sell <= position.sellable_shares(),
+        AstraError::InsufficientShares
+    );
+
+    let refund = position.sell_refund(shares_to_sell)?;
+    // No floor on a quote: min_sol_out 0 never trips SlippageExceeded
+    let (sell_fee, net_refund) = ctx.accounts.config.sell_proceeds(refund, 0)?;
+
+    emit!(QuoteComputed {
+        launch: launch.key(),
+        is_buy: false,
+        sol_amount: refund,
+        shares: shares_to_sell,
+        net_sol: net_refund,
+        total_fee: sell_fee,
+        price_per_share: refund / shares_to_sell,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Recounts `holder_count` on-chain from every position of a launch (authority only)
+///
+/// The on-chain alternative to `backfill_holder_count`: the launch's Position
+/// accounts are passed in `remaining_accounts`, sorted by user, and the ones
+/// still holding shares are counted. Shares its one-time latch.
+#[derive(Accounts)]
+pub struct RecountHolders<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub launch: Account<'info, Launch>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, RecountHolders<'info>>) -> Result<()> {
+    let positions = ctx
+        .remaining_accounts
+        .iter()
+        .map(|info| Account::<Position>::try_from(info).map(Account::into_inner))
+        .collect::<Result<Vec<_>>>()?;
+
+    let launch = &mut ctx.accounts.launch;
+    let launch_key = launch.key();
+    let (previous_count, holder_count) = launch.recount_holders(&launch_key, &positions)?;
+
+    emit!(crate::events::HolderCountBackfilled {
+        launch: launch_key,
+        authority: ctx.accounts.authority.key(),
+        previous_count,
+        holder_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "HOLDER COUNT RECOUNTED: Launch {} ({} -> {})",
+        launch_key,
+        previous_count,
+        holder_count
+    );
+
+    Ok(())
+}
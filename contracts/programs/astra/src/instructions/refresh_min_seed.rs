@@ -0,0 +1,20 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Recompute min_seed_lamports from MIN_SEED_USD_CENTS at the cached SOL price
+/// Permissionless - anyone (typically the price cron) can keep it in sync
+#[derive(Accounts)]
+pub struct RefreshMinSeed<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<RefreshMinSeed>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    emit!(ctx.accounts.config.refresh_min_seed(now)?);
+
+    Ok(())
+}
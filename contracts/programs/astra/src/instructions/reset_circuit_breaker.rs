@@ -0,0 +1,36 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Unfreezes buys on a launch after a tripped sell-pressure circuit breaker
+/// has been reviewed (authority only)
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub launch: Account<'info, Launch>,
+}
+
+pub fn handler(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+    let launch = &mut ctx.accounts.launch;
+    let now = Clock::get()?.unix_timestamp;
+    let was_frozen = launch.reset_circuit_breaker(now);
+
+    emit!(crate::events::CircuitBreakerReset {
+        launch: launch.key(),
+        authority: ctx.accounts.authority.key(),
+        was_frozen,
+        timestamp: now,
+    });
+
+    msg!("CIRCUIT BREAKER RESET: Launch {} (was frozen: {})", launch.key(), was_frozen);
+
+    Ok(())
+}
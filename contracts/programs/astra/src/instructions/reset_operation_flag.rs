@@ -0,0 +1,37 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Clears a stuck `operation_in_progress` flag on a launch (authority only)
+///
+/// Safety valve: reverted transactions never persist the flag, but a future
+/// handler bug that returns Ok with the flag still set would brick the launch.
+#[derive(Accounts)]
+pub struct ResetOperationFlag<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub launch: Account<'info, Launch>,
+}
+
+pub fn handler(ctx: Context<ResetOperationFlag>) -> Result<()> {
+    let launch = &mut ctx.accounts.launch;
+    let was_set = launch.reset_operation_flag();
+
+    emit!(crate::events::OperationFlagReset {
+        launch: launch.key(),
+        authority: ctx.accounts.authority.key(),
+        was_set,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("OPERATION FLAG RESET: Launch {} (was set: {})", launch.key(), was_set);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::errors::AstraError;
+use crate::random::{derive_randomness, latest_slot_hash, select_weighted, RandomnessSource};
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// SlotHashes sysvar address
+pub const SLOT_HASHES_SYSVAR: Pubkey = pubkey!("SysvarS1otHashes111111111111111111111111111");
+
+/// Share-weighted random winner selection for "hold to win" promotions
+///
+/// Candidate positions are passed in `remaining_accounts`, sorted by address
+/// (which also rules out duplicates). Each candidate's weight is its `shares`.
+/// Read-only: the winner is only emitted, any prize is paid off-chain.
+/// The slot hash is predictable to the caller, so this is not fit for prizes
+/// worth gaming; that needs a VRF source (see `crate::random`).
+#[derive(Accounts)]
+pub struct SelectWinner<'info> {
+    #[account(constraint = creator.key() == launch.creator @ AstraError::Unauthorized)]
+    pub creator: Signer<'info>,
+
+    pub launch: Account<'info, Launch>,
+
+    /// CHECK: SlotHashes sysvar, read manually (too large to deserialize)
+    #[account(address = SLOT_HASHES_SYSVAR)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SelectWinner<'info>>,
+    source: RandomnessSource,
+) -> Result<()> {
+    let launch_key = ctx.accounts.launch.key();
+
+    let entropy = match source {
+        RandomnessSource::SlotHash => {
+            latest_slot_hash(&ctx.accounts.slot_hashes.try_borrow_data()?)?
+        }
+    };
+    let randomness = derive_randomness(&entropy, &launch_key);
+
+    let mut candidates: Vec<(Pubkey, Pubkey, u64)> =
+        Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut previous: Option<Pubkey> = None;
+
+    for info in ctx.remaining_accounts.iter() {
+        require!(
+            previous.is_none_or(|key| key < info.key()),
+            AstraError::InvalidWinnerCandidates
+        );
+        previous = Some(info.key());
+
+        let position = Account::<Position>::try_from(info)?;
+        require_keys_eq!(
+            position.launch,
+            launch_key,
+            AstraError::InvalidWinnerCandidates
+        );
+        candidates.push((info.key(), position.user, position.shares));
+    }
+
+    let weights: Vec<u64> = candidates.iter().map(|(_, _, shares)| *shares).collect();
+    let index = select_weighted(&weights, &randomness).ok_or(AstraError::NoEligibleHolders)?;
+    let (position, winner, winner_shares) = candidates[index];
+
+    emit!(crate::events::WinnerSelected {
+        launch: launch_key,
+        winner,
+        position,
+        winner_shares,
+        total_shares: weights.iter().sum(),
+        candidates: candidates.len() as u64,
+        randomness,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
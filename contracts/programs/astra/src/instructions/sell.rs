@@ -1,6 +1,7 @@
-use crate::curve;
 use crate::errors::AstraError;
 use crate::state::*;
+use crate::time::check_deadline;
+use crate::transfer::{ensure_withdrawable, transfer_from_pda};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -35,12 +36,27 @@ pub struct Sell<'info> {
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct SellArgs {
     pub shares_to_sell: u64,
+    /// Lowest net refund (after the sell fee) the seller accepts
     pub min_sol_out: u64,
+    /// Unix timestamp after which the sell reverts (None = no deadline)
+    pub deadline: Option<i64>,
 }
 
 pub fn handler(ctx: Context<Sell>, args: SellArgs) -> Result<()> {
+    ctx.accounts.config.check_not_paused()?;
+
     let launch = &mut ctx.accounts.launch;
     let position = &mut ctx.accounts.position;
+    let config = &ctx.accounts.config;
+
+    // A sell that sat in the mempool past its deadline may meet a different curve
+    check_deadline(args.deadline, Clock::get()?.unix_timestamp)?;
+
+    // Curve is frozen between lock_graduation and graduate
+    require!(
+        !launch.graduation_lock_active(Clock::get()?.unix_timestamp)?,
+        AstraError::GraduationLocked
+    );
 
     // Input validation
     require!(args.shares_to_sell > 0, AstraError::InvalidCalculation);
@@ -48,27 +64,36 @@ pub fn handler(ctx: Context<Sell>, args: SellArgs) -> Result<()> {
         args.shares_to_sell <= position.shares,
         AstraError::InsufficientShares
     );
-    require!(
-        args.min_sol_out <= position.sol_basis,
-        AstraError::InvalidCalculation
-    );
+
+    // Creator cooldown - no trading their own launch early on
+    if ctx.accounts.seller.key() == launch.creator {
+        require!(
+            launch.creator_can_trade(Clock::get()?.unix_timestamp)?,
+            AstraError::CreatorCooldownActive
+        );
+    }
 
     // Reentrancy protection
-    require!(
-        !launch.operation_in_progress,
-        AstraError::InvalidCalculation
-    );
-    launch.operation_in_progress = true;
+    launch.begin_operation()?;
 
     // 1. Calculate Refund (Proportional to Basis)
     // V7: Use simplified position fields (shares, sol_basis)
-    let refund_amount =
-        curve::sell_return(args.shares_to_sell, position.shares, position.sol_basis)?;
-
-    // 2. No fees on sell (protocol promise)
-    let net_refund = refund_amount;
-
-    require!(net_refund >= args.min_sol_out, AstraError::SlippageExceeded);
+    // Never exceeds the basis; the final sale sweeps any rounding residual
+    let refund_amount = position.sell_refund(args.shares_to_sell)?;
+
+    // 2. Sell fee (0 by default - free exit at basis unless operator opts in)
+    // min_sol_out guards what the seller actually receives (SlippageExceeded)
+    let (sell_fee, net_refund) = config.sell_proceeds(refund_amount, args.min_sol_out)?;
+
+    // The refund and fee both leave the launch PDA, which must stay rent-exempt.
+    // Checked before any state moves so the last seller gets a clear error
+    // rather than a runtime rent failure.
+    let launch_info = launch.to_account_info();
+    ensure_withdrawable(
+        launch_info.lamports(),
+        refund_amount,
+        Rent::get()?.minimum_balance(launch_info.data_len()),
+    )?;
 
     // 3. Update Position (V7: Simplified fields)
     position.shares = position
@@ -79,8 +104,18 @@ pub fn handler(ctx: Context<Sell>, args: SellArgs) -> Result<()> {
         .sol_basis
         .checked_sub(refund_amount)
         .ok_or(AstraError::MathOverflow)?;
+    position
+        .record_withdrawal(net_refund, refund_amount)
+        .ok_or(AstraError::MathOverflow)?;
+    if !position.is_holder() {
+        launch.holder_count = launch.holder_count.saturating_sub(1);
+    }
     position.last_updated_at = Clock::get()?.unix_timestamp;
 
+    // Sell-pressure circuit breaker (before total_sol moves, so a new window
+    // snapshots the pre-sale reserve). Sells stay open either way.
+    let breaker_tripped = launch.record_outflow(position.last_updated_at, refund_amount)?;
+
     // 4. Update Launch Totals (V7: Simplified)
     launch.total_shares = launch
         .total_shares
@@ -91,29 +126,44 @@ pub fn handler(ctx: Context<Sell>, args: SellArgs) -> Result<()> {
         .checked_sub(refund_amount)
         .ok_or(AstraError::MathOverflow)?;
 
-    // 5. Transfer Net Refund from Launch PDA to Seller
-    **launch.to_account_info().try_borrow_mut_lamports()? = launch
-        .to_account_info()
-        .lamports()
-        .checked_sub(net_refund)
-        .ok_or(AstraError::MathOverflow)?;
-    **ctx.accounts.seller.try_borrow_mut_lamports()? = ctx
-        .accounts
-        .seller
-        .lamports()
-        .checked_add(net_refund)
-        .ok_or(AstraError::MathOverflow)?;
-
-    // 6. Emit Event
+    // 5. Transfer Net Refund from Launch PDA to Seller (never below rent)
+    transfer_from_pda(
+        &launch.to_account_info(),
+        &ctx.accounts.seller.to_account_info(),
+        net_refund,
+    )?;
+
+    // 6. Route Sell Fee from Launch PDA to Treasury
+    transfer_from_pda(
+        &launch.to_account_info(),
+        &ctx.accounts.protocol_fee_wallet.to_account_info(),
+        sell_fee,
+    )?;
+
+    // 7. Emit Event
     emit!(crate::events::SharesSold {
         launch: launch.key(),
         seller: ctx.accounts.seller.key(),
         shares_sold: args.shares_to_sell,
         sol_refunded: net_refund,
+        sell_fee,
+        holder_count: launch.holder_count,
         timestamp: position.last_updated_at,
     });
 
+    if breaker_tripped {
+        emit!(crate::events::CircuitBreakerTripped {
+            launch: launch.key(),
+            net_outflow: launch.net_outflow(),
+            window_base_sol: launch.flow_window_base_sol,
+            limit_bps: launch.sell_pressure_limit_bps,
+            window_start: launch.flow_window_start,
+            timestamp: position.last_updated_at,
+        });
+        msg!("CIRCUIT BREAKER: Buys frozen on launch {}", launch.key());
+    }
+
     // Reset reentrancy flag
-    launch.operation_in_progress = false;
+    launch.end_operation();
     Ok(())
 }
@@ -0,0 +1,47 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Set the protocol emergency stop (authority only)
+/// The reason code is stored on config and emitted for incident transparency
+///
+/// While paused, exactly these instructions fail with ProtocolPaused:
+/// create_launch, buy, buy_usd, sell, boost_graduation, claim_tokens,
+/// claim_vesting, claim_refund, claim_creator_fees, stake_for_verification
+/// and poke.
+///
+/// Everything else stays open, including instructions that move SOL:
+/// graduate, force_graduate, withdraw_boost, push_refund, top_up_refund_reserve,
+/// sweep_fee_escrow, close_launch, claim_keeper_rewards, claim_booster_yield
+/// and slash_creator_bond - operators need them to wind down an incident.
+/// `set_paused(false, ..)` is the unpause.
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<SetPaused>, paused: bool, reason_code: PauseReason) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let event = ctx.accounts.config.set_paused(
+        paused,
+        reason_code,
+        authority,
+        Clock::get()?.unix_timestamp,
+    );
+
+    emit!(event);
+
+    msg!(
+        "PROTOCOL {}: {:?}",
+        if paused { "PAUSED" } else { "UNPAUSED" },
+        reason_code
+    );
+
+    Ok(())
+}
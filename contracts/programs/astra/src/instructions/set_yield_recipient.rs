@@ -0,0 +1,46 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Route the creator's share of vault yield to another wallet (creator only)
+/// Omit the recipient account to send it back to the creator
+#[derive(Accounts)]
+pub struct SetYieldRecipient<'info> {
+    #[account(
+        constraint = creator.key() == vault.creator @ AstraError::Unauthorized
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.launch.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// New yield recipient; must not be executable (poke couldn't pay it)
+    /// CHECK: Only its key and executable flag are read
+    pub yield_recipient: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<SetYieldRecipient>) -> Result<()> {
+    let yield_recipient = match &ctx.accounts.yield_recipient {
+        Some(recipient) => {
+            Vault::check_yield_recipient(recipient.key, recipient.executable)?;
+            Some(recipient.key())
+        }
+        None => None,
+    };
+
+    let vault = &mut ctx.accounts.vault;
+    vault.set_yield_recipient(yield_recipient)?;
+
+    emit!(crate::events::YieldRecipientUpdated {
+        vault: vault.key(),
+        creator: ctx.accounts.creator.key(),
+        yield_recipient,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use crate::transfer::transfer_from_pda;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Slash a creator's verification bond to the protocol treasury (authority only)
+///
+/// For creators who rug after fast-tracking verification. Also closes the
+/// bond fast-track for that creator permanently.
+///
+/// Optionally slashes the unvested seed of one of the creator's graduated
+/// launches too: pass the launch, the creator's position, the token mint, the
+/// launch's token account and the token program. Only locked_shares are taken
+/// (see Launch::slash_unvested_seed) and their holder tokens are burned.
+#[derive(Accounts)]
+pub struct SlashCreatorBond<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"creator_stats", creator_bond.creator.as_ref()],
+        bump = creator_stats.bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+
+    #[account(
+        mut,
+        seeds = [b"creator_bond", creator_bond.creator.as_ref()],
+        bump = creator_bond.bump
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+
+    /// CHECK: Protocol fee wallet verified against config
+    #[account(mut, address = config.protocol_fee_wallet)]
+    pub protocol_fee_wallet: UncheckedAccount<'info>,
+
+    /// Graduated launch whose unvested seed is slashed as well (optional)
+    #[account(
+        mut,
+        constraint = launch.creator == creator_bond.creator @ AstraError::Unauthorized,
+        constraint = launch.claims_in_protocol() @ AstraError::ClaimViaDistributor
+    )]
+    pub launch: Option<Box<Account<'info, Launch>>>,
+
+    /// The creator's position in `launch` (checked in the handler)
+    #[account(mut)]
+    pub creator_position: Option<Box<Account<'info, Position>>>,
+
+    /// CHECK: Mint verified against launch state in the handler
+    #[account(mut)]
+    pub token_mint: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = launch
+    )]
+    pub launch_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+impl SlashCreatorBond<'_> {
+    /// Slash the launch's unvested seed and burn its holder tokens
+    /// Returns (shares slashed, tokens burned)
+    fn slash_unvested_seed(&mut self) -> Result<(u64, u64)> {
+        let (
+            Some(launch),
+            Some(position),
+            Some(token_mint),
+            Some(launch_token_account),
+            Some(token_program),
+        ) = (
+            self.launch.as_mut(),
+            self.creator_position.as_mut(),
+            self.token_mint.as_ref(),
+            self.launch_token_account.as_ref(),
+            self.token_program.as_ref(),
+        )
+        else {
+            return err!(AstraError::SeedSlashAccountsMissing);
+        };
+
+        require!(
+            position.launch == launch.key() && position.user == launch.creator,
+            AstraError::Unauthorized
+        );
+        require!(
+            launch.token_mint == Some(token_mint.key()),
+            AstraError::InvalidCalculation
+        );
+
+        let (shares, tokens) = launch.slash_unvested_seed(position)?;
+
+        let launch_id_bytes = launch.launch_id.to_le_bytes();
+        let seeds = &[
+            b"launch",
+            launch.creator.as_ref(),
+            &launch_id_bytes,
+            &[launch.bump],
+        ];
+        token::burn(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Burn {
+                    mint: token_mint.to_account_info(),
+                    from: launch_token_account.to_account_info(),
+                    authority: launch.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            tokens,
+        )?;
+
+        Ok((shares, tokens))
+    }
+}
+
+pub fn handler(ctx: Context<SlashCreatorBond>) -> Result<()> {
+    if ctx.accounts.launch.is_some() {
+        let (shares, tokens) = ctx.accounts.slash_unvested_seed()?;
+        let launch = ctx.accounts.launch.as_ref().unwrap();
+        emit!(crate::events::UnvestedSeedSlashed {
+            launch: launch.key(),
+            creator: launch.creator,
+            shares_slashed: shares,
+            tokens_burned: tokens,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    let bond = &mut ctx.accounts.creator_bond;
+    let amount = bond.slash();
+    ctx.accounts.creator_stats.record_bond_slash();
+
+    transfer_from_pda(
+        &bond.to_account_info(),
+        &ctx.accounts.protocol_fee_wallet.to_account_info(),
+        amount,
+    )?;
+
+    emit!(crate::events::CreatorBondSlashed {
+        creator: bond.creator,
+        authority: ctx.accounts.authority.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("CREATOR BOND SLASHED: {} ({} lamports)", bond.creator, amount);
+
+    Ok(())
+}
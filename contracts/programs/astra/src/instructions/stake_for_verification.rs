@@ -0,0 +1,72 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Stake SOL into the creator's verification bond
+///
+/// Fast-track to verified status (higher creator fee tier) without waiting
+/// for a graduation. Requires an existing CreatorStats (i.e. at least one
+/// launch). The bond is refundable after CREATOR_BOND_LOCK_SECONDS and
+/// slashable by the authority if the creator rugs.
+#[derive(Accounts)]
+pub struct StakeForVerification<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"creator_stats", creator.key().as_ref()],
+        bump = creator_stats.bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorBond::INIT_SPACE,
+        seeds = [b"creator_bond", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<StakeForVerification>, amount: u64) -> Result<()> {
+    ctx.accounts.config.check_not_paused()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let bond = &mut ctx.accounts.creator_bond;
+    if bond.creator == Pubkey::default() {
+        bond.creator = ctx.accounts.creator.key();
+        bond.bump = ctx.bumps.creator_bond;
+    }
+    bond.stake(amount, now)?;
+
+    let creator_stats = &mut ctx.accounts.creator_stats;
+    creator_stats.sync_bond(bond.amount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: bond.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit!(crate::events::CreatorBondStaked {
+        creator: bond.creator,
+        amount,
+        total_bonded: bond.amount,
+        verified: creator_stats.is_verified(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use crate::transfer::{transfer_from_pda, withdrawable_lamports};
+use anchor_lang::prelude::*;
+
+/// Drain and close a refunding launch's fee escrow (janitor)
+///
+/// claim_creator_fees only runs after graduation, so fees escrowed during
+/// trading would otherwise be stranded once a launch fails. The fees first
+/// cover any refund shortfall on the launch PDA; the remainder and the
+/// escrow's rent go back to the creator, who paid for it at create_launch.
+/// close_launch requires this to have run.
+#[derive(Accounts)]
+pub struct SweepFeeEscrow<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_authorized_janitor(&caller.key()) @ AstraError::UnauthorizedKeeper
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = launch.refund_mode @ AstraError::RefundModeNotActive
+    )]
+    pub launch: Account<'info, Launch>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"fee_escrow", launch.key().as_ref()],
+        bump = fee_escrow.bump
+    )]
+    pub fee_escrow: Account<'info, FeeEscrow>,
+
+    /// CHECK: Receives the leftover fees and escrow rent - verified against launch.creator
+    #[account(mut, address = launch.creator @ AstraError::NotCreator)]
+    pub creator: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SweepFeeEscrow>) -> Result<()> {
+    let launch_info = ctx.accounts.launch.to_account_info();
+    let escrow_info = ctx.accounts.fee_escrow.to_account_info();
+
+    let shortfall = ctx
+        .accounts
+        .launch
+        .refund_shortfall(withdrawable_lamports(&launch_info)?);
+    let (to_refund_pool, to_creator) =
+        FeeEscrow::refund_sweep_split(withdrawable_lamports(&escrow_info)?, shortfall);
+
+    transfer_from_pda(&escrow_info, &launch_info, to_refund_pool)?;
+    transfer_from_pda(
+        &escrow_info,
+        &ctx.accounts.creator.to_account_info(),
+        to_creator,
+    )?;
+
+    let launch = &mut ctx.accounts.launch;
+    launch.creator_accrued_fees = 0;
+
+    emit!(crate::events::FeeEscrowSwept {
+        launch: launch.key(),
+        caller: ctx.accounts.caller.key(),
+        to_refund_pool,
+        to_creator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use crate::transfer::withdrawable_lamports;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Add SOL to an under-funded launch in refund mode (authority only)
+///
+/// Backstop for the pro-rata refund model: refunds never fail on a short PDA,
+/// but holders take a haircut until the shortfall is covered. Topping up to
+/// the shortfall restores full refunds for everyone who hasn't claimed yet.
+#[derive(Accounts)]
+pub struct TopUpRefundReserve<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = launch.refund_mode @ AstraError::RefundModeNotActive
+    )]
+    pub launch: Account<'info, Launch>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<TopUpRefundReserve>, amount: u64) -> Result<()> {
+    let launch = &ctx.accounts.launch;
+    let available = withdrawable_lamports(&launch.to_account_info())?;
+    let shortfall = launch.refund_shortfall(available);
+    require!(
+        amount > 0 && amount <= shortfall,
+        AstraError::InvalidTopUpAmount
+    );
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: launch.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit!(crate::events::RefundReserveToppedUp {
+        launch: launch.key(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+        shortfall_remaining: shortfall - amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Withdraw the creator's whole verification bond and close it
+///
+/// Allowed once CREATOR_BOND_LOCK_SECONDS have passed since the last stake.
+/// Verified status then falls back to the graduation track record.
+#[derive(Accounts)]
+pub struct UnstakeVerification<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"creator_stats", creator.key().as_ref()],
+        bump = creator_stats.bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+
+    /// Bond lamports (stake + rent) return to the creator on close
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"creator_bond", creator.key().as_ref()],
+        bump = creator_bond.bump
+    )]
+    pub creator_bond: Account<'info, CreatorBond>,
+}
+
+pub fn handler(ctx: Context<UnstakeVerification>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let bond = &ctx.accounts.creator_bond;
+    require!(bond.can_unstake(now)?, AstraError::BondLocked);
+
+    let amount = bond.amount;
+    ctx.accounts.creator_stats.sync_bond(0);
+
+    emit!(crate::events::CreatorBondUnstaked {
+        creator: bond.creator,
+        amount,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::constants::MAX_AMM_CONFIGS;
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Replace the allowlist of Raydium AMM configs graduation may use (authority only)
+#[derive(Accounts)]
+pub struct UpdateAmmConfigs<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<UpdateAmmConfigs>, amm_configs: Vec<Pubkey>) -> Result<()> {
+    require!(
+        amm_configs.len() <= MAX_AMM_CONFIGS,
+        AstraError::TooManyAmmConfigs
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.allowed_amm_configs = amm_configs;
+
+    emit!(crate::events::AmmConfigsUpdated {
+        amm_configs: config.allowed_amm_configs.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Set the creator concentration cap enforced by graduate (authority only)
+/// 0 disables the gate; bounded by BPS_DENOMINATOR
+#[derive(Accounts)]
+pub struct UpdateCreatorConcentrationCap<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateCreatorConcentrationCap>,
+    max_creator_concentration_bps: u64,
+) -> Result<()> {
+    require!(
+        max_creator_concentration_bps <= BPS_DENOMINATOR,
+        AstraError::InvalidConcentrationCap
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.max_creator_concentration_bps = max_creator_concentration_bps;
+
+    emit!(crate::events::CreatorConcentrationCapUpdated {
+        max_creator_concentration_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
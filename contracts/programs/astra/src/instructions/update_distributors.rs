@@ -0,0 +1,33 @@
+use crate::constants::MAX_DISTRIBUTORS;
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Replace the allowlist of holder distributors launches may opt into (authority only)
+#[derive(Accounts)]
+pub struct UpdateDistributors<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<UpdateDistributors>, distributors: Vec<Pubkey>) -> Result<()> {
+    require!(
+        distributors.len() <= MAX_DISTRIBUTORS,
+        AstraError::TooManyDistributors
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.allowed_distributors = distributors;
+
+    emit!(crate::events::DistributorsUpdated {
+        distributors: config.allowed_distributors.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Replace the M-of-N operator set graduate requires (authority only)
+/// An empty set with threshold 1 restores single-operator mode
+#[derive(Accounts)]
+pub struct UpdateGraduationOperators<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateGraduationOperators>,
+    graduation_operators: Vec<Pubkey>,
+    graduation_threshold: u8,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.set_graduation_operators(graduation_operators, graduation_threshold)?;
+
+    emit!(crate::events::GraduationOperatorsUpdated {
+        graduation_operators: config.graduation_operators.clone(),
+        graduation_threshold: config.graduation_threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Set or clear the janitor rewards wallet (authority only)
+/// Some = keeper-only janitor calls with rent to this wallet; None = permissionless
+#[derive(Accounts)]
+pub struct UpdateJanitorRewardsWallet<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateJanitorRewardsWallet>,
+    janitor_rewards_wallet: Option<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.janitor_rewards_wallet = janitor_rewards_wallet;
+
+    emit!(crate::events::JanitorRewardsWalletUpdated {
+        janitor_rewards_wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::constants::MAX_METADATA_UPDATES;
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Fix a launch's URI (and optionally name) before graduation (creator only)
+/// The symbol can't change; each call spends one of MAX_METADATA_UPDATES
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        constraint = creator.key() == launch.creator @ AstraError::Unauthorized
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = !launch.graduated @ AstraError::AlreadyGraduated
+    )]
+    pub launch: Account<'info, Launch>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateMetadataArgs {
+    /// New display name (None = keep the current one)
+    pub name: Option<String>,
+    pub uri: String,
+    /// SHA-256 of the JSON at the new `uri` (None = no commitment)
+    pub metadata_hash: Option<[u8; 32]>,
+    /// Size bound on the new `uri` content (1..=MAX_METADATA_FETCH_BYTES)
+    pub max_metadata_bytes: Option<u32>,
+}
+
+pub fn handler(ctx: Context<UpdateMetadata>, args: UpdateMetadataArgs) -> Result<()> {
+    let launch = &mut ctx.accounts.launch;
+    let new_uri = args.uri.clone();
+    let old_uri = launch.update_metadata(
+        args.name,
+        args.uri,
+        args.metadata_hash,
+        args.max_metadata_bytes,
+    )?;
+
+    emit!(crate::events::MetadataUpdated {
+        launch: launch.key(),
+        name: launch.name.clone(),
+        old_uri,
+        new_uri,
+        metadata_hash: launch.metadata_hash,
+        max_metadata_bytes: launch.max_metadata_bytes,
+        updates_remaining: MAX_METADATA_UPDATES - launch.metadata_updates_used,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Rotate the operator wallet (authority only)
+/// The operator signs price updates, graduation locks and keeper-gated janitor calls
+#[derive(Accounts)]
+pub struct UpdateOperatorWallet<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<UpdateOperatorWallet>, operator_wallet: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_wallet =
+        GlobalConfig::rotate_wallet(&mut config.operator_wallet, operator_wallet)?;
+
+    emit!(crate::events::OperatorWalletUpdated {
+        previous_wallet,
+        wallet: operator_wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
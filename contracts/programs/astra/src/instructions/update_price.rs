@@ -0,0 +1,51 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// A single feed update in a batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PriceUpdate {
+    /// Asset mint the price is for (SOL_PRICE_FEED_ASSET for SOL)
+    pub asset: Pubkey,
+    /// Price in whole USD
+    pub price_usd: u64,
+    /// Confidence interval as bps of the price (Pyth conf / price)
+    /// 0 = not reported; create_launch won't price seed bounds from it
+    pub confidence_bps: u64,
+}
+
+/// Batch-update cached USD prices (operator or authority)
+/// Called by the price cron; the SOL feed also refreshes sol_price_usd
+#[derive(Accounts)]
+pub struct UpdatePrice<'info> {
+    #[account(
+        constraint = updater.key() == config.operator_wallet
+            || updater.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub updater: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<UpdatePrice>, updates: Vec<PriceUpdate>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+
+    for update in updates {
+        config.set_price_feed(
+            update.asset,
+            update.price_usd,
+            update.confidence_bps,
+            now,
+        )?;
+
+        emit!(crate::events::PriceUpdated {
+            asset: update.asset,
+            price_usd: update.price_usd,
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::constants::{MAX_PRICE_STALENESS_SECONDS, PYTH_SOL_USD_FEED_ID, SOL_PRICE_FEED_ASSET};
+use crate::state::*;
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
+
+/// Refresh the SOL price from a verified Pyth update (permissionless)
+///
+/// The update must be for PYTH_SOL_USD_FEED_ID, fully verified, published
+/// within MAX_PRICE_STALENESS_SECONDS and strictly newer than the stored SOL
+/// price (so older updates can't be replayed). The operator's update_price stays
+/// available as the fallback when Pyth is down.
+#[derive(Accounts)]
+pub struct UpdatePricePyth<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Posted by the Pyth receiver program; ownership checked by Account
+    pub price_update: Account<'info, PriceUpdateV2>,
+}
+
+pub fn handler(ctx: Context<UpdatePricePyth>) -> Result<()> {
+    let feed_id = get_feed_id_from_hex(PYTH_SOL_USD_FEED_ID)?;
+    let price = ctx.accounts.price_update.get_price_no_older_than(
+        &Clock::get()?,
+        MAX_PRICE_STALENESS_SECONDS as u64,
+        &feed_id,
+    )?;
+    let (price_usd, confidence_bps) =
+        GlobalConfig::usd_from_pyth(price.price, price.conf, price.exponent)?;
+
+    // Stamped with the publish time, so staleness checks measure the price's
+    // real age rather than when it was relayed
+    let config = &mut ctx.accounts.config;
+    config.set_price_feed(
+        SOL_PRICE_FEED_ASSET,
+        price_usd,
+        confidence_bps,
+        price.publish_time,
+    )?;
+
+    emit!(crate::events::PriceUpdated {
+        asset: SOL_PRICE_FEED_ASSET,
+        price_usd,
+        timestamp: price.publish_time,
+    });
+
+    Ok(())
+}
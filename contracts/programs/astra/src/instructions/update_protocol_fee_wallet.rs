@@ -0,0 +1,29 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Rotate the treasury wallet that receives trading and launch fees (authority only)
+#[derive(Accounts)]
+pub struct UpdateProtocolFeeWallet<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<UpdateProtocolFeeWallet>, protocol_fee_wallet: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_wallet =
+        GlobalConfig::rotate_wallet(&mut config.protocol_fee_wallet, protocol_fee_wallet)?;
+
+    emit!(crate::events::ProtocolFeeWalletUpdated {
+        previous_wallet,
+        wallet: protocol_fee_wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::constants::MAX_SELL_FEE_BPS;
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Set the protocol-wide sell fee (authority only)
+/// Default is 0 (free exits); bounded by MAX_SELL_FEE_BPS
+#[derive(Accounts)]
+pub struct UpdateSellFee<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<UpdateSellFee>, sell_fee_bps: u64) -> Result<()> {
+    require!(sell_fee_bps <= MAX_SELL_FEE_BPS, AstraError::SellFeeTooHigh);
+
+    let config = &mut ctx.accounts.config;
+    config.sell_fee_bps = sell_fee_bps;
+
+    emit!(crate::events::SellFeeUpdated {
+        sell_fee_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
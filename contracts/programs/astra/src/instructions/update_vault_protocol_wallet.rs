@@ -0,0 +1,32 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Rotate the wallet that receives the protocol's 10% of vault yield (authority only)
+#[derive(Accounts)]
+pub struct UpdateVaultProtocolWallet<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateVaultProtocolWallet>,
+    vault_protocol_wallet: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_wallet =
+        GlobalConfig::rotate_wallet(&mut config.vault_protocol_wallet, vault_protocol_wallet)?;
+
+    emit!(crate::events::VaultProtocolWalletUpdated {
+        previous_wallet,
+        wallet: vault_protocol_wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
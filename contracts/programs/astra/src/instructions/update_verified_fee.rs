@@ -0,0 +1,30 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Set the total buy fee on verified creators' launches (authority only)
+/// 0 disables the discount; otherwise CREATOR_FEE_VERIFIED_BPS..=TOTAL_FEE_BPS
+#[derive(Accounts)]
+pub struct UpdateVerifiedFee<'info> {
+    #[account(
+        constraint = authority.key() == config.authority @ AstraError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<UpdateVerifiedFee>, verified_total_fee_bps: u64) -> Result<()> {
+    GlobalConfig::validate_verified_total_fee_bps(verified_total_fee_bps)?;
+
+    let config = &mut ctx.accounts.config;
+    config.verified_total_fee_bps = verified_total_fee_bps;
+
+    emit!(crate::events::VerifiedFeeUpdated {
+        verified_total_fee_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Read-only accounting audit for a launch
+///
+/// Every position of the launch is passed in `remaining_accounts`, sorted by
+/// address (which also rules out duplicates). Their summed shares and SOL
+/// basis are compared with the launch counters and the result is emitted as
+/// LaunchReconciled - a mismatch is reported, not treated as an error.
+#[derive(Accounts)]
+pub struct VerifyLaunchAccounting<'info> {
+    pub launch: Account<'info, Launch>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VerifyLaunchAccounting<'info>>,
+) -> Result<()> {
+    let launch = &ctx.accounts.launch;
+    let launch_key = launch.key();
+
+    let mut positions: Vec<Position> = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut previous: Option<Pubkey> = None;
+
+    for info in ctx.remaining_accounts.iter() {
+        require!(
+            previous.is_none_or(|key| key < info.key()),
+            AstraError::InvalidReconciliationAccounts
+        );
+        previous = Some(info.key());
+
+        let position = Account::<Position>::try_from(info)?;
+        require_keys_eq!(
+            position.launch,
+            launch_key,
+            AstraError::InvalidReconciliationAccounts
+        );
+        positions.push(position.into_inner());
+    }
+
+    emit!(launch.reconcile(launch_key, &positions, Clock::get()?.unix_timestamp)?);
+
+    Ok(())
+}
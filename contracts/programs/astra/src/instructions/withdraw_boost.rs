@@ -0,0 +1,38 @@
+use crate::errors::AstraError;
+use crate::state::*;
+use crate::transfer::transfer_from_pda;
+use anchor_lang::prelude::*;
+
+/// Return a booster's SOL from a launch that went to refunds instead of graduating
+#[derive(Accounts)]
+pub struct WithdrawBoost<'info> {
+    #[account(mut)]
+    pub booster: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = launch.refund_mode @ AstraError::RefundModeNotActive
+    )]
+    pub launch: Account<'info, Launch>,
+}
+
+pub fn handler(ctx: Context<WithdrawBoost>) -> Result<()> {
+    let launch = &mut ctx.accounts.launch;
+    let amount = launch.take_boost_refund(&ctx.accounts.booster.key())?;
+
+    // Booster SOL is outside total_sol, so this never eats into holder refunds
+    transfer_from_pda(
+        &launch.to_account_info(),
+        &ctx.accounts.booster.to_account_info(),
+        amount,
+    )?;
+
+    emit!(crate::events::BoostWithdrawn {
+        launch: launch.key(),
+        booster: ctx.accounts.booster.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
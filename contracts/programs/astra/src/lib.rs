@@ -20,7 +20,15 @@
 //! - Fair launch: USD-based economics, not SOL-volatile
 //! - Exit guarantee: Sell anytime for proportional SOL (no gains until graduation)
 //! - Yield sharing: LP fees distributed to creator/protocol
-//! - Graduation gates: Min holders (100) + max concentration (10%) enforced off-chain
+//! - Graduation gates: Min holders (100) + max concentration (10%) enforced on-chain
+//!
+//! # Deployment
+//! Account layouts (Launch, GlobalConfig, Vault, ...) are not versioned and
+//! have no realloc migrations: fields were added mid-struct as features
+//! landed, so accounts written by an earlier build don't deserialize. Every
+//! layout change ships as a fresh deployment (new program ID).
+//! Launches whose holder_count predates holder tracking get it set once
+//! through `backfill_holder_count` or `recount_holders`.
 
 use anchor_lang::prelude::*;
 
@@ -29,7 +37,10 @@ pub mod curve;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod random;
 pub mod state;
+pub mod time;
+pub mod transfer;
 
 use instructions::*;
 
@@ -42,21 +53,9 @@ declare_id!("5AWQHT1RLaHbTiwcsPouTrTaVSA2XGyFHc8iTNp6Ruzu");
 pub mod astra {
     use super::*;
 
-    /// Initialize the protocol with configuration
-    pub fn initialize(
-        ctx: Context<Initialize>,
-        operator_wallet: Pubkey,
-        protocol_fee_wallet: Pubkey,
-        vault_protocol_wallet: Pubkey,
-        min_seed_lamports: u64,
-    ) -> Result<()> {
-        instructions::initialize::handler(
-            ctx,
-            operator_wallet,
-            protocol_fee_wallet,
-            vault_protocol_wallet,
-            min_seed_lamports,
-        )
+    /// Initialize the protocol with its full configuration in one transaction
+    pub fn initialize(ctx: Context<Initialize>, args: state::InitConfigArgs) -> Result<()> {
+        instructions::initialize::handler(ctx, args)
     }
 
     /// Create a new token launch
@@ -69,14 +68,34 @@ pub mod astra {
         instructions::buy::handler(ctx, args)
     }
 
+    /// Buy shares for an exact USD amount (cents), converted at the cached price
+    pub fn buy_usd(ctx: Context<Buy>, args: BuyUsdArgs) -> Result<()> {
+        instructions::buy_usd::handler(ctx, args)
+    }
+
     /// Sell shares for proportional SOL
     pub fn sell(ctx: Context<Sell>, args: SellArgs) -> Result<()> {
         instructions::sell::handler(ctx, args)
     }
 
-    /// Graduate launch to Raydium (operator only)
-    /// Graduation gates checked off-chain by cron job
-    pub fn graduate(ctx: Context<Graduate>) -> Result<()> {
+    /// Add SOL to a launch's graduation pool as its booster
+    pub fn boost_graduation(ctx: Context<BoostGraduation>, amount: u64) -> Result<()> {
+        instructions::boost_graduation::handler(ctx, amount)
+    }
+
+    /// Withdraw booster SOL from a refunding launch
+    pub fn withdraw_boost(ctx: Context<WithdrawBoost>) -> Result<()> {
+        instructions::withdraw_boost::handler(ctx)
+    }
+
+    /// Snapshot the curve and freeze trading ahead of graduate (operator only)
+    pub fn lock_graduation(ctx: Context<LockGraduation>) -> Result<()> {
+        instructions::lock_graduation::handler(ctx)
+    }
+
+    /// Graduate launch to Raydium (operator only, M-of-N when an operator set is configured)
+    /// Requires a prior lock_graduation; holder and concentration gates checked on-chain
+    pub fn graduate<'info>(ctx: Context<'_, '_, 'info, 'info, Graduate<'info>>) -> Result<()> {
         instructions::graduate::handler(ctx)
     }
 
@@ -86,9 +105,9 @@ pub mod astra {
         instructions::force_graduate::handler(ctx)
     }
 
-    /// Claim SPL tokens after graduation
-    pub fn claim_tokens(ctx: Context<ClaimTokens>) -> Result<()> {
-        instructions::claim_tokens::handler(ctx)
+    /// Claim SPL tokens after graduation, optionally with a min_tokens_out floor
+    pub fn claim_tokens(ctx: Context<ClaimTokens>, min_tokens_out: Option<u64>) -> Result<()> {
+        instructions::claim_tokens::handler(ctx, min_tokens_out)
     }
 
     /// Claim vested shares (creator only, post-graduation)
@@ -96,6 +115,11 @@ pub mod astra {
         instructions::claim_vesting::handler(ctx)
     }
 
+    /// Claim the poke yield held for a graduation booster
+    pub fn claim_booster_yield(ctx: Context<ClaimBoosterYield>) -> Result<()> {
+        instructions::claim_booster_yield::handler(ctx)
+    }
+
     /// Claim accrued creator fees
     pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
         instructions::claim_creator_fees::handler(ctx)
@@ -111,6 +135,36 @@ pub mod astra {
         instructions::poke::handler(ctx)
     }
 
+    /// Route the creator's vault yield share to another wallet (creator only)
+    pub fn set_yield_recipient(ctx: Context<SetYieldRecipient>) -> Result<()> {
+        instructions::set_yield_recipient::handler(ctx)
+    }
+
+    /// Fix a launch's URI (and optionally name) before graduation (creator only)
+    pub fn update_metadata(ctx: Context<UpdateMetadata>, args: UpdateMetadataArgs) -> Result<()> {
+        instructions::update_metadata::handler(ctx, args)
+    }
+
+    /// Emit the refund a position would receive right now (read-only)
+    pub fn preview_refund(ctx: Context<PreviewRefund>) -> Result<()> {
+        instructions::preview_refund::handler(ctx)
+    }
+
+    /// Simulate a buy of `sol_amount` with buy's fee and curve math (read-only)
+    pub fn quote_buy(ctx: Context<QuoteBuy>, sol_amount: u64) -> Result<()> {
+        instructions::quote_buy::handler(ctx, sol_amount)
+    }
+
+    /// Simulate selling `shares_to_sell` from a position (read-only)
+    pub fn quote_sell(ctx: Context<QuoteSell>, shares_to_sell: u64) -> Result<()> {
+        instructions::quote_sell::handler(ctx, shares_to_sell)
+    }
+
+    /// Batch-claim poke rewards accrued to the caller's keeper account
+    pub fn claim_keeper_rewards(ctx: Context<ClaimKeeperRewards>) -> Result<()> {
+        instructions::claim_keeper_rewards::handler(ctx)
+    }
+
     /// Enable refund mode (permissionless after 7 days)
     pub fn enable_refund(ctx: Context<EnableRefund>) -> Result<()> {
         instructions::enable_refund::handler(ctx)
@@ -121,8 +175,231 @@ pub mod astra {
         instructions::push_refund::handler(ctx)
     }
 
+    /// Add SOL to an under-funded refunding launch (authority only)
+    pub fn top_up_refund_reserve(ctx: Context<TopUpRefundReserve>, amount: u64) -> Result<()> {
+        instructions::top_up_refund_reserve::handler(ctx, amount)
+    }
+
+    /// Drain and close a refunding launch's fee escrow (janitor)
+    pub fn sweep_fee_escrow(ctx: Context<SweepFeeEscrow>) -> Result<()> {
+        instructions::sweep_fee_escrow::handler(ctx)
+    }
+
     /// Close launch after all refunds processed
     pub fn close_launch(ctx: Context<CloseLaunch>) -> Result<()> {
         instructions::close_launch::handler(ctx)
     }
+
+    /// Close a wound-down vault and its LP token account (authority only)
+    pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+        instructions::close_vault::handler(ctx)
+    }
+
+    /// Propose a new authority, effective after a 48h delay (authority only)
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a proposed authority change after the delay (pending authority only)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    /// Abort a pending authority change (authority only)
+    pub fn cancel_authority_change(ctx: Context<CancelAuthorityChange>) -> Result<()> {
+        instructions::cancel_authority_change::handler(ctx)
+    }
+
+    /// Replace the graduation AMM config allowlist (authority only)
+    pub fn update_amm_configs(
+        ctx: Context<UpdateAmmConfigs>,
+        amm_configs: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_amm_configs::handler(ctx, amm_configs)
+    }
+
+    /// Replace the holder distributor allowlist (authority only)
+    pub fn update_distributors(
+        ctx: Context<UpdateDistributors>,
+        distributors: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_distributors::handler(ctx, distributors)
+    }
+
+    /// Set the M-of-N operator set required by graduate (authority only)
+    pub fn update_graduation_operators(
+        ctx: Context<UpdateGraduationOperators>,
+        graduation_operators: Vec<Pubkey>,
+        graduation_threshold: u8,
+    ) -> Result<()> {
+        instructions::update_graduation_operators::handler(
+            ctx,
+            graduation_operators,
+            graduation_threshold,
+        )
+    }
+
+    /// Stake SOL for fast-track creator verification
+    pub fn stake_for_verification(ctx: Context<StakeForVerification>, amount: u64) -> Result<()> {
+        instructions::stake_for_verification::handler(ctx, amount)
+    }
+
+    /// Withdraw and close the creator verification bond (after the lock)
+    pub fn unstake_verification(ctx: Context<UnstakeVerification>) -> Result<()> {
+        instructions::unstake_verification::handler(ctx)
+    }
+
+    /// Slash a creator's verification bond to the treasury, and optionally a
+    /// graduated launch's unvested seed (authority only)
+    pub fn slash_creator_bond(ctx: Context<SlashCreatorBond>) -> Result<()> {
+        instructions::slash_creator_bond::handler(ctx)
+    }
+
+    /// Emit a creator's reputation score (read-only)
+    pub fn get_creator_reputation(ctx: Context<GetCreatorReputation>) -> Result<()> {
+        instructions::get_creator_reputation::handler(ctx)
+    }
+
+    /// Emit the current fee schedule (read-only)
+    pub fn get_fee_schedule(ctx: Context<GetFeeSchedule>) -> Result<()> {
+        instructions::get_fee_schedule::handler(ctx)
+    }
+
+    /// Emit the graduation tokens-per-share ratio claims use (read-only)
+    pub fn get_tokens_per_share(ctx: Context<GetTokensPerShare>) -> Result<()> {
+        instructions::get_tokens_per_share::handler(ctx)
+    }
+
+    /// Pause or unpause the protocol with a reason code (authority only)
+    pub fn set_paused(
+        ctx: Context<SetPaused>,
+        paused: bool,
+        reason_code: state::PauseReason,
+    ) -> Result<()> {
+        instructions::set_paused::handler(ctx, paused, reason_code)
+    }
+
+    /// Set the sell fee (authority only, default 0)
+    pub fn update_sell_fee(ctx: Context<UpdateSellFee>, sell_fee_bps: u64) -> Result<()> {
+        instructions::update_sell_fee::handler(ctx, sell_fee_bps)
+    }
+
+    /// Set the total buy fee on verified creators' launches (authority only, 0 = off)
+    pub fn update_verified_fee(
+        ctx: Context<UpdateVerifiedFee>,
+        verified_total_fee_bps: u64,
+    ) -> Result<()> {
+        instructions::update_verified_fee::handler(ctx, verified_total_fee_bps)
+    }
+
+    /// Batch-update cached USD price feeds (operator or authority)
+    /// Also the permissioned fallback for the SOL price when Pyth is down
+    pub fn update_price(ctx: Context<UpdatePrice>, updates: Vec<PriceUpdate>) -> Result<()> {
+        instructions::update_price::handler(ctx, updates)
+    }
+
+    /// Refresh the SOL price from a verified Pyth SOL/USD update (permissionless)
+    pub fn update_price_pyth(ctx: Context<UpdatePricePyth>) -> Result<()> {
+        instructions::update_price_pyth::handler(ctx)
+    }
+
+    /// Recompute min_seed_lamports from the cached SOL price (permissionless)
+    pub fn refresh_min_seed(ctx: Context<RefreshMinSeed>) -> Result<()> {
+        instructions::refresh_min_seed::handler(ctx)
+    }
+
+    /// Set the creator concentration cap for graduation (authority only)
+    pub fn update_creator_concentration_cap(
+        ctx: Context<UpdateCreatorConcentrationCap>,
+        max_creator_concentration_bps: u64,
+    ) -> Result<()> {
+        instructions::update_creator_concentration_cap::handler(ctx, max_creator_concentration_bps)
+    }
+
+    /// Set or clear the janitor rewards wallet (authority only)
+    pub fn update_janitor_rewards_wallet(
+        ctx: Context<UpdateJanitorRewardsWallet>,
+        janitor_rewards_wallet: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_janitor_rewards_wallet::handler(ctx, janitor_rewards_wallet)
+    }
+
+    /// Rotate the operator wallet (authority only)
+    pub fn update_operator_wallet(
+        ctx: Context<UpdateOperatorWallet>,
+        operator_wallet: Pubkey,
+    ) -> Result<()> {
+        instructions::update_operator_wallet::handler(ctx, operator_wallet)
+    }
+
+    /// Rotate the treasury wallet that receives trading and launch fees (authority only)
+    pub fn update_protocol_fee_wallet(
+        ctx: Context<UpdateProtocolFeeWallet>,
+        protocol_fee_wallet: Pubkey,
+    ) -> Result<()> {
+        instructions::update_protocol_fee_wallet::handler(ctx, protocol_fee_wallet)
+    }
+
+    /// Rotate the wallet that receives the protocol's 10% of vault yield (authority only)
+    pub fn update_vault_protocol_wallet(
+        ctx: Context<UpdateVaultProtocolWallet>,
+        vault_protocol_wallet: Pubkey,
+    ) -> Result<()> {
+        instructions::update_vault_protocol_wallet::handler(ctx, vault_protocol_wallet)
+    }
+
+    /// Set holder_count on a launch that predates holder tracking (authority only, once)
+    pub fn backfill_holder_count(
+        ctx: Context<BackfillHolderCount>,
+        holder_count: u64,
+    ) -> Result<()> {
+        instructions::backfill_holder_count::handler(ctx, holder_count)
+    }
+
+    /// Set holder_count by counting the launch's positions (authority only, once)
+    pub fn recount_holders<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecountHolders<'info>>,
+    ) -> Result<()> {
+        instructions::recount_holders::handler(ctx)
+    }
+
+    /// Clear a stuck reentrancy flag on a launch (authority only)
+    pub fn reset_operation_flag(ctx: Context<ResetOperationFlag>) -> Result<()> {
+        instructions::reset_operation_flag::handler(ctx)
+    }
+
+    /// Unfreeze buys after a tripped sell-pressure circuit breaker (authority only)
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        instructions::reset_circuit_breaker::handler(ctx)
+    }
+
+    /// Emit pending vault yield and its split (read-only)
+    pub fn preview_poke(ctx: Context<PreviewPoke>) -> Result<()> {
+        instructions::preview_poke::handler(ctx)
+    }
+
+    /// Select a share-weighted random winner among positions (creator only)
+    pub fn select_winner<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SelectWinner<'info>>,
+        source: random::RandomnessSource,
+    ) -> Result<()> {
+        instructions::select_winner::handler(ctx, source)
+    }
+
+    /// Check position balances sum to the launch's share/SOL totals (read-only)
+    pub fn verify_launch_accounting<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyLaunchAccounting<'info>>,
+    ) -> Result<()> {
+        instructions::verify_launch_accounting::handler(ctx)
+    }
+
+    /// Round-trip the bonding curve math at fixed points; fails on regression
+    pub fn curve_selftest(ctx: Context<CurveSelftest>) -> Result<()> {
+        instructions::curve_selftest::handler(ctx)
+    }
+
+    /// Emit graduation progress and recent volume (read-only)
+    pub fn estimate_graduation(ctx: Context<EstimateGraduation>) -> Result<()> {
+        instructions::estimate_graduation::handler(ctx)
+    }
 }
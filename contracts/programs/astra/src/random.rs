@@ -0,0 +1,116 @@
+//! Verifiable weighted selection for holder promotions
+//!
+//! Randomness is pluggable: the default mixes in the most recent slot hash,
+//! and a VRF source can be added as another `RandomnessSource` variant without
+//! changing the selection logic. A slot hash is known before the draw lands, so
+//! the caller can simulate it; it is only fit for low-stakes promotions.
+
+use crate::errors::AstraError;
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+/// Entropy source for `select_winner`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RandomnessSource {
+    /// Most recent entry of the SlotHashes sysvar
+    SlotHash,
+}
+
+/// Read the most recent slot hash from raw SlotHashes sysvar data
+/// Layout: u64 entry count, then (u64 slot, [u8; 32] hash) entries, newest first
+///
+/// # Errors
+/// * `InvalidCalculation` - If the sysvar holds no entries
+pub fn latest_slot_hash(data: &[u8]) -> Result<[u8; 32]> {
+    let count = data
+        .get(..8)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(AstraError::InvalidCalculation)?;
+    require!(count > 0, AstraError::InvalidCalculation);
+
+    let hash = data.get(16..48).ok_or(AstraError::InvalidCalculation)?;
+    Ok(hash.try_into().unwrap())
+}
+
+/// Domain-separate entropy per launch so one draw can't be replayed on another
+pub fn derive_randomness(entropy: &[u8; 32], launch: &Pubkey) -> [u8; 32] {
+    hashv(&[b"select_winner", launch.as_ref(), entropy]).to_bytes()
+}
+
+/// Pick an index with probability proportional to its weight
+///
+/// Returns None if the total weight is zero (or overflows u128).
+/// The 128-bit draw keeps modulo bias negligible for any u64 share totals.
+pub fn select_weighted(weights: &[u64], randomness: &[u8; 32]) -> Option<usize> {
+    let total = weights
+        .iter()
+        .try_fold(0u128, |acc, w| acc.checked_add(*w as u128))?;
+    if total == 0 {
+        return None;
+    }
+
+    let draw = u128::from_le_bytes(randomness[..16].try_into().ok()?) % total;
+
+    let mut cumulative = 0u128;
+    weights.iter().position(|w| {
+        cumulative += *w as u128;
+        draw < cumulative
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_weight_never_selected() {
+        let randomness = derive_randomness(&[7; 32], &Pubkey::new_unique());
+        assert_eq!(select_weighted(&[0, 5, 0], &randomness), Some(1));
+        assert_eq!(select_weighted(&[0, 0], &randomness), None);
+        assert_eq!(select_weighted(&[], &randomness), None);
+    }
+
+    #[test]
+    fn test_weighting_distribution_over_many_seeds() {
+        let launch = Pubkey::new_unique();
+        let weights = [1u64, 3, 6];
+        let mut wins = [0u32; 3];
+        let draws = 10_000u32;
+
+        for i in 0..draws {
+            let seed = hashv(&[&i.to_le_bytes()]).to_bytes();
+            let winner = select_weighted(&weights, &derive_randomness(&seed, &launch)).unwrap();
+            wins[winner] += 1;
+        }
+
+        // Expect 10% / 30% / 60% within 2 percentage points
+        for (i, weight) in weights.iter().enumerate() {
+            let expected = draws * *weight as u32 / 10;
+            let diff = wins[i].abs_diff(expected);
+            assert!(
+                diff < draws / 50,
+                "index {i}: {} wins, expected ~{expected}",
+                wins[i]
+            );
+        }
+    }
+
+    #[test]
+    fn test_latest_slot_hash_layout() {
+        let mut data = vec![0u8; 16];
+        data[..8].copy_from_slice(&1u64.to_le_bytes());
+        data.extend_from_slice(&[9u8; 32]);
+
+        assert_eq!(latest_slot_hash(&data).unwrap(), [9u8; 32]);
+        assert!(latest_slot_hash(&data[..20]).is_err());
+    }
+
+    #[test]
+    fn test_latest_slot_hash_ignores_stale_bytes_without_entries() {
+        // Entry count 0: whatever follows the header is not a live slot hash
+        let mut data = vec![0u8; 16];
+        data.extend_from_slice(&[9u8; 32]);
+        assert!(latest_slot_hash(&data).is_err());
+        assert!(latest_slot_hash(&[]).is_err());
+    }
+}
@@ -5,11 +5,17 @@ use anchor_lang::prelude::*;
 /// PDA seeds: [b"config"]
 /// Singleton account initialized once at protocol deployment
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct GlobalConfig {
     /// Protocol admin who can update config
     pub authority: Pubkey,
 
+    /// Proposed next authority (None = no change pending)
+    pub pending_authority: Option<Pubkey>,
+
+    /// When the pending authority may accept (propose time + AUTHORITY_CHANGE_DELAY_SECONDS)
+    pub authority_change_effective_at: i64,
+
     /// Operator wallet that can call graduate()
     pub operator_wallet: Pubkey,
 
@@ -19,8 +25,8 @@ pub struct GlobalConfig {
     /// Wallet receiving vault protocol share (10% of yield)
     pub vault_protocol_wallet: Pubkey,
 
-    /// Minimum seed in lamports (calculated from MIN_SEED_USD at current SOL price)
-    /// Updated periodically via oracle
+    /// Minimum seed in lamports (calculated from MIN_SEED_USD_CENTS at current SOL price)
+    /// Kept in sync with the oracle price by refresh_min_seed
     pub min_seed_lamports: u64,
 
     /// Current SOL price in USD (for runtime conversions)
@@ -30,29 +36,277 @@ pub struct GlobalConfig {
     /// Last price update timestamp
     pub price_last_updated: i64,
 
+    /// Confidence interval of sol_price_usd as bps of the price (Pyth conf / price)
+    /// 0 = not reported (seed bounds refuse it)
+    pub price_confidence_bps: u64,
+
+    /// Reject buys while a set SOL price is stale (unset price 0 is exempt)
+    pub enforce_fresh_price: bool,
+
     /// Is protocol paused? (emergency stop)
     pub paused: bool,
 
+    /// Why the protocol is paused (None while unpaused)
+    pub pause_reason: Option<PauseReason>,
+
     /// Total launches created (for stats)
     pub total_launches: u64,
 
+    /// Times force_graduate has run - monitoring alerts on growth
+    pub force_graduations_count: u64,
+
+    /// Fee on sells in bps, routed to protocol_fee_wallet (default 0, max 300)
+    pub sell_fee_bps: u64,
+
+    /// Total buy fee in bps on verified creators' launches (0 = no discount)
+    /// The discount comes out of the protocol's cut; the creator share is unchanged
+    pub verified_total_fee_bps: u64,
+
+    /// Raydium AMM configs (fee tiers) graduation pools may use
+    /// Empty = graduation blocked until the authority vets a config
+    #[max_len(8)]
+    pub allowed_amm_configs: Vec<Pubkey>,
+
+    /// Holder distributors (e.g. Merkle-drop authorities) launches may route tokens to
+    /// Empty = every launch uses claim_tokens
+    #[max_len(4)]
+    pub allowed_distributors: Vec<Pubkey>,
+
+    /// Minimum holders required by `graduate` (default GRADUATION_MIN_HOLDERS)
+    pub min_holders_for_graduation: u64,
+
+    /// Maturity-scaled buy cap as bps of the launch's current pool (0 = disabled)
+    pub max_buy_pool_bps: u64,
+
+    /// Cap on the creator's share of total shares at graduation, in bps (0 = disabled)
+    pub max_creator_concentration_bps: u64,
+
+    /// M-of-N operator set for graduate; empty = operator_wallet alone
+    #[max_len(5)]
+    pub graduation_operators: Vec<Pubkey>,
+
+    /// Operator signatures graduate requires from graduation_operators
+    pub graduation_threshold: u8,
+
+    /// Keeper treasury for janitor rent (push_refund, close_launch)
+    /// None = permissionless, rent goes to the caller
+    /// Some = only the operator may call, rent goes to this wallet
+    pub janitor_rewards_wallet: Option<Pubkey>,
+
+    /// Per-asset USD prices, keyed by asset mint
+    /// The SOL feed (SOL_PRICE_FEED_ASSET) is mirrored into sol_price_usd
+    #[max_len(4)]
+    pub price_feeds: Vec<PriceFeed>,
+
     /// Bump for PDA derivation
     pub bump: u8,
 }
 
+/// Structured reason for a pause state change (shown to users and monitoring)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum PauseReason {
+    /// Planned upgrade or migration
+    Maintenance,
+    /// Price feed unavailable or unreliable
+    OracleFailure,
+    /// Responding to an exploit or suspected exploit
+    ExploitResponse,
+    /// Incident resolved (typically used when unpausing)
+    Resolved,
+    /// Anything else - see the authority's announcement
+    Other,
+}
+
+/// Fee split of one buy (see GlobalConfig::split_buy_fee)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuyFees {
+    pub total_fee: u64,
+    pub creator_fee: u64,
+    pub protocol_fee: u64,
+    /// SOL that buys shares on the curve
+    pub net_sol: u64,
+}
+
+/// Cached USD price for one asset
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub struct PriceFeed {
+    /// Asset mint this price is for
+    pub asset: Pubkey,
+
+    /// Price in whole USD
+    pub price_usd: u64,
+
+    /// Oracle confidence interval as bps of the price (0 = not reported)
+    pub confidence_bps: u64,
+
+    /// Last update timestamp
+    pub last_updated: i64,
+}
+
+impl PriceFeed {
+    /// Throttle: reject updates within PRICE_CACHE_UPDATE_INTERVAL_SECONDS of the
+    /// last one, unless the price moved by at least PRICE_DEVIATION_OVERRIDE_BPS
+    ///
+    /// # Errors
+    /// * `PriceUpdateNotNewer` - If `now` isn't strictly after the stored price,
+    ///   so an older verified Pyth update can't be replayed over a newer price
+    pub fn accepts_update(&self, new_price_usd: u64, now: i64) -> Result<bool> {
+        use crate::constants::{
+            BPS_DENOMINATOR, PRICE_CACHE_UPDATE_INTERVAL_SECONDS, PRICE_DEVIATION_OVERRIDE_BPS,
+        };
+
+        require!(
+            now > self.last_updated,
+            crate::errors::AstraError::PriceUpdateNotNewer
+        );
+        let age = crate::time::safe_elapsed(now, self.last_updated)?;
+        if age >= PRICE_CACHE_UPDATE_INTERVAL_SECONDS {
+            return Ok(true);
+        }
+
+        // Genuine volatility: |new - old| / old >= threshold
+        let deviation = new_price_usd.abs_diff(self.price_usd) as u128;
+        Ok(deviation * BPS_DENOMINATOR as u128
+            >= self.price_usd as u128 * PRICE_DEVIATION_OVERRIDE_BPS as u128)
+    }
+}
+
+/// Every deploy-time parameter, validated and applied in one transaction
+/// None / empty fields take the same defaults the individual setters start from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct InitConfigArgs {
+    pub operator_wallet: Pubkey,
+    pub protocol_fee_wallet: Pubkey,
+    pub vault_protocol_wallet: Pubkey,
+    pub min_seed_lamports: u64,
+    /// Sell fee in bps (default SELL_FEE_BPS, max MAX_SELL_FEE_BPS)
+    pub sell_fee_bps: Option<u64>,
+    /// Total buy fee on verified creators' launches (default 0 = no discount)
+    pub verified_total_fee_bps: Option<u64>,
+    /// Graduation holder gate (default GRADUATION_MIN_HOLDERS, min 1)
+    pub min_holders_for_graduation: Option<u64>,
+    /// Maturity-scaled buy cap in bps (default DEFAULT_MAX_BUY_POOL_BPS, 0 = disabled)
+    pub max_buy_pool_bps: Option<u64>,
+    /// Creator concentration cap in bps (default DEFAULT_MAX_CREATOR_CONCENTRATION_BPS, 0 = disabled)
+    pub max_creator_concentration_bps: Option<u64>,
+    /// Keeper treasury for janitor rent (None = permissionless)
+    pub janitor_rewards_wallet: Option<Pubkey>,
+    /// Vetted Raydium AMM configs (empty = graduation blocked until set)
+    pub allowed_amm_configs: Vec<Pubkey>,
+    /// Reject buys while a set SOL price is stale (default false)
+    pub enforce_fresh_price: bool,
+}
+
 impl GlobalConfig {
-    /// Calculate lamports from USD amount
+    /// Validate initialize's args and write the whole config atomically
+    ///
+    /// Rejects the same values the individual setters would, plus zeroed
+    /// wallets and a zero seed floor, so a deployment can't come up half-set.
+    pub fn apply_init_args(
+        &mut self,
+        authority: Pubkey,
+        args: &InitConfigArgs,
+        bump: u8,
+    ) -> Result<()> {
+        use crate::constants::{
+            BPS_DENOMINATOR, DEFAULT_MAX_BUY_POOL_BPS, DEFAULT_MAX_CREATOR_CONCENTRATION_BPS,
+            GRADUATION_MIN_HOLDERS, MAX_AMM_CONFIGS, MAX_SELL_FEE_BPS, SELL_FEE_BPS,
+        };
+        use crate::errors::AstraError;
+
+        let sell_fee_bps = args.sell_fee_bps.unwrap_or(SELL_FEE_BPS);
+        let verified_total_fee_bps = args.verified_total_fee_bps.unwrap_or(0);
+        let min_holders = args
+            .min_holders_for_graduation
+            .unwrap_or(GRADUATION_MIN_HOLDERS);
+        let max_buy_pool_bps = args.max_buy_pool_bps.unwrap_or(DEFAULT_MAX_BUY_POOL_BPS);
+        let max_creator_concentration_bps = args
+            .max_creator_concentration_bps
+            .unwrap_or(DEFAULT_MAX_CREATOR_CONCENTRATION_BPS);
+
+        require!(
+            [
+                args.operator_wallet,
+                args.protocol_fee_wallet,
+                args.vault_protocol_wallet,
+            ]
+            .iter()
+            .all(|wallet| *wallet != Pubkey::default())
+                && args.janitor_rewards_wallet != Some(Pubkey::default()),
+            AstraError::InvalidConfigWallet
+        );
+        require!(args.min_seed_lamports > 0, AstraError::InvalidCalculation);
+        require!(sell_fee_bps <= MAX_SELL_FEE_BPS, AstraError::SellFeeTooHigh);
+        Self::validate_verified_total_fee_bps(verified_total_fee_bps)?;
+        require!(min_holders > 0, AstraError::InvalidMinHolders);
+        require!(
+            max_buy_pool_bps <= BPS_DENOMINATOR,
+            AstraError::InvalidMaxBuyPoolBps
+        );
+        require!(
+            max_creator_concentration_bps <= BPS_DENOMINATOR,
+            AstraError::InvalidConcentrationCap
+        );
+        require!(
+            args.allowed_amm_configs.len() <= MAX_AMM_CONFIGS,
+            AstraError::TooManyAmmConfigs
+        );
+
+        *self = GlobalConfig {
+            authority,
+            pending_authority: None,
+            authority_change_effective_at: 0,
+            operator_wallet: args.operator_wallet,
+            protocol_fee_wallet: args.protocol_fee_wallet,
+            vault_protocol_wallet: args.vault_protocol_wallet,
+            min_seed_lamports: args.min_seed_lamports,
+            // Price is set later by update_price_pyth (or the update_price fallback)
+            sol_price_usd: 0,
+            price_last_updated: 0,
+            price_confidence_bps: 0,
+            enforce_fresh_price: args.enforce_fresh_price,
+            paused: false,
+            pause_reason: None,
+            total_launches: 0,
+            force_graduations_count: 0,
+            sell_fee_bps,
+            verified_total_fee_bps,
+            allowed_amm_configs: args.allowed_amm_configs.clone(),
+            // Distributors are vetted one by one via update_distributors
+            allowed_distributors: Vec::new(),
+            min_holders_for_graduation: min_holders,
+            max_buy_pool_bps,
+            max_creator_concentration_bps,
+            graduation_operators: Vec::new(),
+            graduation_threshold: 1,
+            janitor_rewards_wallet: args.janitor_rewards_wallet,
+            price_feeds: Vec::new(),
+            bump,
+        };
+        Ok(())
+    }
+
+    /// Calculate lamports from a whole-dollar USD amount
     pub fn usd_to_lamports(&self, usd_amount: u64) -> Option<u64> {
+        let usd_cents = usd_amount.checked_mul(crate::constants::USD_CENTS_PER_DOLLAR)?;
+        self.usd_cents_to_lamports(usd_cents)
+    }
+
+    /// Calculate lamports from a fixed-point USD amount in cents
+    pub fn usd_cents_to_lamports(&self, usd_cents: u64) -> Option<u64> {
         if self.sol_price_usd == 0 {
             return None;
         }
 
-        // lamports = (USD / price) * 1B (lamports per SOL)
-        let lamports = (usd_amount as u128)
-            .checked_mul(1_000_000_000)?
-            .checked_div(self.sol_price_usd as u128)?;
+        // lamports = (cents / (price * 100)) * 1B (lamports per SOL)
+        let lamports = (usd_cents as u128)
+            .checked_mul(crate::constants::LAMPORTS_PER_SOL as u128)?
+            .checked_div(
+                (self.sol_price_usd as u128)
+                    .checked_mul(crate::constants::USD_CENTS_PER_DOLLAR as u128)?,
+            )?;
 
-        Some(lamports as u64)
+        lamports.try_into().ok()
     }
 
     /// Calculate USD from lamports
@@ -60,13 +314,1513 @@ impl GlobalConfig {
         // USD = (lamports * price) / 1B
         let usd = (lamports as u128)
             .checked_mul(self.sol_price_usd as u128)?
-            .checked_div(1_000_000_000)?;
+            .checked_div(crate::constants::LAMPORTS_PER_SOL as u128)?;
 
         Some(usd as u64)
     }
 
+    /// Current fee schedule (constants + configured sell fee)
+    pub fn fee_schedule(&self) -> crate::events::FeeSchedule {
+        crate::events::FeeSchedule {
+            total_fee_bps: crate::constants::TOTAL_FEE_BPS,
+            creator_unverified_bps: crate::constants::CREATOR_FEE_UNVERIFIED_BPS,
+            creator_verified_bps: crate::constants::CREATOR_FEE_VERIFIED_BPS,
+            sell_fee_bps: self.sell_fee_bps,
+            verified_total_fee_bps: self.verified_total_fee_bps,
+        }
+    }
+
+    /// Point a config wallet at a new address; returns the one it replaced
+    /// Rejects the default pubkey, which would send funds nowhere
+    pub fn rotate_wallet(wallet: &mut Pubkey, new_wallet: Pubkey) -> Result<Pubkey> {
+        require!(
+            new_wallet != Pubkey::default(),
+            crate::errors::AstraError::InvalidConfigWallet
+        );
+        Ok(std::mem::replace(wallet, new_wallet))
+    }
+
+    /// Validate a verified-creator total fee
+    /// 0 (no discount) or CREATOR_FEE_VERIFIED_BPS..=TOTAL_FEE_BPS, so the
+    /// protocol's remainder can shrink to zero but never go negative
+    pub fn validate_verified_total_fee_bps(verified_total_fee_bps: u64) -> Result<()> {
+        use crate::constants::{CREATOR_FEE_VERIFIED_BPS, TOTAL_FEE_BPS};
+
+        require!(
+            verified_total_fee_bps == 0
+                || (CREATOR_FEE_VERIFIED_BPS..=TOTAL_FEE_BPS).contains(&verified_total_fee_bps),
+            crate::errors::AstraError::InvalidVerifiedFee
+        );
+        Ok(())
+    }
+
+    /// Buy fee rates (total_bps, creator_bps) for a launch by this creator
+    /// Verified creators earn CREATOR_FEE_VERIFIED_BPS and, when configured,
+    /// their buyers pay verified_total_fee_bps instead of TOTAL_FEE_BPS
+    pub fn buy_fee_bps(&self, creator_stats: &super::CreatorStats) -> (u64, u64) {
+        let total_fee_bps = if creator_stats.is_verified() && self.verified_total_fee_bps > 0 {
+            self.verified_total_fee_bps
+        } else {
+            crate::constants::TOTAL_FEE_BPS
+        };
+        (total_fee_bps, creator_stats.get_creator_fee_bps())
+    }
+
+    /// Split a buy into fees and the net SOL that goes into the curve
+    /// Each fee rounds down on its own, as buy has always charged them
+    pub fn split_buy_fee(
+        sol_amount: u64,
+        total_fee_bps: u64,
+        creator_fee_bps: u64,
+    ) -> Result<BuyFees> {
+        use crate::errors::AstraError;
+
+        let protocol_fee_bps = total_fee_bps
+            .checked_sub(creator_fee_bps)
+            .ok_or(AstraError::MathOverflow)?;
+        let fee = |bps: u64| -> Result<u64> {
+            sol_amount
+                .checked_mul(bps)
+                .and_then(|v| v.checked_div(crate::constants::BPS_DENOMINATOR))
+                .ok_or_else(|| AstraError::MathOverflow.into())
+        };
+        let total_fee = fee(total_fee_bps)?;
+
+        Ok(BuyFees {
+            total_fee,
+            creator_fee: fee(creator_fee_bps)?,
+            protocol_fee: fee(protocol_fee_bps)?,
+            net_sol: sol_amount
+                .checked_sub(total_fee)
+                .ok_or(AstraError::MathOverflow)?,
+        })
+    }
+
+    /// Fee split of a buy that took a fee-free slot
+    /// Fees are waived on the first FEE_FREE_BUY_CAP_LAMPORTS only; the rest of
+    /// the buy pays the normal rates
+    pub fn split_fee_free_buy(
+        sol_amount: u64,
+        total_fee_bps: u64,
+        creator_fee_bps: u64,
+    ) -> Result<BuyFees> {
+        let charged = sol_amount.saturating_sub(crate::constants::FEE_FREE_BUY_CAP_LAMPORTS);
+        let fees = Self::split_buy_fee(charged, total_fee_bps, creator_fee_bps)?;
+
+        Ok(BuyFees {
+            net_sol: sol_amount
+                .checked_sub(fees.total_fee)
+                .ok_or(crate::errors::AstraError::MathOverflow)?,
+            ..fees
+        })
+    }
+
+    /// Calculate the sell fee on a refund
+    pub fn sell_fee(&self, refund: u64) -> Option<u64> {
+        let fee = (refund as u128)
+            .checked_mul(self.sell_fee_bps as u128)?
+            .checked_div(crate::constants::BPS_DENOMINATOR as u128)?;
+
+        Some(fee as u64)
+    }
+
+    /// Split a sell refund into (sell_fee, net_refund), enforcing the seller's floor
+    ///
+    /// # Errors
+    /// * `SlippageExceeded` - If the net refund is below `min_sol_out`
+    pub fn sell_proceeds(&self, refund: u64, min_sol_out: u64) -> Result<(u64, u64)> {
+        use crate::errors::AstraError;
+
+        let sell_fee = self.sell_fee(refund).ok_or(AstraError::MathOverflow)?;
+        let net_refund = refund
+            .checked_sub(sell_fee)
+            .ok_or(AstraError::MathOverflow)?;
+        require!(net_refund >= min_sol_out, AstraError::SlippageExceeded);
+        Ok((sell_fee, net_refund))
+    }
+
+    /// Minimum buy in lamports (MIN_BUY_USD_CENTS at current SOL price)
+    /// Returns None if price is not available (0)
+    pub fn min_buy_lamports(&self) -> Option<u64> {
+        self.usd_cents_to_lamports(crate::constants::MIN_BUY_USD_CENTS)
+    }
+
+    /// Check if an AMM config is allowlisted for graduation
+    pub fn is_amm_config_allowed(&self, amm_config: &Pubkey) -> bool {
+        self.allowed_amm_configs.contains(amm_config)
+    }
+
+    /// Check a launch's requested holder distributor (None = claim_tokens, always allowed)
+    /// The distributor receives the whole holder allocation, so only vetted ones pass
+    pub fn check_distributor(&self, distributor: Option<Pubkey>) -> Result<()> {
+        require!(
+            distributor.is_none_or(|d| self.allowed_distributors.contains(&d)),
+            crate::errors::AstraError::InvalidDistributor
+        );
+        Ok(())
+    }
+
+    /// Maximum buy (lamports) allowed in one transaction for a pool of `total_sol`
+    /// min(MAX_BUY_LAMPORTS, max(MIN_BUY_CAP_LAMPORTS, total_sol * max_buy_pool_bps / 10000))
+    pub fn max_buy_lamports(&self, total_sol: u64) -> u64 {
+        use crate::constants::{MAX_BUY_LAMPORTS, MIN_BUY_CAP_LAMPORTS};
+
+        if self.max_buy_pool_bps == 0 {
+            return MAX_BUY_LAMPORTS;
+        }
+
+        let scaled = (total_sol as u128)
+            .saturating_mul(self.max_buy_pool_bps as u128)
+            / crate::constants::BPS_DENOMINATOR as u128;
+
+        scaled
+            .max(MIN_BUY_CAP_LAMPORTS as u128)
+            .min(MAX_BUY_LAMPORTS as u128) as u64
+    }
+
+    /// Check `operator` may sign graduate
+    /// Single-operator mode (no set configured) accepts only operator_wallet
+    pub fn is_graduation_operator(&self, operator: &Pubkey) -> bool {
+        if self.graduation_operators.is_empty() {
+            *operator == self.operator_wallet
+        } else {
+            self.graduation_operators.contains(operator)
+        }
+    }
+
+    /// Check `signers` include enough distinct graduation operators
+    /// A signer listed twice still counts once
+    pub fn check_graduation_signers(&self, signers: &[Pubkey]) -> Result<()> {
+        use crate::errors::AstraError;
+
+        if self.graduation_operators.is_empty() {
+            require!(
+                signers.contains(&self.operator_wallet),
+                AstraError::Unauthorized
+            );
+            return Ok(());
+        }
+
+        let approvals = self
+            .graduation_operators
+            .iter()
+            .filter(|operator| signers.contains(operator))
+            .count();
+        require!(
+            approvals >= self.graduation_threshold.max(1) as usize,
+            AstraError::InsufficientOperatorSignatures
+        );
+        Ok(())
+    }
+
+    /// Replace the graduation operator set and threshold
+    /// An empty set (threshold 1) restores single-operator mode
+    pub fn set_graduation_operators(
+        &mut self,
+        operators: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        use crate::constants::MAX_GRADUATION_OPERATORS;
+        use crate::errors::AstraError;
+
+        let threshold_usize = threshold as usize;
+        require!(
+            operators.len() <= MAX_GRADUATION_OPERATORS,
+            AstraError::InvalidOperatorSet
+        );
+        require!(
+            threshold_usize >= 1 && threshold_usize <= operators.len().max(1),
+            AstraError::InvalidOperatorSet
+        );
+        for (i, operator) in operators.iter().enumerate() {
+            require!(
+                *operator != Pubkey::default() && !operators[..i].contains(operator),
+                AstraError::InvalidOperatorSet
+            );
+        }
+
+        self.graduation_operators = operators;
+        self.graduation_threshold = threshold;
+        Ok(())
+    }
+
+    /// Convert a Pyth price (price * 10^exponent) to (whole USD, confidence bps)
+    /// Rounds the price down and the confidence up, so a nonzero Pyth interval
+    /// never reads as 0 (unknown); a non-positive price is rejected (InvalidPrice)
+    pub fn usd_from_pyth(price: i64, conf: u64, exponent: i32) -> Result<(u64, u64)> {
+        use crate::errors::AstraError;
+
+        require!(price > 0, AstraError::InvalidPrice);
+        let scale = 10u128
+            .checked_pow(exponent.unsigned_abs())
+            .ok_or(AstraError::MathOverflow)?;
+        let price_usd = if exponent < 0 {
+            price as u128 / scale
+        } else {
+            (price as u128)
+                .checked_mul(scale)
+                .ok_or(AstraError::MathOverflow)?
+        };
+        let confidence_bps = (conf as u128)
+            .checked_mul(crate::constants::BPS_DENOMINATOR as u128)
+            .ok_or(AstraError::MathOverflow)?
+            .div_ceil(price as u128);
+
+        Ok((
+            u64::try_from(price_usd).map_err(|_| AstraError::MathOverflow)?,
+            u64::try_from(confidence_bps).map_err(|_| AstraError::MathOverflow)?,
+        ))
+    }
+
+    /// Look up the cached price feed for an asset
+    pub fn price_feed(&self, asset: &Pubkey) -> Option<&PriceFeed> {
+        self.price_feeds.iter().find(|feed| feed.asset == *asset)
+    }
+
+    /// Insert or update an asset's price feed
+    /// The SOL feed also updates sol_price_usd / price_last_updated
+    pub fn set_price_feed(
+        &mut self,
+        asset: Pubkey,
+        price_usd: u64,
+        confidence_bps: u64,
+        now: i64,
+    ) -> Result<()> {
+        use crate::constants::{MAX_PRICE_FEEDS, SOL_PRICE_FEED_ASSET};
+        use crate::errors::AstraError;
+
+        require!(price_usd > 0, AstraError::InvalidPrice);
+
+        match self.price_feeds.iter_mut().find(|feed| feed.asset == asset) {
+            Some(feed) => {
+                require!(
+                    feed.accepts_update(price_usd, now)?,
+                    AstraError::PriceUpdateTooFrequent
+                );
+                feed.price_usd = price_usd;
+                feed.confidence_bps = confidence_bps;
+                feed.last_updated = now;
+            }
+            None => {
+                require!(
+                    self.price_feeds.len() < MAX_PRICE_FEEDS,
+                    AstraError::TooManyPriceFeeds
+                );
+                self.price_feeds.push(PriceFeed {
+                    asset,
+                    price_usd,
+                    confidence_bps,
+                    last_updated: now,
+                });
+            }
+        }
+
+        if asset == SOL_PRICE_FEED_ASSET {
+            self.sol_price_usd = price_usd;
+            self.price_confidence_bps = confidence_bps;
+            self.price_last_updated = now;
+        }
+
+        Ok(())
+    }
+
+    /// Seed bounds (MIN/MAX_SEED_USD_CENTS) in lamports at the cached SOL price
+    ///
+    /// Rejected while the price's confidence interval is wider than
+    /// MAX_SEED_PRICE_CONFIDENCE_BPS - a volatile price would misplace both
+    /// bounds and let a seed land outside the intended USD range. A confidence
+    /// of 0 means none was reported, not a perfect price, and is rejected too.
+    pub fn seed_bounds_lamports(&self) -> Result<(u64, u64)> {
+        use crate::constants::{
+            MAX_SEED_PRICE_CONFIDENCE_BPS, MAX_SEED_USD_CENTS, MIN_SEED_USD_CENTS,
+        };
+        use crate::errors::AstraError;
+
+        require!(
+            self.price_confidence_bps > 0,
+            AstraError::PriceConfidenceUnknown
+        );
+        require!(
+            self.price_confidence_bps <= MAX_SEED_PRICE_CONFIDENCE_BPS,
+            AstraError::PriceConfidenceTooWide
+        );
+        let min_lamports = self
+            .usd_cents_to_lamports(MIN_SEED_USD_CENTS)
+            .ok_or(AstraError::PriceOracleUnavailable)?;
+        let max_lamports = self
+            .usd_cents_to_lamports(MAX_SEED_USD_CENTS)
+            .ok_or(AstraError::PriceOracleUnavailable)?;
+        Ok((min_lamports, max_lamports))
+    }
+
+    /// Check if `caller` may run janitor instructions
+    pub fn is_authorized_janitor(&self, caller: &Pubkey) -> bool {
+        self.janitor_rewards_wallet.is_none() || *caller == self.operator_wallet
+    }
+
+    /// Account that receives reclaimed rent from janitor instructions
+    pub fn janitor_rent_recipient(&self, caller: &Pubkey) -> Pubkey {
+        self.janitor_rewards_wallet.unwrap_or(*caller)
+    }
+
+    /// Reject while the emergency stop is on (see set_paused for the gated set)
+    pub fn check_not_paused(&self) -> Result<()> {
+        require!(!self.paused, crate::errors::AstraError::ProtocolPaused);
+        Ok(())
+    }
+
+    /// Set the emergency stop and record why
+    /// Returns the PauseToggled event for the handler to emit
+    pub fn set_paused(
+        &mut self,
+        paused: bool,
+        reason_code: PauseReason,
+        authority: Pubkey,
+        now: i64,
+    ) -> crate::events::PauseToggled {
+        self.paused = paused;
+        self.pause_reason = paused.then_some(reason_code);
+
+        crate::events::PauseToggled {
+            paused,
+            authority,
+            reason_code,
+            timestamp: now,
+        }
+    }
+
+    /// Count an emergency graduation
+    /// Returns the running total for the ForceGraduated event
+    pub fn record_force_graduation(&mut self) -> Result<u64> {
+        self.force_graduations_count = self
+            .force_graduations_count
+            .checked_add(1)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        Ok(self.force_graduations_count)
+    }
+
+    /// Start a timelocked authority change; replaces any pending proposal
+    /// Returns when the new authority may accept
+    pub fn propose_authority(&mut self, new_authority: Pubkey, now: i64) -> Result<i64> {
+        use crate::errors::AstraError;
+
+        require!(
+            new_authority != Pubkey::default() && new_authority != self.authority,
+            AstraError::InvalidAuthority
+        );
+        let effective_at = crate::time::safe_deadline(
+            now,
+            crate::constants::AUTHORITY_CHANGE_DELAY_SECONDS,
+        )?;
+
+        self.pending_authority = Some(new_authority);
+        self.authority_change_effective_at = effective_at;
+        Ok(effective_at)
+    }
+
+    /// Finalize the pending change once the delay has passed
+    /// Must be signed by the proposed authority; returns the previous one
+    pub fn accept_authority(&mut self, new_authority: &Pubkey, now: i64) -> Result<Pubkey> {
+        use crate::errors::AstraError;
+
+        require!(
+            self.pending_authority == Some(*new_authority),
+            AstraError::Unauthorized
+        );
+        require!(
+            now >= self.authority_change_effective_at,
+            AstraError::AuthorityChangeNotReady
+        );
+
+        let previous = self.authority;
+        self.authority = *new_authority;
+        self.pending_authority = None;
+        self.authority_change_effective_at = 0;
+        Ok(previous)
+    }
+
+    /// Abort the pending change; returns the authority that was proposed
+    pub fn cancel_authority_change(&mut self) -> Result<Pubkey> {
+        let cancelled = self
+            .pending_authority
+            .take()
+            .ok_or(crate::errors::AstraError::NoPendingAuthorityChange)?;
+        self.authority_change_effective_at = 0;
+        Ok(cancelled)
+    }
+
     /// Check if price is stale (>5 minutes old)
-    pub fn is_price_stale(&self, current_time: i64) -> bool {
-        current_time - self.price_last_updated > 300 // 5 minutes
+    pub fn is_price_stale(&self, current_time: i64) -> Result<bool> {
+        let age = crate::time::safe_elapsed(current_time, self.price_last_updated)?;
+        Ok(age > crate::constants::MAX_PRICE_STALENESS_SECONDS) // 5 minutes
+    }
+
+    /// Re-derive min_seed_lamports from MIN_SEED_USD_CENTS at the cached SOL price
+    /// Rejects a stale or missing price (PriceOracleUnavailable)
+    pub fn refresh_min_seed(&mut self, now: i64) -> Result<crate::events::MinSeedRefreshed> {
+        let previous = self.min_seed_lamports;
+        self.min_seed_lamports =
+            self.fresh_usd_cents_to_lamports(crate::constants::MIN_SEED_USD_CENTS, now)?;
+
+        Ok(crate::events::MinSeedRefreshed {
+            previous_min_seed_lamports: previous,
+            min_seed_lamports: self.min_seed_lamports,
+            sol_price_usd: self.sol_price_usd,
+            timestamp: now,
+        })
+    }
+
+    /// Check a buy may use the cached SOL price (market cap, graduation signal)
+    /// Only when enforce_fresh_price is on and a price has been set - an unset
+    /// price (devnet) keeps buys open
+    pub fn check_price_fresh_for_buy(&self, current_time: i64) -> Result<()> {
+        if self.enforce_fresh_price && self.sol_price_usd > 0 {
+            require!(
+                !self.is_price_stale(current_time)?,
+                crate::errors::AstraError::PriceOracleUnavailable
+            );
+        }
+        Ok(())
+    }
+
+    /// Convert USD cents to lamports at the cached price, rejecting a stale price
+    /// Used where the USD amount is the user's intent (buy_usd), not just a bound
+    pub fn fresh_usd_cents_to_lamports(&self, usd_cents: u64, current_time: i64) -> Result<u64> {
+        require!(
+            !self.is_price_stale(current_time)?,
+            crate::errors::AstraError::PriceOracleUnavailable
+        );
+        self.usd_cents_to_lamports(usd_cents)
+            .ok_or_else(|| crate::errors::AstraError::PriceOracleUnavailable.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{
+        CREATOR_FEE_UNVERIFIED_BPS, CREATOR_FEE_VERIFIED_BPS, DEFAULT_MAX_BUY_POOL_BPS,
+        MAX_BUY_LAMPORTS, MAX_PRICE_FEEDS, MIN_BUY_CAP_LAMPORTS, SOL_PRICE_FEED_ASSET,
+        TOTAL_FEE_BPS,
+    };
+
+    const SOL: u64 = crate::constants::LAMPORTS_PER_SOL;
+
+    fn init_args() -> InitConfigArgs {
+        InitConfigArgs {
+            operator_wallet: Pubkey::new_unique(),
+            protocol_fee_wallet: Pubkey::new_unique(),
+            vault_protocol_wallet: Pubkey::new_unique(),
+            min_seed_lamports: SOL / 4,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_init_applies_defaults_for_omitted_fields() {
+        use crate::constants::{
+            DEFAULT_MAX_CREATOR_CONCENTRATION_BPS, GRADUATION_MIN_HOLDERS, SELL_FEE_BPS,
+        };
+
+        let authority = Pubkey::new_unique();
+        let args = init_args();
+        let mut config = GlobalConfig::default();
+        config.apply_init_args(authority, &args, 254).unwrap();
+
+        assert_eq!(config.authority, authority);
+        assert_eq!(config.operator_wallet, args.operator_wallet);
+        assert_eq!(config.min_seed_lamports, SOL / 4);
+        assert_eq!(config.sell_fee_bps, SELL_FEE_BPS);
+        assert_eq!(config.min_holders_for_graduation, GRADUATION_MIN_HOLDERS);
+        assert_eq!(config.max_buy_pool_bps, DEFAULT_MAX_BUY_POOL_BPS);
+        assert_eq!(
+            config.max_creator_concentration_bps,
+            DEFAULT_MAX_CREATOR_CONCENTRATION_BPS
+        );
+        assert!(config.janitor_rewards_wallet.is_none());
+        assert!(config.allowed_amm_configs.is_empty());
+        assert!(!config.paused);
+        assert_eq!(config.bump, 254);
+    }
+
+    #[test]
+    fn test_init_fully_parameterized() {
+        let janitor = Pubkey::new_unique();
+        let amm_config = Pubkey::new_unique();
+        let args = InitConfigArgs {
+            sell_fee_bps: Some(150),
+            min_holders_for_graduation: Some(250),
+            max_buy_pool_bps: Some(0),
+            max_creator_concentration_bps: Some(1_500),
+            janitor_rewards_wallet: Some(janitor),
+            allowed_amm_configs: vec![amm_config],
+            enforce_fresh_price: true,
+            ..init_args()
+        };
+        let mut config = GlobalConfig::default();
+        config.apply_init_args(Pubkey::new_unique(), &args, 1).unwrap();
+
+        assert_eq!(config.sell_fee_bps, 150);
+        assert_eq!(config.min_holders_for_graduation, 250);
+        assert_eq!(config.max_buy_pool_bps, 0);
+        assert_eq!(config.max_creator_concentration_bps, 1_500);
+        assert_eq!(config.janitor_rewards_wallet, Some(janitor));
+        assert_eq!(config.allowed_amm_configs, vec![amm_config]);
+        assert!(config.enforce_fresh_price);
+    }
+
+    #[test]
+    fn test_init_rejects_invalid_parameters() {
+        use crate::constants::{MAX_AMM_CONFIGS, MAX_SELL_FEE_BPS};
+        use crate::errors::AstraError;
+
+        let cases = [
+            (
+                InitConfigArgs {
+                    operator_wallet: Pubkey::default(),
+                    ..init_args()
+                },
+                AstraError::InvalidConfigWallet,
+            ),
+            (
+                InitConfigArgs {
+                    janitor_rewards_wallet: Some(Pubkey::default()),
+                    ..init_args()
+                },
+                AstraError::InvalidConfigWallet,
+            ),
+            (
+                InitConfigArgs {
+                    min_seed_lamports: 0,
+                    ..init_args()
+                },
+                AstraError::InvalidCalculation,
+            ),
+            (
+                InitConfigArgs {
+                    sell_fee_bps: Some(MAX_SELL_FEE_BPS + 1),
+                    ..init_args()
+                },
+                AstraError::SellFeeTooHigh,
+            ),
+            (
+                InitConfigArgs {
+                    verified_total_fee_bps: Some(TOTAL_FEE_BPS + 1),
+                    ..init_args()
+                },
+                AstraError::InvalidVerifiedFee,
+            ),
+            (
+                InitConfigArgs {
+                    min_holders_for_graduation: Some(0),
+                    ..init_args()
+                },
+                AstraError::InvalidMinHolders,
+            ),
+            (
+                InitConfigArgs {
+                    max_buy_pool_bps: Some(10_001),
+                    ..init_args()
+                },
+                AstraError::InvalidMaxBuyPoolBps,
+            ),
+            (
+                InitConfigArgs {
+                    max_creator_concentration_bps: Some(10_001),
+                    ..init_args()
+                },
+                AstraError::InvalidConcentrationCap,
+            ),
+            (
+                InitConfigArgs {
+                    allowed_amm_configs: vec![Pubkey::new_unique(); MAX_AMM_CONFIGS + 1],
+                    ..init_args()
+                },
+                AstraError::TooManyAmmConfigs,
+            ),
+        ];
+
+        for (args, expected) in cases {
+            let mut config = GlobalConfig::default();
+            assert_eq!(
+                config.apply_init_args(Pubkey::new_unique(), &args, 1).unwrap_err(),
+                expected.into()
+            );
+            // Nothing is written on failure
+            assert_eq!(config.authority, Pubkey::default());
+        }
+    }
+
+    fn config() -> GlobalConfig {
+        GlobalConfig {
+            max_buy_pool_bps: DEFAULT_MAX_BUY_POOL_BPS,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_fifty_dollar_buy_at_different_sol_prices() {
+        let now = 1_700_000_000;
+        let fifty_dollars = 5_000; // cents
+
+        for (sol_price_usd, expected_lamports) in [
+            (100, SOL / 2),        // $50 = 0.5 SOL
+            (200, SOL / 4),        // $50 = 0.25 SOL
+            (250, SOL / 5),        // $50 = 0.2 SOL
+            (300, 166_666_666),    // $50 = 0.1666.. SOL (rounded down)
+        ] {
+            let config = GlobalConfig {
+                sol_price_usd,
+                price_last_updated: now,
+                ..Default::default()
+            };
+            assert_eq!(
+                config.fresh_usd_cents_to_lamports(fifty_dollars, now).unwrap(),
+                expected_lamports
+            );
+        }
+    }
+
+    #[test]
+    fn test_usd_buy_rejects_stale_or_missing_price() {
+        let now = 1_700_000_000;
+        let stale = GlobalConfig {
+            sol_price_usd: 200,
+            price_last_updated: now - crate::constants::MAX_PRICE_STALENESS_SECONDS - 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            stale.fresh_usd_cents_to_lamports(5_000, now).unwrap_err(),
+            crate::errors::AstraError::PriceOracleUnavailable.into()
+        );
+
+        let unpriced = GlobalConfig {
+            price_last_updated: now,
+            ..Default::default()
+        };
+        assert!(unpriced.fresh_usd_cents_to_lamports(5_000, now).is_err());
+    }
+
+    #[test]
+    fn test_buy_rejected_once_price_goes_stale() {
+        use crate::constants::MAX_PRICE_STALENESS_SECONDS;
+
+        let updated_at = 1_700_000_000;
+        let config = GlobalConfig {
+            sol_price_usd: 200,
+            price_last_updated: updated_at,
+            enforce_fresh_price: true,
+            ..Default::default()
+        };
+        assert!(config
+            .check_price_fresh_for_buy(updated_at + MAX_PRICE_STALENESS_SECONDS)
+            .is_ok());
+
+        // Clock warped past the staleness window
+        let stale = updated_at + MAX_PRICE_STALENESS_SECONDS + 1;
+        assert_eq!(
+            config.check_price_fresh_for_buy(stale).unwrap_err(),
+            crate::errors::AstraError::PriceOracleUnavailable.into()
+        );
+
+        // Flag off, or no price ever set (devnet): buys stay open
+        let relaxed = GlobalConfig {
+            enforce_fresh_price: false,
+            ..config.clone()
+        };
+        assert!(relaxed.check_price_fresh_for_buy(stale).is_ok());
+        let unpriced = GlobalConfig {
+            sol_price_usd: 0,
+            ..config
+        };
+        assert!(unpriced.check_price_fresh_for_buy(stale).is_ok());
+    }
+
+    #[test]
+    fn test_refresh_min_seed_tracks_current_price() {
+        use crate::constants::{MAX_PRICE_STALENESS_SECONDS, MIN_SEED_USD_CENTS};
+
+        let now = 1_700_000_000;
+        let mut config = GlobalConfig {
+            min_seed_lamports: 12_345, // set at init, long out of date
+            ..Default::default()
+        };
+        config.set_price_feed(SOL_PRICE_FEED_ASSET, 200, 0, now).unwrap();
+
+        let event = config.refresh_min_seed(now).unwrap();
+        // $40 at $200/SOL = 0.2 SOL
+        assert_eq!(config.min_seed_lamports, SOL / 5);
+        assert_eq!(
+            Some(config.min_seed_lamports),
+            config.usd_cents_to_lamports(MIN_SEED_USD_CENTS)
+        );
+        assert_eq!(event.previous_min_seed_lamports, 12_345);
+        assert_eq!(event.sol_price_usd, 200);
+
+        // Price doubles: the minimum halves
+        let later = now + crate::constants::PRICE_CACHE_UPDATE_INTERVAL_SECONDS;
+        config.set_price_feed(SOL_PRICE_FEED_ASSET, 400, 0, later).unwrap();
+        config.refresh_min_seed(later).unwrap();
+        assert_eq!(config.min_seed_lamports, SOL / 10);
+
+        // Stale price: rejected, cached value untouched
+        let stale = later + MAX_PRICE_STALENESS_SECONDS + 1;
+        assert_eq!(
+            config.refresh_min_seed(stale).map(|_| ()).unwrap_err(),
+            crate::errors::AstraError::PriceOracleUnavailable.into()
+        );
+        assert_eq!(config.min_seed_lamports, SOL / 10);
+    }
+
+    #[test]
+    fn test_force_graduation_counter_increments() {
+        let mut config = GlobalConfig::default();
+        assert_eq!(config.force_graduations_count, 0);
+
+        assert_eq!(config.record_force_graduation().unwrap(), 1);
+        assert_eq!(config.record_force_graduation().unwrap(), 2);
+        assert_eq!(config.force_graduations_count, 2);
+
+        config.force_graduations_count = u64::MAX;
+        assert!(config.record_force_graduation().is_err());
+    }
+
+    #[test]
+    fn test_pause_event_carries_state_and_reason() {
+        let mut config = GlobalConfig::default();
+        let authority = Pubkey::new_unique();
+
+        let event = config.set_paused(true, PauseReason::OracleFailure, authority, 1_700_000_000);
+        assert!(event.paused);
+        assert_eq!(event.reason_code, PauseReason::OracleFailure);
+        assert_eq!(event.authority, authority);
+        assert_eq!(event.timestamp, 1_700_000_000);
+        assert!(config.paused);
+        assert_eq!(config.pause_reason, Some(PauseReason::OracleFailure));
+
+        let event = config.set_paused(false, PauseReason::Resolved, authority, 1_700_000_600);
+        assert!(!event.paused);
+        assert_eq!(event.reason_code, PauseReason::Resolved);
+        assert!(!config.paused);
+        assert_eq!(config.pause_reason, None);
+    }
+
+    #[test]
+    fn test_buy_reverts_when_paused() {
+        let mut config = GlobalConfig::default();
+        assert!(config.check_not_paused().is_ok());
+
+        config.set_paused(true, PauseReason::ExploitResponse, Pubkey::new_unique(), 1_700_000_000);
+        assert_eq!(
+            config.check_not_paused().unwrap_err(),
+            crate::errors::AstraError::ProtocolPaused.into()
+        );
+
+        // buy (and buy_usd, which shares its path) runs the check first
+        let buy = include_str!("../instructions/buy.rs");
+        let execute = buy.split("pub(crate) fn execute").nth(1).unwrap();
+        assert!(execute.contains("config.check_not_paused()?"));
+    }
+
+    #[test]
+    fn test_every_gated_instruction_checks_pause() {
+        // The set documented on set_paused; buy_usd runs buy's execute
+        let gated = [
+            ("boost_graduation", include_str!("../instructions/boost_graduation.rs")),
+            ("buy", include_str!("../instructions/buy.rs")),
+            ("claim_creator_fees", include_str!("../instructions/claim_creator_fees.rs")),
+            ("claim_refund", include_str!("../instructions/claim_refund.rs")),
+            ("claim_tokens", include_str!("../instructions/claim_tokens.rs")),
+            ("claim_vesting", include_str!("../instructions/claim_vesting.rs")),
+            ("create_launch", include_str!("../instructions/create_launch.rs")),
+            ("poke", include_str!("../instructions/poke.rs")),
+            ("sell", include_str!("../instructions/sell.rs")),
+            (
+                "stake_for_verification",
+                include_str!("../instructions/stake_for_verification.rs"),
+            ),
+        ];
+
+        for (name, source) in gated {
+            let entry = if name == "buy" {
+                "pub(crate) fn execute"
+            } else {
+                "pub fn handler"
+            };
+            let body = source
+                .split(entry)
+                .nth(1)
+                .unwrap_or_else(|| panic!("{name}: no {entry}"));
+            assert!(
+                body.contains("config.check_not_paused()?"),
+                "{name} doesn't check the pause"
+            );
+        }
+
+        let buy_usd = include_str!("../instructions/buy_usd.rs");
+        assert!(buy_usd.contains("buy::execute("));
+    }
+
+    #[test]
+    fn test_early_large_buy_is_capped() {
+        // 1 SOL pool: a 10 SOL buy exceeds the 50% cap
+        let cap = config().max_buy_lamports(SOL);
+        assert_eq!(cap, SOL / 2);
+        assert!(10 * SOL > cap);
+    }
+
+    #[test]
+    fn test_same_buy_allowed_once_pool_matures() {
+        // 100 SOL pool: the same 10 SOL buy is within the cap
+        let cap = config().max_buy_lamports(100 * SOL);
+        assert!(10 * SOL <= cap);
+    }
+
+    #[test]
+    fn test_empty_pool_still_accepts_a_buy() {
+        // 0 SOL pool: the scaled cap is 0, the floor still admits a first buy
+        let cap = config().max_buy_lamports(0);
+        assert_eq!(cap, MIN_BUY_CAP_LAMPORTS);
+        assert!(SOL / 20 <= cap);
+        assert!(config().max_buy_lamports(1) > 0);
+    }
+
+    #[test]
+    fn test_cap_never_exceeds_flat_max() {
+        assert_eq!(config().max_buy_lamports(u64::MAX), MAX_BUY_LAMPORTS);
+    }
+
+    #[test]
+    fn test_fee_schedule_matches_constants() {
+        let config = GlobalConfig {
+            sell_fee_bps: 25,
+            ..Default::default()
+        };
+        let schedule = config.fee_schedule();
+
+        assert_eq!(schedule.total_fee_bps, TOTAL_FEE_BPS);
+        assert_eq!(schedule.creator_unverified_bps, CREATOR_FEE_UNVERIFIED_BPS);
+        assert_eq!(schedule.creator_verified_bps, CREATOR_FEE_VERIFIED_BPS);
+        assert_eq!(schedule.sell_fee_bps, 25);
+        assert_eq!(schedule.verified_total_fee_bps, 0);
+    }
+
+    #[test]
+    fn test_verified_creator_fee_discount() {
+        let unverified = crate::state::CreatorStats::default();
+        let verified = crate::state::CreatorStats {
+            graduated_count: 1,
+            ..Default::default()
+        };
+        let total_fee = |(total_bps, _): (u64, u64)| 10 * SOL * total_bps / 10_000;
+
+        // Off by default: verification only moves the creator/protocol split
+        let config = GlobalConfig::default();
+        assert_eq!(
+            config.buy_fee_bps(&unverified),
+            (TOTAL_FEE_BPS, CREATOR_FEE_UNVERIFIED_BPS)
+        );
+        assert_eq!(
+            config.buy_fee_bps(&verified),
+            (TOTAL_FEE_BPS, CREATOR_FEE_VERIFIED_BPS)
+        );
+
+        // 0.8% for verified creators' buyers; unverified launches still pay 1%
+        let config = GlobalConfig {
+            verified_total_fee_bps: 80,
+            ..Default::default()
+        };
+        assert_eq!(total_fee(config.buy_fee_bps(&unverified)), SOL / 10);
+        assert_eq!(total_fee(config.buy_fee_bps(&verified)), SOL * 8 / 100);
+        assert_eq!(
+            config.buy_fee_bps(&verified),
+            (80, CREATOR_FEE_VERIFIED_BPS)
+        );
+    }
+
+    #[test]
+    fn test_verified_total_fee_bounds() {
+        use crate::errors::AstraError;
+
+        for ok in [0, CREATOR_FEE_VERIFIED_BPS, TOTAL_FEE_BPS] {
+            assert!(GlobalConfig::validate_verified_total_fee_bps(ok).is_ok());
+        }
+        // Below the creator share would leave the protocol a negative cut
+        for bad in [CREATOR_FEE_VERIFIED_BPS - 1, TOTAL_FEE_BPS + 1] {
+            assert_eq!(
+                GlobalConfig::validate_verified_total_fee_bps(bad).unwrap_err(),
+                AstraError::InvalidVerifiedFee.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_buy_fee_split() {
+        // Unverified 1%: 0.3% creator, 0.7% protocol
+        let fees = GlobalConfig::split_buy_fee(SOL, TOTAL_FEE_BPS, CREATOR_FEE_UNVERIFIED_BPS)
+            .unwrap();
+        assert_eq!(fees.total_fee, SOL / 100);
+        assert_eq!(fees.creator_fee, SOL * 3 / 1_000);
+        assert_eq!(fees.protocol_fee, SOL * 7 / 1_000);
+        assert_eq!(fees.net_sol, SOL - SOL / 100);
+
+        // Fees floor independently; net_sol is what the total leaves
+        let fees = GlobalConfig::split_buy_fee(999, TOTAL_FEE_BPS, CREATOR_FEE_VERIFIED_BPS)
+            .unwrap();
+        assert_eq!((fees.total_fee, fees.creator_fee, fees.protocol_fee), (9, 4, 4));
+        assert_eq!(fees.net_sol, 990);
+
+        // Fee-free buy
+        let fees = GlobalConfig::split_buy_fee(SOL, 0, 0).unwrap();
+        assert_eq!(fees.net_sol, SOL);
+        assert!(GlobalConfig::split_buy_fee(SOL, 30, 50).is_err());
+    }
+
+    #[test]
+    fn test_fee_free_buy_waiver_is_capped() {
+        use crate::constants::FEE_FREE_BUY_CAP_LAMPORTS;
+
+        // At or under the cap: no fees at all
+        let fees = GlobalConfig::split_fee_free_buy(
+            FEE_FREE_BUY_CAP_LAMPORTS,
+            TOTAL_FEE_BPS,
+            CREATOR_FEE_UNVERIFIED_BPS,
+        )
+        .unwrap();
+        assert_eq!(fees.total_fee, 0);
+        assert_eq!(fees.net_sol, FEE_FREE_BUY_CAP_LAMPORTS);
+
+        // A whale buy pays normal fees on everything above the cap
+        let amount = FEE_FREE_BUY_CAP_LAMPORTS + 100 * SOL;
+        let fees =
+            GlobalConfig::split_fee_free_buy(amount, TOTAL_FEE_BPS, CREATOR_FEE_UNVERIFIED_BPS)
+                .unwrap();
+        let excess =
+            GlobalConfig::split_buy_fee(100 * SOL, TOTAL_FEE_BPS, CREATOR_FEE_UNVERIFIED_BPS)
+                .unwrap();
+        assert_eq!(fees.total_fee, excess.total_fee);
+        assert_eq!(fees.creator_fee, excess.creator_fee);
+        assert_eq!(fees.protocol_fee, excess.protocol_fee);
+        assert_eq!(fees.net_sol, amount - excess.total_fee);
+    }
+
+    #[test]
+    fn test_default_sell_fee_is_free_exit() {
+        assert_eq!(GlobalConfig::default().sell_fee(5 * SOL), Some(0));
+    }
+
+    #[test]
+    fn test_nonzero_sell_fee_reduces_refund() {
+        let config = GlobalConfig {
+            sell_fee_bps: 100, // 1%
+            ..Default::default()
+        };
+        let refund = 5 * SOL;
+        let fee = config.sell_fee(refund).unwrap();
+
+        // Treasury is credited the fee, seller receives the remainder
+        assert_eq!(fee, SOL / 20);
+        assert_eq!(refund - fee, 4_950_000_000);
+    }
+
+    #[test]
+    fn test_sell_min_out_guards_the_net_refund() {
+        let config = GlobalConfig {
+            sell_fee_bps: 100, // 1%
+            ..Default::default()
+        };
+        let refund = 5 * SOL;
+
+        assert_eq!(
+            config.sell_proceeds(refund, 4_950_000_000).unwrap(),
+            (SOL / 20, 4_950_000_000)
+        );
+        // A floor at the gross refund is too high once the fee comes out
+        assert_eq!(
+            config.sell_proceeds(refund, refund).unwrap_err(),
+            crate::errors::AstraError::SlippageExceeded.into()
+        );
+        // ...and a floor above the whole basis can never be met
+        assert_eq!(
+            config.sell_proceeds(refund, u64::MAX).unwrap_err(),
+            crate::errors::AstraError::SlippageExceeded.into()
+        );
+    }
+
+    #[test]
+    fn test_authority_change_propose_wait_accept() {
+        use crate::constants::AUTHORITY_CHANGE_DELAY_SECONDS;
+
+        let old = Pubkey::new_unique();
+        let new = Pubkey::new_unique();
+        let mut config = GlobalConfig {
+            authority: old,
+            ..Default::default()
+        };
+        let now = 1_700_000_000;
+
+        let effective_at = config.propose_authority(new, now).unwrap();
+        assert_eq!(effective_at, now + AUTHORITY_CHANGE_DELAY_SECONDS);
+        assert_eq!(config.pending_authority, Some(new));
+        // Nothing changes until acceptance
+        assert_eq!(config.authority, old);
+
+        assert_eq!(config.accept_authority(&new, effective_at).unwrap(), old);
+        assert_eq!(config.authority, new);
+        assert_eq!(config.pending_authority, None);
+        assert_eq!(config.authority_change_effective_at, 0);
+    }
+
+    #[test]
+    fn test_premature_or_wrong_authority_accept_rejected() {
+        let new = Pubkey::new_unique();
+        let mut config = GlobalConfig {
+            authority: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let effective_at = config.propose_authority(new, 1_700_000_000).unwrap();
+
+        assert_eq!(
+            config.accept_authority(&new, effective_at - 1).unwrap_err(),
+            crate::errors::AstraError::AuthorityChangeNotReady.into()
+        );
+        assert_eq!(
+            config
+                .accept_authority(&Pubkey::new_unique(), effective_at)
+                .unwrap_err(),
+            crate::errors::AstraError::Unauthorized.into()
+        );
+        assert_eq!(config.pending_authority, Some(new));
+
+        // Proposals must name a real, different key
+        let current = config.authority;
+        assert!(config.propose_authority(current, 0).is_err());
+        assert!(config.propose_authority(Pubkey::default(), 0).is_err());
+    }
+
+    #[test]
+    fn test_authority_change_cancellation() {
+        let old = Pubkey::new_unique();
+        let new = Pubkey::new_unique();
+        let mut config = GlobalConfig {
+            authority: old,
+            ..Default::default()
+        };
+        let effective_at = config.propose_authority(new, 1_700_000_000).unwrap();
+
+        assert_eq!(config.cancel_authority_change().unwrap(), new);
+        assert_eq!(config.pending_authority, None);
+
+        // The cancelled proposal can no longer be accepted, even after the delay
+        assert!(config.accept_authority(&new, effective_at).is_err());
+        assert_eq!(config.authority, old);
+        assert_eq!(
+            config.cancel_authority_change().unwrap_err(),
+            crate::errors::AstraError::NoPendingAuthorityChange.into()
+        );
+    }
+
+    #[test]
+    fn test_wallet_rotation() {
+        let treasury = Pubkey::new_unique();
+        let migrated = Pubkey::new_unique();
+        let mut config = GlobalConfig {
+            protocol_fee_wallet: treasury,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            GlobalConfig::rotate_wallet(&mut config.protocol_fee_wallet, migrated).unwrap(),
+            treasury
+        );
+        assert_eq!(config.protocol_fee_wallet, migrated);
+
+        // Never rotate a wallet to the default pubkey
+        assert_eq!(
+            GlobalConfig::rotate_wallet(&mut config.protocol_fee_wallet, Pubkey::default())
+                .unwrap_err(),
+            crate::errors::AstraError::InvalidConfigWallet.into()
+        );
+        assert_eq!(config.protocol_fee_wallet, migrated);
+    }
+
+    #[test]
+    fn test_old_authority_loses_access_after_rotation() {
+        let old = Pubkey::new_unique();
+        let new = Pubkey::new_unique();
+        let mut config = GlobalConfig {
+            authority: old,
+            ..Default::default()
+        };
+        let effective_at = config.propose_authority(new, 1_700_000_000).unwrap();
+        config.accept_authority(&new, effective_at).unwrap();
+
+        // Admin instructions gate on `signer == config.authority`
+        assert_ne!(config.authority, old);
+        assert_eq!(config.authority, new);
+
+        // The old key can't take control back through the handoff either:
+        // only the new authority can propose, and only the proposed key accepts
+        assert_eq!(
+            config.accept_authority(&old, effective_at).unwrap_err(),
+            crate::errors::AstraError::Unauthorized.into()
+        );
+        let reproposed_at = config.propose_authority(old, effective_at).unwrap();
+        assert_eq!(config.authority, new, "a proposal alone grants nothing");
+        assert!(config.accept_authority(&old, reproposed_at - 1).is_err());
+    }
+
+    #[test]
+    fn test_two_of_three_graduation_signers() {
+        let operators = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut config = GlobalConfig {
+            operator_wallet: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        config
+            .set_graduation_operators(operators.to_vec(), 2)
+            .unwrap();
+
+        assert!(config.is_graduation_operator(&operators[1]));
+        assert!(!config.is_graduation_operator(&config.operator_wallet));
+
+        // Two distinct operators sign: graduation proceeds
+        assert!(config
+            .check_graduation_signers(&[operators[0], operators[2]])
+            .is_ok());
+
+        // One operator (even listed twice, or with an outsider) is not enough
+        for signers in [
+            vec![operators[0]],
+            vec![operators[0], operators[0]],
+            vec![operators[0], Pubkey::new_unique()],
+        ] {
+            assert_eq!(
+                config.check_graduation_signers(&signers).unwrap_err(),
+                crate::errors::AstraError::InsufficientOperatorSignatures.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_single_operator_graduation_by_default() {
+        let operator = Pubkey::new_unique();
+        let config = GlobalConfig {
+            operator_wallet: operator,
+            ..Default::default()
+        };
+
+        assert!(config.is_graduation_operator(&operator));
+        assert!(config.check_graduation_signers(&[operator]).is_ok());
+        assert!(config
+            .check_graduation_signers(&[Pubkey::new_unique()])
+            .is_err());
+    }
+
+    #[test]
+    fn test_invalid_graduation_operator_sets_rejected() {
+        let mut config = GlobalConfig::default();
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let too_many = (0..=crate::constants::MAX_GRADUATION_OPERATORS)
+            .map(|_| Pubkey::new_unique())
+            .collect::<Vec<_>>();
+
+        for (operators, threshold) in [
+            (vec![a, b], 0),
+            (vec![a, b], 3),
+            (vec![a, a], 1),
+            (vec![a, Pubkey::default()], 1),
+            (vec![], 2),
+            (too_many, 1),
+        ] {
+            assert_eq!(
+                config
+                    .set_graduation_operators(operators, threshold)
+                    .unwrap_err(),
+                crate::errors::AstraError::InvalidOperatorSet.into()
+            );
+        }
+
+        // Clearing the set returns to single-operator mode
+        config.set_graduation_operators(vec![a, b], 2).unwrap();
+        config.set_graduation_operators(vec![], 1).unwrap();
+        assert!(config.graduation_operators.is_empty());
+        assert_eq!(config.graduation_threshold, 1);
+    }
+
+    #[test]
+    fn test_min_buy_lamports_at_price() {
+        let config = GlobalConfig {
+            sol_price_usd: 200,
+            ..Default::default()
+        };
+        // $1 at $200/SOL = 0.005 SOL
+        assert_eq!(config.min_buy_lamports(), Some(5_000_000));
+        assert_eq!(GlobalConfig::default().min_buy_lamports(), None);
+    }
+
+    #[test]
+    fn test_allowed_amm_config() {
+        let vetted = Pubkey::new_unique();
+        let config = GlobalConfig {
+            allowed_amm_configs: vec![vetted],
+            ..Default::default()
+        };
+        assert!(config.is_amm_config_allowed(&vetted));
+    }
+
+    #[test]
+    fn test_disallowed_amm_config() {
+        let config = GlobalConfig {
+            allowed_amm_configs: vec![Pubkey::new_unique()],
+            ..Default::default()
+        };
+        assert!(!config.is_amm_config_allowed(&Pubkey::new_unique()));
+        assert!(!GlobalConfig::default().is_amm_config_allowed(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn test_distributor_must_be_allowlisted() {
+        let vetted = Pubkey::new_unique();
+        let config = GlobalConfig {
+            allowed_distributors: vec![vetted],
+            ..Default::default()
+        };
+        assert!(config.check_distributor(None).is_ok());
+        assert!(config.check_distributor(Some(vetted)).is_ok());
+        assert_eq!(
+            config.check_distributor(Some(Pubkey::new_unique())).unwrap_err(),
+            crate::errors::AstraError::InvalidDistributor.into()
+        );
+    }
+
+    #[test]
+    fn test_distributor_rejected_without_allowlist() {
+        let config = GlobalConfig::default();
+        assert!(config.check_distributor(None).is_ok());
+        assert_eq!(
+            config.check_distributor(Some(Pubkey::new_unique())).unwrap_err(),
+            crate::errors::AstraError::InvalidDistributor.into()
+        );
+    }
+
+    #[test]
+    fn test_zero_scale_disables_maturity_cap() {
+        assert_eq!(GlobalConfig::default().max_buy_lamports(0), MAX_BUY_LAMPORTS);
+    }
+
+    #[test]
+    fn test_permissionless_janitor_keeps_rent() {
+        let config = GlobalConfig::default();
+        let caller = Pubkey::new_unique();
+
+        assert!(config.is_authorized_janitor(&caller));
+        assert_eq!(config.janitor_rent_recipient(&caller), caller);
+    }
+
+    #[test]
+    fn test_keeper_mode_routes_rent_to_treasury() {
+        let operator = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+        let config = GlobalConfig {
+            operator_wallet: operator,
+            janitor_rewards_wallet: Some(treasury),
+            ..Default::default()
+        };
+
+        assert!(config.is_authorized_janitor(&operator));
+        assert!(!config.is_authorized_janitor(&Pubkey::new_unique()));
+        assert_eq!(config.janitor_rent_recipient(&operator), treasury);
+    }
+
+    #[test]
+    fn test_price_feeds_update_independently() {
+        let mut config = GlobalConfig::default();
+        let other = Pubkey::new_unique();
+
+        config.set_price_feed(SOL_PRICE_FEED_ASSET, 200, 0, 100).unwrap();
+        config.set_price_feed(other, 3, 0, 110).unwrap();
+        config.set_price_feed(SOL_PRICE_FEED_ASSET, 210, 0, 120).unwrap();
+
+        assert_eq!(config.price_feeds.len(), 2);
+        assert_eq!(config.price_feed(&other).unwrap().price_usd, 3);
+        assert_eq!(config.price_feed(&other).unwrap().last_updated, 110);
+        assert_eq!(config.price_feed(&SOL_PRICE_FEED_ASSET).unwrap().price_usd, 210);
+
+        // SOL feed mirrors into the legacy single-price fields; others don't
+        assert_eq!(config.sol_price_usd, 210);
+        assert_eq!(config.price_last_updated, 120);
+    }
+
+    #[test]
+    fn test_seed_bounds_require_tight_confidence() {
+        use crate::constants::MAX_SEED_PRICE_CONFIDENCE_BPS;
+
+        let mut config = GlobalConfig::default();
+        config
+            .set_price_feed(SOL_PRICE_FEED_ASSET, 200, MAX_SEED_PRICE_CONFIDENCE_BPS, 100)
+            .unwrap();
+        // $40 / $20,000 at $200/SOL
+        assert_eq!(config.seed_bounds_lamports().unwrap(), (SOL / 5, 100 * SOL));
+
+        // Volatile market: wide confidence blocks create_launch's seed check
+        config
+            .set_price_feed(
+                SOL_PRICE_FEED_ASSET,
+                200,
+                MAX_SEED_PRICE_CONFIDENCE_BPS + 1,
+                100 + crate::constants::PRICE_CACHE_UPDATE_INTERVAL_SECONDS,
+            )
+            .unwrap();
+        assert_eq!(config.price_confidence_bps, MAX_SEED_PRICE_CONFIDENCE_BPS + 1);
+        assert_eq!(
+            config.seed_bounds_lamports().unwrap_err(),
+            crate::errors::AstraError::PriceConfidenceTooWide.into()
+        );
+
+        // Only the SOL feed drives the gate
+        config
+            .set_price_feed(Pubkey::new_unique(), 1, 10_000, 0)
+            .unwrap();
+        assert_eq!(config.price_confidence_bps, MAX_SEED_PRICE_CONFIDENCE_BPS + 1);
+    }
+
+    #[test]
+    fn test_seed_bounds_reject_unreported_confidence() {
+        let mut config = GlobalConfig::default();
+        config
+            .set_price_feed(SOL_PRICE_FEED_ASSET, 200, 0, 100)
+            .unwrap();
+        assert_eq!(
+            config.seed_bounds_lamports().unwrap_err(),
+            crate::errors::AstraError::PriceConfidenceUnknown.into()
+        );
+
+        // A tight but nonzero Pyth interval rounds up to 1 bps and passes
+        let (price_usd, confidence_bps) =
+            GlobalConfig::usd_from_pyth(20_000_000_000, 1, -8).unwrap();
+        assert_eq!((price_usd, confidence_bps), (200, 1));
+        config
+            .set_price_feed(
+                SOL_PRICE_FEED_ASSET,
+                price_usd,
+                confidence_bps,
+                100 + crate::constants::PRICE_CACHE_UPDATE_INTERVAL_SECONDS,
+            )
+            .unwrap();
+        assert!(config.seed_bounds_lamports().is_ok());
+    }
+
+    #[test]
+    fn test_usd_from_pyth_scales_by_exponent() {
+        use crate::errors::AstraError;
+
+        // $187.65432100 +/- $1.87654321 (1%) at exponent -8
+        assert_eq!(
+            GlobalConfig::usd_from_pyth(18_765_432_100, 187_654_321, -8).unwrap(),
+            (187, 100)
+        );
+        assert_eq!(GlobalConfig::usd_from_pyth(150, 0, 0).unwrap(), (150, 0));
+        assert_eq!(GlobalConfig::usd_from_pyth(15, 0, 1).unwrap(), (150, 0));
+
+        // Sub-dollar prices round to 0 and are rejected by set_price_feed
+        assert_eq!(GlobalConfig::usd_from_pyth(99, 0, -2).unwrap(), (0, 0));
+        for price in [0, -1] {
+            assert_eq!(
+                GlobalConfig::usd_from_pyth(price, 0, -8).unwrap_err(),
+                AstraError::InvalidPrice.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_price_feed_capacity_and_zero_price() {
+        let mut config = GlobalConfig::default();
+        for _ in 0..MAX_PRICE_FEEDS {
+            config.set_price_feed(Pubkey::new_unique(), 1, 0, 0).unwrap();
+        }
+        assert!(config.set_price_feed(Pubkey::new_unique(), 1, 0, 0).is_err());
+        assert!(config.set_price_feed(SOL_PRICE_FEED_ASSET, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_too_frequent_price_update_rejected() {
+        let mut config = GlobalConfig::default();
+        config.set_price_feed(SOL_PRICE_FEED_ASSET, 200, 0, 1_000).unwrap();
+
+        // Within the interval and inside the deviation band
+        let err = config
+            .set_price_feed(SOL_PRICE_FEED_ASSET, 201, 0, 1_000 + 10)
+            .unwrap_err();
+        assert_eq!(err, crate::errors::AstraError::PriceUpdateTooFrequent.into());
+        assert_eq!(config.sol_price_usd, 200);
+
+        // Once the interval has passed any price is accepted
+        config
+            .set_price_feed(
+                SOL_PRICE_FEED_ASSET,
+                201,
+                0,
+                1_000 + crate::constants::PRICE_CACHE_UPDATE_INTERVAL_SECONDS,
+            )
+            .unwrap();
+        assert_eq!(config.sol_price_usd, 201);
+    }
+
+    #[test]
+    fn test_replayed_older_price_update_rejected() {
+        let mut config = GlobalConfig::default();
+        config.set_price_feed(SOL_PRICE_FEED_ASSET, 200, 0, 1_000).unwrap();
+
+        // An older (or same-second) update is rejected even if the price moved a lot
+        for publish_time in [999, 1_000, 1_000 - 200] {
+            assert_eq!(
+                config
+                    .set_price_feed(SOL_PRICE_FEED_ASSET, 150, 0, publish_time)
+                    .unwrap_err(),
+                crate::errors::AstraError::PriceUpdateNotNewer.into()
+            );
+        }
+        assert_eq!(config.sol_price_usd, 200);
+        assert_eq!(config.price_last_updated, 1_000);
+
+        // A newer update still goes through
+        config.set_price_feed(SOL_PRICE_FEED_ASSET, 150, 0, 1_001).unwrap();
+        assert_eq!(config.sol_price_usd, 150);
+    }
+
+    #[test]
+    fn test_volatility_overrides_price_interval() {
+        let mut config = GlobalConfig::default();
+        config.set_price_feed(SOL_PRICE_FEED_ASSET, 200, 0, 1_000).unwrap();
+
+        // 2% move a second later is genuine volatility
+        config.set_price_feed(SOL_PRICE_FEED_ASSET, 196, 0, 1_001).unwrap();
+        assert_eq!(config.sol_price_usd, 196);
+        assert_eq!(config.price_last_updated, 1_001);
+    }
+
+    #[test]
+    fn test_fractional_usd_preset_converts_exactly() {
+        let config = GlobalConfig {
+            sol_price_usd: 200,
+            ..Default::default()
+        };
+
+        // $2.50 at $200/SOL = 0.0125 SOL
+        assert_eq!(config.usd_cents_to_lamports(250), Some(12_500_000));
+
+        // Whole-dollar amounts agree with the cents path
+        assert_eq!(config.usd_to_lamports(5), config.usd_cents_to_lamports(500));
+        assert_eq!(
+            config.usd_cents_to_lamports(crate::constants::BUY_PRESETS_USD_CENTS[0]),
+            Some(25_000_000)
+        );
+        assert_eq!(GlobalConfig::default().usd_cents_to_lamports(250), None);
     }
 }
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CREATOR_LAUNCHES_PER_PAGE;
+
+/// Creator launch index - append-only, paginated list of a creator's launches
+///
+/// Lets clients enumerate a creator's portfolio without `getProgramAccounts`.
+/// Launch N (the creator's Nth, from `CreatorStats.total_launches`) lives on
+/// page N / CREATOR_LAUNCHES_PER_PAGE; clients walk pages 0..=last_page.
+///
+/// PDA seeds: [b"creator_launches", creator.key().as_ref(), page.to_le_bytes().as_ref()]
+#[account]
+#[derive(InitSpace, Default)]
+pub struct CreatorLaunches {
+    /// The creator whose launches are indexed
+    pub creator: Pubkey,
+
+    /// Page number within the creator's index
+    pub page: u64,
+
+    /// Launch keys in creation order
+    #[max_len(CREATOR_LAUNCHES_PER_PAGE)]
+    pub launches: Vec<Pubkey>,
+
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl CreatorLaunches {
+    /// Page holding the creator's launch at `launch_index` (0-based)
+    pub fn page_for(launch_index: u64) -> u64 {
+        launch_index / CREATOR_LAUNCHES_PER_PAGE as u64
+    }
+
+    /// Check whether the page has room for another launch
+    pub fn is_full(&self) -> bool {
+        self.launches.len() >= CREATOR_LAUNCHES_PER_PAGE
+    }
+
+    /// Append a launch key to this page
+    pub fn record_launch(&mut self, launch: Pubkey) -> Result<()> {
+        require!(
+            !self.is_full(),
+            crate::errors::AstraError::CreatorLaunchesPageFull
+        );
+        self.launches.push(launch);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::AstraError;
+
+    /// Append `count` launches the way create_launch does, one page PDA per page
+    fn index_launches(count: u64) -> (Vec<Pubkey>, Vec<CreatorLaunches>) {
+        let creator = Pubkey::new_unique();
+        let mut keys = Vec::new();
+        let mut pages: Vec<CreatorLaunches> = Vec::new();
+
+        for launch_index in 0..count {
+            let page = CreatorLaunches::page_for(launch_index);
+            if pages.len() as u64 == page {
+                pages.push(CreatorLaunches {
+                    creator,
+                    page,
+                    ..Default::default()
+                });
+            }
+            let key = Pubkey::new_unique();
+            pages[page as usize].record_launch(key).unwrap();
+            keys.push(key);
+        }
+        (keys, pages)
+    }
+
+    #[test]
+    fn test_launches_read_back_in_order() {
+        let (keys, pages) = index_launches(3);
+
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].launches, keys);
+    }
+
+    #[test]
+    fn test_launches_paginate_across_pages() {
+        let per_page = CREATOR_LAUNCHES_PER_PAGE as u64;
+        let (keys, pages) = index_launches(per_page * 2 + 1);
+
+        assert_eq!(pages.len(), 3);
+        assert!(pages[0].is_full() && pages[1].is_full());
+        assert_eq!(pages[2].launches.len(), 1);
+        assert_eq!(pages[2].page, 2);
+
+        // Walking pages in order yields every launch in creation order
+        let walked: Vec<Pubkey> = pages
+            .iter()
+            .flat_map(|p| p.launches.iter().copied())
+            .collect();
+        assert_eq!(walked, keys);
+    }
+
+    #[test]
+    fn test_page_for_boundaries() {
+        let per_page = CREATOR_LAUNCHES_PER_PAGE as u64;
+        assert_eq!(CreatorLaunches::page_for(0), 0);
+        assert_eq!(CreatorLaunches::page_for(per_page - 1), 0);
+        assert_eq!(CreatorLaunches::page_for(per_page), 1);
+    }
+
+    #[test]
+    fn test_full_page_rejects_append() {
+        let (_, mut pages) = index_launches(CREATOR_LAUNCHES_PER_PAGE as u64);
+        let err = pages[0].record_launch(Pubkey::new_unique()).unwrap_err();
+        assert_eq!(err, AstraError::CreatorLaunchesPageFull.into());
+    }
+}
@@ -4,11 +4,11 @@ use anchor_lang::prelude::*;
 ///
 /// Used for fee tier determination:
 /// - Unverified creators: 0.3% fee
-/// - Verified creators (≥1 graduation): 0.5% fee
+/// - Verified creators (≥1 graduation, or a sufficient unslashed bond): 0.5% fee
 ///
 /// PDA seeds: [b"creator_stats", creator.key().as_ref()]
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct CreatorStats {
     /// The creator's wallet address
     pub creator: Pubkey,
@@ -22,14 +22,42 @@ pub struct CreatorStats {
     /// Total launches created (graduated or not)
     pub total_launches: u64,
 
+    /// Timestamp of the creator's first launch (for longevity)
+    pub first_launch_at: i64,
+
+    /// Lamports staked in the creator's verification bond (mirror of CreatorBond.amount)
+    pub bonded_lamports: u64,
+
+    /// Set once a bond is slashed - the bond fast-track is closed for good
+    pub bond_slashed: bool,
+
     /// Bump for PDA derivation
     pub bump: u8,
 }
 
 impl CreatorStats {
-    /// Check if creator is verified (has at least one graduation)
+    /// Check if creator is verified
+    /// A graduation, or a bond of at least MIN_VERIFICATION_BOND_LAMPORTS that
+    /// has never been slashed
     pub fn is_verified(&self) -> bool {
-        self.graduated_count > 0
+        self.graduated_count > 0 || self.has_verification_bond()
+    }
+
+    /// Check the bond fast-track alone
+    pub fn has_verification_bond(&self) -> bool {
+        !self.bond_slashed
+            && self.bonded_lamports >= crate::constants::MIN_VERIFICATION_BOND_LAMPORTS
+    }
+
+    /// Mirror the creator's bond balance after a stake or unstake
+    pub fn sync_bond(&mut self, bonded_lamports: u64) {
+        self.bonded_lamports = bonded_lamports;
+    }
+
+    /// Record a slashed bond - permanently disables the bond fast-track
+    pub fn record_bond_slash(&mut self) {
+        self.bonded_lamports = 0;
+        self.bond_slashed = true;
     }
 
     /// Get creator fee rate in basis points
@@ -42,7 +70,12 @@ impl CreatorStats {
     }
 
     /// Record a new launch creation
-    pub fn record_launch(&mut self) {
+    /// Stamps first_launch_at on the first launch, even when the account was
+    /// created earlier by another instruction (e.g. a verification bond)
+    pub fn record_launch(&mut self, now: i64) {
+        if self.first_launch_at == 0 {
+            self.first_launch_at = now;
+        }
         self.total_launches += 1;
     }
 
@@ -55,4 +88,116 @@ impl CreatorStats {
     pub fn record_fees(&mut self, amount: u64) {
         self.total_fees_earned += amount;
     }
+
+    /// Reputation score in [0, 10000]
+    ///
+    /// Weighting:
+    /// - Graduation rate: up to 5000 (graduated / launched)
+    /// - Lifetime fees: up to 3000 (linear to 100 SOL)
+    /// - Longevity: up to 2000 (linear to 365 days since first launch)
+    pub fn reputation_score(&self, now: i64) -> u64 {
+        const GRADUATION_WEIGHT: u64 = 5000;
+        const FEES_WEIGHT: u64 = 3000;
+        const LONGEVITY_WEIGHT: u64 = 2000;
+        const FEES_CAP_SOL: u64 = 100;
+        const LONGEVITY_CAP_DAYS: u64 = 365;
+
+        if self.total_launches == 0 {
+            return 0;
+        }
+
+        // graduated_count can't exceed total_launches, but clamp defensively
+        let graduated = self.graduated_count.min(self.total_launches);
+        let graduation_score = graduated * GRADUATION_WEIGHT / self.total_launches;
+
+        let fees_sol = (self.total_fees_earned / crate::constants::LAMPORTS_PER_SOL)
+            .min(FEES_CAP_SOL);
+        let fees_score = fees_sol * FEES_WEIGHT / FEES_CAP_SOL;
+
+        let days_active = (now.saturating_sub(self.first_launch_at).max(0) / 86_400) as u64;
+        let longevity_score =
+            days_active.min(LONGEVITY_CAP_DAYS) * LONGEVITY_WEIGHT / LONGEVITY_CAP_DAYS;
+
+        graduation_score + fees_score + longevity_score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::LAMPORTS_PER_SOL;
+
+    const NOW: i64 = 1_700_000_000;
+    const DAY: i64 = 86_400;
+
+    #[test]
+    fn test_reputation_zero_launches() {
+        assert_eq!(CreatorStats::default().reputation_score(NOW), 0);
+    }
+
+    #[test]
+    fn test_reputation_perfect_graduation_record() {
+        let stats = CreatorStats {
+            graduated_count: 4,
+            total_launches: 4,
+            first_launch_at: NOW,
+            ..Default::default()
+        };
+        assert_eq!(stats.reputation_score(NOW), 5000);
+    }
+
+    #[test]
+    fn test_reputation_maxed_out() {
+        let stats = CreatorStats {
+            graduated_count: 2,
+            total_launches: 2,
+            total_fees_earned: 500 * LAMPORTS_PER_SOL,
+            first_launch_at: NOW - 1_000 * DAY,
+            ..Default::default()
+        };
+        assert_eq!(stats.reputation_score(NOW), 10_000);
+    }
+
+    #[test]
+    fn test_reputation_partial_components() {
+        let stats = CreatorStats {
+            graduated_count: 1,
+            total_launches: 4,
+            total_fees_earned: 10 * LAMPORTS_PER_SOL,
+            first_launch_at: NOW - 73 * DAY,
+            ..Default::default()
+        };
+        // 1250 (25% graduated) + 300 (10 SOL) + 400 (73 days)
+        assert_eq!(stats.reputation_score(NOW), 1950);
+    }
+
+    #[test]
+    fn test_reputation_clock_before_first_launch() {
+        let stats = CreatorStats {
+            total_launches: 1,
+            first_launch_at: NOW,
+            ..Default::default()
+        };
+        assert_eq!(stats.reputation_score(NOW - DAY), 0);
+    }
+
+    #[test]
+    fn test_first_launch_stamped_when_account_predates_launches() {
+        // Account already initialized (e.g. by a bond stake) with no launch yet
+        let mut stats = CreatorStats {
+            creator: Pubkey::new_unique(),
+            bonded_lamports: 1,
+            ..Default::default()
+        };
+
+        stats.record_launch(NOW - 10 * DAY);
+        assert_eq!(stats.first_launch_at, NOW - 10 * DAY);
+
+        // Later launches keep the original timestamp
+        stats.record_launch(NOW);
+        assert_eq!(stats.first_launch_at, NOW - 10 * DAY);
+        assert_eq!(stats.total_launches, 2);
+        // Longevity counts from the first launch: 10 days -> 54
+        assert_eq!(stats.reputation_score(NOW), 54);
+    }
 }
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AstraError;
+
+/// Keeper rewards account - a poke caller's claimable 1% yield share
+///
+/// poke accrues the caller reward here instead of paying it out per call, so
+/// keepers batch-claim with claim_keeper_rewards. Like the fee escrow, the
+/// lamports above rent back `accrued`.
+///
+/// PDA seeds: [b"keeper_rewards", keeper.key().as_ref()]
+#[account]
+#[derive(InitSpace, Default)]
+pub struct KeeperRewards {
+    /// The keeper (poke caller) these rewards belong to
+    pub keeper: Pubkey,
+
+    /// Claimable rewards in lamports
+    pub accrued: u64,
+
+    /// Lifetime rewards claimed
+    pub total_claimed: u64,
+
+    /// Lifetime pokes credited (including zero-reward pokes)
+    pub pokes: u64,
+
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl KeeperRewards {
+    /// Credit one poke's caller reward
+    pub fn accrue(&mut self, reward: u64) -> Result<()> {
+        self.accrued = self
+            .accrued
+            .checked_add(reward)
+            .ok_or(AstraError::MathOverflow)?;
+        self.pokes = self.pokes.checked_add(1).ok_or(AstraError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Zero the claimable balance for a batched claim; returns the lamports owed
+    pub fn take_claim(&mut self) -> Result<u64> {
+        let amount = std::mem::take(&mut self.accrued);
+        require!(amount > 0, AstraError::NoKeeperRewards);
+        self.total_claimed = self
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(AstraError::MathOverflow)?;
+        Ok(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accrues_across_pokes_then_batch_claims() {
+        let mut rewards = KeeperRewards::default();
+        for _ in 0..5 {
+            rewards.accrue(10_000).unwrap();
+        }
+        assert_eq!(rewards.accrued, 50_000);
+        assert_eq!(rewards.pokes, 5);
+
+        // One claim pays out every poke so far
+        assert_eq!(rewards.take_claim().unwrap(), 50_000);
+        assert_eq!(rewards.accrued, 0);
+        assert_eq!(rewards.total_claimed, 50_000);
+
+        // Accrual resumes from zero; lifetime totals keep growing
+        rewards.accrue(10_000).unwrap();
+        assert_eq!(rewards.take_claim().unwrap(), 10_000);
+        assert_eq!(rewards.total_claimed, 60_000);
+        assert_eq!(rewards.pokes, 6);
+    }
+
+    #[test]
+    fn test_empty_claim_rejected() {
+        let mut rewards = KeeperRewards::default();
+        rewards.accrue(0).unwrap();
+        assert_eq!(
+            rewards.take_claim().unwrap_err(),
+            AstraError::NoKeeperRewards.into()
+        );
+        assert_eq!(rewards.total_claimed, 0);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::time::{safe_deadline, safe_elapsed};
 use anchor_lang::prelude::*;
 
 /// Launch account - represents a token launch on the bonding curve
@@ -7,8 +8,15 @@ use anchor_lang::prelude::*;
 /// - Simplified state tracking (total_shares, total_sol)
 /// - Creator vesting tracked separately
 /// - Dynamic share issuance (no cap - graduate at USD market cap target)
+///
+/// STATE MACHINE INVARIANTS:
+/// - Active -> Graduated (`mark_graduated`) or Active -> Refunding (`enter_refund_mode`)
+/// - Both terminal states are final: whichever transition lands first wins
+/// - `graduated && refund_mode` is never a reachable state - each transition
+///   rejects if either flag is already set, so overlapping graduate/enable_refund
+///   transactions resolve to exactly one outcome
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Launch {
     /// Unique launch ID (incrementing)
     pub launch_id: u64,
@@ -26,6 +34,18 @@ pub struct Launch {
     #[max_len(200)]
     pub uri: String, // Metadata URI (image, description)
 
+    /// SHA-256 of the JSON at `uri`, committed at creation (None = no commitment)
+    /// Clients fetch the URI, hash it and compare to detect swapped metadata
+    pub metadata_hash: Option<[u8; 32]>,
+
+    /// Creator's upper bound on the size of the content at `uri`, in bytes
+    /// CLIENT CONTRACT: read at most metadata_fetch_limit() bytes and treat a
+    /// larger (or unbounded) response as invalid metadata. Not enforced on-chain.
+    pub max_metadata_bytes: Option<u32>,
+
+    /// Post-creation metadata updates used (max MAX_METADATA_UPDATES)
+    pub metadata_updates_used: u8,
+
     /// ------ SUPPLY TRACKING (V7 SIMPLIFIED) ------
     /// Total shares issued (dynamic - no cap)
     /// All shares are unlocked - no 92/8 split
@@ -42,6 +62,19 @@ pub struct Launch {
     /// Creator's seed SOL basis
     pub creator_seed_sol: u64,
 
+    /// How the seed unlocks over VESTING_DURATION_SECONDS (set at creation)
+    pub vesting_mode: VestingMode,
+
+    /// Linear mode: nothing vests until this many seconds after vesting_start
+    pub vesting_cliff_seconds: i64,
+
+    /// Seconds after creation during which the creator can't buy/sell (0 = none)
+    pub creator_trade_cooldown: i64,
+
+    /// Buys are rejected before this time (None = open from creation)
+    /// The creator seed in create_launch is exempt
+    pub trading_starts_at: Option<i64>,
+
     /// ------ STATE FLAGS ------
     /// Has this launch graduated to Raydium?
     pub graduated: bool,
@@ -59,6 +92,14 @@ pub struct Launch {
     /// The vault for LP tokens
     pub vault: Option<Pubkey>,
 
+    /// External holder distributor (e.g. Merkle-drop authority)
+    /// When set, TOKENS_FOR_HOLDERS go here at graduation and claim_tokens is disabled
+    pub distributor: Option<Pubkey>,
+
+    /// Share of the minted supply burned at graduation, in bps (0 = none)
+    /// Taken pro-rata from the holder and LP allocations (see token_allocation)
+    pub burn_bps: u64,
+
     /// ------ VESTING ------
     /// Timestamp when vesting started (graduation time)
     pub vesting_start: Option<i64>,
@@ -66,6 +107,10 @@ pub struct Launch {
     /// Creator's claimed vested shares so far
     pub creator_claimed_shares: u64,
 
+    /// Unvested seed shares removed by slash_creator_bond (never vested ones)
+    /// Also counted in holder_shares_claimed: their tokens were burned
+    pub creator_slashed_shares: u64,
+
     /// ------ TIMESTAMPS ------
     /// Launch creation time
     pub created_at: i64,
@@ -78,6 +123,10 @@ pub struct Launch {
 
     /// ------ SAFETY ------
     /// Reentrancy guard - set to true during operations
+    /// Transaction-scoped: every handler clears it before returning Ok, and a
+    /// failed transaction reverts it, so it is never observed set by a later
+    /// transaction. Freezing a launch across transactions is the graduation
+    /// lock's job (graduation_locked_at), not this flag's.
     pub operation_in_progress: bool,
 
     /// ------ FEE TRACKING ------
@@ -90,29 +139,3327 @@ pub struct Launch {
     /// Total shares snapshot at graduation (for proportional token distribution)
     pub total_shares_at_graduation: u64,
 
+    /// Graduation shares whose tokens have been claimed so far
+    pub holder_shares_claimed: u64,
+
+    /// Holder tokens paid out so far (the last claim takes the rounding remainder)
+    pub holder_tokens_claimed: u64,
+
+    /// ------ GRADUATION LOCK ------
+    /// When lock_graduation froze trading (phase 1); None if never locked
+    pub graduation_locked_at: Option<i64>,
+
+    /// total_sol at lock time - the exact amount graduate puts into the pool
+    pub graduation_sol_snapshot: u64,
+
+    /// total_shares at lock time
+    pub graduation_shares_snapshot: u64,
+
+    /// Opening pool reserves read back after the Raydium CPI (set once)
+    pub graduation_record: Option<GraduationRecord>,
+
+    /// ------ GRADUATION BOOSTER ------
+    /// External account adding SOL to the graduation pool (set by its first boost)
+    pub booster: Option<Pubkey>,
+
+    /// Booster SOL held by the launch PDA on top of the curve's total_sol
+    /// Goes into the pool at graduation (unmatched - no extra tokens), or back
+    /// to the booster via withdraw_boost if the launch refunds
+    pub boosted_sol: u64,
+
+    /// ------ VOLUME TRACKING ------
+    /// Start of the current rolling volume window
+    pub volume_window_start: i64,
+
+    /// Buy volume (lamports) in the current window
+    pub volume_last_hour: u64,
+
+    /// ------ CIRCUIT BREAKER ------
+    /// Creator opt-in: freeze buys if net outflow in a window exceeds this share of total_sol (0 = off)
+    pub sell_pressure_limit_bps: u64,
+
+    /// Start of the current net-flow window
+    pub flow_window_start: i64,
+
+    /// total_sol when the current flow window opened (threshold base)
+    pub flow_window_base_sol: u64,
+
+    /// Buy inflow (lamports, net of fees) in the current flow window
+    pub flow_window_inflow: u64,
+
+    /// Sell outflow (lamports, gross of sell fee) in the current flow window
+    pub flow_window_outflow: u64,
+
+    /// Buys frozen by the circuit breaker until authority review (sells stay open)
+    pub buys_frozen: bool,
+
+    /// ------ HOLDER TRACKING ------
+    /// Positions currently holding shares (creator included)
+    pub holder_count: u64,
+
+    /// Largest buyer position reached, in shares (creator excluded)
+    /// High-water mark: sells don't lower it, so a whale can't dip under the
+    /// graduation cap for one block. graduate can replace it with the true
+    /// current maximum (prove_largest_holder). The creator has
+    /// max_creator_concentration_bps.
+    pub largest_holder_shares: u64,
+
+    /// Holder count snapshot at graduation
+    pub holders_at_graduation: u64,
+
+    /// Optional cap on concurrent holders (creator included); None = uncapped
+    pub max_holders: Option<u64>,
+
+    /// ------ EARLY BUYER INCENTIVE ------
+    /// First N unique buyers pay no fees on their first buy, up to
+    /// FEE_FREE_BUY_CAP_LAMPORTS (0 = off)
+    pub free_fee_buyer_count: u64,
+
+    /// Fee-free first buys handed out so far
+    pub fee_free_buys_used: u64,
+
+    /// One-time latch: holder_count is authoritative (tracked from creation or backfilled)
+    pub holder_count_backfilled: bool,
+
+    /// ------ REFUND TRACKING ------
+    /// Number of open position accounts (creator position included)
+    pub position_count: u64,
+
+    /// Positions still awaiting a refund (snapshot of position_count at enable_refund)
+    pub positions_remaining: u64,
+
+    /// Total SOL refunded so far (lamports)
+    pub total_refunded: u64,
+
     /// Bump for PDA derivation
     pub bump: u8,
 }
 
+/// Creator seed vesting mode (chosen at launch creation)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub enum VestingMode {
+    /// Vests linearly over the duration, after an optional cliff
+    #[default]
+    Linear,
+    /// Nothing vests until the full duration has elapsed, then everything
+    Cliff,
+}
+
+/// Refund a position receives in refund mode (see Launch::refund_quote)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RefundQuote {
+    /// Full basis owed (creator seed included for the creator)
+    pub entitlement: u64,
+    /// Lamports actually paid
+    pub amount: u64,
+    /// True if the launch is under-funded and a pro-rata haircut applies
+    pub is_proportional: bool,
+}
+
+/// Creator lockup terms, emitted at graduation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VestingSchedule {
+    pub start: i64,
+    /// Effective cliff (the full duration in Cliff mode)
+    pub cliff_seconds: i64,
+    pub duration_seconds: i64,
+    pub mode: VestingMode,
+}
+
+/// How the minted supply (with decimals) is split at graduation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TokenAllocation {
+    /// Distributed to share holders (claim_tokens or the distributor)
+    pub holders: u64,
+    /// Paired with the pool SOL in the Raydium pool
+    pub lp: u64,
+    /// Burned from the launch token account right after minting
+    pub burned: u64,
+}
+
+/// Opening state of the Raydium pool, read from the pool vaults after creation
+/// Immutable reference for the launch's opening valuation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct GraduationRecord {
+    /// wSOL in the pool's token 0 vault (lamports)
+    pub init_sol: u64,
+    /// Tokens in the pool's token 1 vault (base units)
+    pub init_tokens: u64,
+    /// Opening price in lamports per whole token, scaled by GRADUATION_PRICE_SCALE
+    pub initial_price: u64,
+    /// LP tokens minted to the vault
+    pub lp_minted: u64,
+}
+
+impl GraduationRecord {
+    /// Build a record from post-CPI pool reserves
+    pub fn from_reserves(init_sol: u64, init_tokens: u64, lp_minted: u64) -> Result<Self> {
+        require!(
+            init_sol > 0 && init_tokens > 0,
+            crate::errors::AstraError::InsufficientPoolAmounts
+        );
+
+        let initial_price = (init_sol as u128)
+            .checked_mul(crate::constants::TOKEN_DECIMALS_SCALE as u128)
+            .and_then(|v| v.checked_mul(crate::constants::GRADUATION_PRICE_SCALE))
+            .and_then(|v| v.checked_div(init_tokens as u128))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+
+        Ok(Self {
+            init_sol,
+            init_tokens,
+            initial_price,
+            lp_minted,
+        })
+    }
+}
+
 impl Launch {
+    /// Validate a launch name: 1-50 bytes, no leading/trailing whitespace
+    /// WHY: Padded names ("  SCAM  ") can mimic legitimate tokens and break UIs
+    pub fn validate_name(name: &str) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() <= 50 && name.trim() == name,
+            crate::errors::AstraError::InvalidLaunchName
+        );
+        Ok(())
+    }
+
+    /// Validate a launch symbol: 1-10 bytes, no whitespace anywhere
+    pub fn validate_symbol(symbol: &str) -> Result<()> {
+        require!(
+            !symbol.is_empty()
+                && symbol.len() <= 10
+                && !symbol.chars().any(char::is_whitespace),
+            crate::errors::AstraError::InvalidLaunchSymbol
+        );
+        Ok(())
+    }
+
+    /// Uppercase a symbol (ASCII only) and validate the normalized form
+    /// Stops case-spoofing (`Sol` vs `SOL`); names keep their case
+    pub fn normalize_symbol(symbol: &str) -> Result<String> {
+        let normalized = symbol.to_ascii_uppercase();
+        Self::validate_symbol(&normalized)?;
+        Ok(normalized)
+    }
+
+    /// Validate a metadata URI: 1-200 bytes
+    pub fn validate_uri(uri: &str) -> Result<()> {
+        require!(
+            !uri.is_empty() && uri.len() <= 200,
+            crate::errors::AstraError::InvalidLaunchUri
+        );
+        Ok(())
+    }
+
+    /// Validate a metadata size commitment: 1..=MAX_METADATA_FETCH_BYTES when set
+    pub fn validate_max_metadata_bytes(max_metadata_bytes: Option<u32>) -> Result<()> {
+        use crate::constants::MAX_METADATA_FETCH_BYTES;
+
+        require!(
+            max_metadata_bytes.is_none_or(|bytes| bytes > 0 && bytes <= MAX_METADATA_FETCH_BYTES),
+            crate::errors::AstraError::InvalidMaxMetadataBytes
+        );
+        Ok(())
+    }
+
+    /// Bytes a client should fetch from `uri` at most
+    /// The creator's commitment, or MAX_METADATA_FETCH_BYTES without one
+    pub fn metadata_fetch_limit(&self) -> u32 {
+        self.max_metadata_bytes
+            .unwrap_or(crate::constants::MAX_METADATA_FETCH_BYTES)
+    }
+
     /// Check if launch can be graduated
-    /// Basic checks only - full graduation gates checked off-chain
+    /// Basic checks only - the holder/concentration gates are checked in graduate
     pub fn can_graduate(&self) -> bool {
-        !self.graduated && !self.refund_mode && self.total_shares > 0
+        !self.graduated && !self.refund_mode && self.has_liquidity()
+    }
+
+    /// Check the launch has both shares and SOL on the curve
+    /// A zero-share or zero-SOL launch is degenerate: it must never graduate
+    /// (claim_tokens divides by the share snapshot) or report a market cap
+    pub fn has_liquidity(&self) -> bool {
+        self.total_shares > 0 && self.total_sol > 0
     }
     
-    /// Calculate current market cap in USD
-    /// Returns None if price is not available (0)
-    pub fn market_cap_usd(&self, sol_price_usd: u64) -> Option<u64> {
-        if sol_price_usd == 0 {
+    /// Add buy volume to the rolling window, resetting it once the window expires
+    pub fn record_volume(&mut self, now: i64, lamports: u64) -> Result<()> {
+        let elapsed = safe_elapsed(now, self.volume_window_start)?;
+        if elapsed >= crate::constants::VOLUME_WINDOW_SECONDS {
+            self.volume_window_start = now;
+            self.volume_last_hour = 0;
+        }
+
+        self.volume_last_hour = self
+            .volume_last_hour
+            .checked_add(lamports)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Open a fresh net-flow window if the current one has expired
+    /// Must run before total_sol changes so the window base is the pre-trade reserve
+    fn roll_flow_window(&mut self, now: i64) -> Result<()> {
+        let elapsed = safe_elapsed(now, self.flow_window_start)?;
+        if elapsed >= crate::constants::SELL_PRESSURE_WINDOW_SECONDS {
+            self.flow_window_start = now;
+            self.flow_window_base_sol = self.total_sol;
+            self.flow_window_inflow = 0;
+            self.flow_window_outflow = 0;
+        }
+        Ok(())
+    }
+
+    /// Record buy inflow (lamports added to the curve) in the net-flow window
+    pub fn record_inflow(&mut self, now: i64, lamports: u64) -> Result<()> {
+        self.roll_flow_window(now)?;
+        self.flow_window_inflow = self
+            .flow_window_inflow
+            .checked_add(lamports)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Record sell outflow (lamports leaving the curve) in the net-flow window
+    /// Returns true if this sale tripped the circuit breaker (buys now frozen)
+    pub fn record_outflow(&mut self, now: i64, lamports: u64) -> Result<bool> {
+        self.roll_flow_window(now)?;
+        self.flow_window_outflow = self
+            .flow_window_outflow
+            .checked_add(lamports)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+
+        if self.sell_pressure_limit_bps == 0 || self.buys_frozen {
+            return Ok(false);
+        }
+
+        // net_outflow * BPS > base * limit, in u128 to avoid overflow
+        let net_outflow = self.net_outflow();
+        let tripped = (net_outflow as u128) * crate::constants::BPS_DENOMINATOR as u128
+            > self.flow_window_base_sol as u128 * self.sell_pressure_limit_bps as u128;
+        if tripped {
+            self.buys_frozen = true;
+        }
+        Ok(tripped)
+    }
+
+    /// Net outflow (sells minus buys, floored at 0) in the current flow window
+    pub fn net_outflow(&self) -> u64 {
+        self.flow_window_outflow
+            .saturating_sub(self.flow_window_inflow)
+    }
+
+    /// Unfreeze buys after authority review and start a fresh flow window
+    pub fn reset_circuit_breaker(&mut self, now: i64) -> bool {
+        let was_frozen = self.buys_frozen;
+        self.buys_frozen = false;
+        self.flow_window_start = now;
+        self.flow_window_base_sol = self.total_sol;
+        self.flow_window_inflow = 0;
+        self.flow_window_outflow = 0;
+        was_frozen
+    }
+
+    /// Get buy volume (lamports) in the current window
+    /// Returns 0 if the window has expired without a new buy
+    pub fn current_volume(&self, now: i64) -> Result<u64> {
+        let elapsed = safe_elapsed(now, self.volume_window_start)?;
+        if elapsed >= crate::constants::VOLUME_WINDOW_SECONDS {
+            Ok(0)
+        } else {
+            Ok(self.volume_last_hour)
+        }
+    }
+
+    /// Check holders claim through claim_tokens (no external distributor)
+    pub fn claims_in_protocol(&self) -> bool {
+        self.distributor.is_none()
+    }
+
+    /// Check the launch is still trading on the bonding curve
+    /// Refund mode gets a dedicated error so buyers know the launch failed
+    pub fn check_tradeable(&self) -> Result<()> {
+        require!(!self.graduated, crate::errors::AstraError::AlreadyGraduated);
+        require!(!self.refund_mode, crate::errors::AstraError::LaunchInRefundMode);
+        Ok(())
+    }
+
+    /// Check the launch has passed its refund window (LAUNCH_DURATION_SECONDS)
+    /// From here enable_refund is open to anyone, and graduate defers to force_graduate
+    pub fn refund_eligible(&self, now: i64) -> Result<bool> {
+        Ok(now >= safe_deadline(self.created_at, crate::constants::LAUNCH_DURATION_SECONDS)?)
+    }
+
+    /// Readiness gate for lock_graduation: the graduation market cap is reached
+    /// and the launch has enough holders, so a lock can't freeze trading on a
+    /// launch graduate would reject anyway
+    pub fn check_graduation_ready(&self, sol_price_usd: u64, min_holders: u64) -> Result<()> {
+        use crate::errors::AstraError;
+
+        let market_cap_usd = self
+            .graduation_market_cap_usd(sol_price_usd)
+            .ok_or(AstraError::GraduationThresholdNotMet)?;
+        require!(
+            market_cap_usd >= crate::constants::GRADUATION_MARKET_CAP_USD,
+            AstraError::GraduationThresholdNotMet
+        );
+        require!(
+            self.meets_holder_minimum(min_holders),
+            AstraError::NotEnoughHolders
+        );
+        Ok(())
+    }
+
+    /// Phase 1 of graduation: snapshot the curve and freeze buys/sells
+    /// Trades landing after the lock are rejected, so graduate (phase 2)
+    /// sizes the pool from exactly what the operator decided on. A lapsed lock
+    /// can only be re-taken after GRADUATION_RELOCK_COOLDOWN_SECONDS.
+    pub fn lock_graduation(&mut self, now: i64) -> Result<()> {
+        use crate::constants::{GRADUATION_LOCK_SECONDS, GRADUATION_RELOCK_COOLDOWN_SECONDS};
+
+        self.check_tradeable()?;
+        require!(
+            !self.graduation_lock_active(now)?,
+            crate::errors::AstraError::GraduationLocked
+        );
+        if let Some(locked_at) = self.graduation_locked_at {
+            require!(
+                safe_elapsed(now, locked_at)?
+                    >= GRADUATION_LOCK_SECONDS + GRADUATION_RELOCK_COOLDOWN_SECONDS,
+                crate::errors::AstraError::GraduationLockCooldown
+            );
+        }
+        require!(self.has_liquidity(), crate::errors::AstraError::EmptyLaunch);
+
+        self.graduation_locked_at = Some(now);
+        self.graduation_sol_snapshot = self.total_sol;
+        self.graduation_shares_snapshot = self.total_shares;
+        Ok(())
+    }
+
+    /// Check whether a graduation lock is freezing trades
+    /// The lock lapses after GRADUATION_LOCK_SECONDS so a failed graduation
+    /// can't halt trading indefinitely
+    pub fn graduation_lock_active(&self, now: i64) -> Result<bool> {
+        match self.graduation_locked_at {
+            Some(locked_at) if !self.graduated => {
+                let elapsed = safe_elapsed(now, locked_at)?;
+                Ok(elapsed < crate::constants::GRADUATION_LOCK_SECONDS)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Phase 2 of graduation: the SOL amount to seed the pool with
+    /// Requires an active lock and an untouched curve since the snapshot
+    pub fn locked_graduation_sol(&self, now: i64) -> Result<u64> {
+        require!(
+            self.graduation_lock_active(now)?,
+            crate::errors::AstraError::GraduationNotLocked
+        );
+        require!(
+            self.total_sol == self.graduation_sol_snapshot
+                && self.total_shares == self.graduation_shares_snapshot,
+            crate::errors::AstraError::GraduationSnapshotMismatch
+        );
+        Ok(self.graduation_sol_snapshot)
+    }
+
+    /// Check if the creator's own buy/sell cooldown has elapsed
+    pub fn creator_can_trade(&self, now: i64) -> Result<bool> {
+        let cooldown_end = crate::time::safe_deadline(self.created_at, self.creator_trade_cooldown)?;
+        Ok(now >= cooldown_end)
+    }
+
+    /// Take the reentrancy guard for the rest of the instruction
+    /// Only guards against re-entry within one transaction (e.g. from a CPI);
+    /// pair with end_operation before the handler returns Ok
+    pub fn begin_operation(&mut self) -> Result<()> {
+        require!(
+            !self.operation_in_progress,
+            crate::errors::AstraError::ReentrancyDetected
+        );
+        self.operation_in_progress = true;
+        Ok(())
+    }
+
+    /// Release the reentrancy guard taken by begin_operation
+    pub fn end_operation(&mut self) {
+        self.operation_in_progress = false;
+    }
+
+    /// Trading start for a launch created at `created_at` with `delay` seconds
+    /// of warm-up (0..=MAX_TRADING_START_DELAY_SECONDS; 0 = None, open immediately)
+    pub fn trading_start_after(created_at: i64, delay: i64) -> Result<Option<i64>> {
+        require!(
+            (0..=crate::constants::MAX_TRADING_START_DELAY_SECONDS).contains(&delay),
+            crate::errors::AstraError::InvalidTradingDelay
+        );
+        if delay == 0 {
+            return Ok(None);
+        }
+        Ok(Some(safe_deadline(created_at, delay)?))
+    }
+
+    /// Check public buys are open (trading_starts_at reached)
+    pub fn check_trading_started(&self, now: i64) -> Result<()> {
+        if let Some(starts_at) = self.trading_starts_at {
+            require!(
+                now >= starts_at,
+                crate::errors::AstraError::TradingNotStarted
+            );
+        }
+        Ok(())
+    }
+
+    /// Clear a stuck reentrancy guard
+    /// Returns whether the flag was actually set
+    pub fn reset_operation_flag(&mut self) -> bool {
+        let was_set = self.operation_in_progress;
+        self.operation_in_progress = false;
+        was_set
+    }
+
+    /// Record a processed refund
+    /// Returns Some(true) only for the refund that takes positions_remaining
+    /// to zero, so RefundsComplete is emitted once
+    pub fn record_refund(&mut self, amount: u64) -> Option<bool> {
+        self.total_refunded = self.total_refunded.checked_add(amount)?;
+        let was_outstanding = self.positions_remaining > 0;
+        self.positions_remaining = self.positions_remaining.saturating_sub(1);
+        Some(was_outstanding && self.positions_remaining == 0)
+    }
+
+    /// SOL a position is owed in refund mode, before any under-funding haircut
+    ///
+    /// The creator position also carries the seed: locked_shares backed by
+    /// creator_seed_sol (its sol_basis only covers shares bought later).
+    pub fn refund_entitlement(&self, position: &crate::state::Position) -> Result<u64> {
+        let seed_sol = if position.user == self.creator {
+            self.creator_seed_sol
+        } else {
+            0
+        };
+        position
+            .sol_basis
+            .checked_add(seed_sol)
+            .ok_or_else(|| crate::errors::AstraError::MathOverflow.into())
+    }
+
+    /// Exact refund a position receives given the launch's withdrawable lamports
+    ///
+    /// Booster SOL in the PDA belongs to the booster and isn't counted.
+    /// Outstanding entitlements sum to total_sol. If the PDA holds less than
+    /// that, every position takes the same pro-rata haircut
+    /// (entitlement * available / total_sol), so the shortfall is shared
+    /// rather than landing on whoever claims last. Shared by claim/push_refund
+    /// and preview_refund so the preview always matches the claim.
+    ///
+    /// PRECEDENCE: none. The creator's seed SOL is just part of its
+    /// entitlement and ranks pari passu with holder basis - it is neither
+    /// refunded first nor subordinated, whatever order the claims land in.
+    pub fn refund_quote(
+        &self,
+        position: &crate::state::Position,
+        available_lamports: u64,
+    ) -> Result<RefundQuote> {
+        let entitlement = self.refund_entitlement(position)?;
+        let available_lamports = available_lamports.saturating_sub(self.boosted_sol);
+        if available_lamports >= self.total_sol {
+            return Ok(RefundQuote {
+                entitlement,
+                amount: entitlement,
+                is_proportional: false,
+            });
+        }
+
+        let amount = (entitlement as u128)
+            .checked_mul(available_lamports as u128)
+            .and_then(|v| v.checked_div(self.total_sol as u128))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        Ok(RefundQuote {
+            entitlement,
+            amount,
+            is_proportional: true,
+        })
+    }
+
+    /// Compare summed position balances against the launch counters
+    ///
+    /// total_shares should equal the sum of shares + locked_shares, and
+    /// total_sol the sum of sol_basis plus the creator seed's SOL (which is
+    /// tracked on the launch, not the creator position).
+    pub fn reconcile(
+        &self,
+        launch: Pubkey,
+        positions: &[crate::state::Position],
+        now: i64,
+    ) -> Result<crate::events::LaunchReconciled> {
+        let mut summed_shares: u64 = 0;
+        let mut summed_sol: u64 = self.creator_seed_sol;
+        for position in positions {
+            summed_shares = summed_shares
+                .checked_add(position.shares)
+                .and_then(|v| v.checked_add(position.locked_shares))
+                .ok_or(crate::errors::AstraError::MathOverflow)?;
+            summed_sol = summed_sol
+                .checked_add(position.sol_basis)
+                .ok_or(crate::errors::AstraError::MathOverflow)?;
+        }
+
+        Ok(crate::events::LaunchReconciled {
+            launch,
+            positions_counted: positions.len() as u64,
+            position_count: self.position_count,
+            summed_shares,
+            total_shares: self.total_shares,
+            summed_sol,
+            total_sol: self.total_sol,
+            shares_match: summed_shares == self.total_shares,
+            sol_match: summed_sol == self.total_sol,
+            timestamp: now,
+        })
+    }
+
+    /// Credit the creation seed to the creator position
+    ///
+    /// A vesting seed is locked (locked_shares, tracked in creator_seed_*) and
+    /// released by claim_vesting. A non-vesting seed is plain liquidity: it
+    /// lands in shares/sol_basis like any buy and creator_seed_* stay zero,
+    /// so nothing vests and it can be sold or claimed right away.
+    pub fn assign_seed(
+        &mut self,
+        position: &mut crate::state::Position,
+        shares: u64,
+        net_deposit: u64,
+        seed_vests: bool,
+    ) {
+        if seed_vests {
+            self.creator_seed_shares = shares;
+            self.creator_seed_sol = net_deposit;
+            position.shares = 0; // Will be set after vesting
+            position.sol_basis = 0;
+            position.locked_shares = shares;
+        } else {
+            self.creator_seed_shares = 0;
+            self.creator_seed_sol = 0;
+            position.shares = shares;
+            position.sol_basis = net_deposit;
+            position.locked_shares = 0;
+        }
+    }
+
+    /// Lamports missing for every outstanding position to get its full entitlement
+    pub fn refund_shortfall(&self, available_lamports: u64) -> u64 {
+        self.total_sol
+            .saturating_sub(available_lamports.saturating_sub(self.boosted_sol))
+    }
+
+    /// Add booster SOL for the graduation pool
+    /// One booster per launch: the first boost (at least MIN_BOOST_LAMPORTS)
+    /// claims the slot, later boosts must match
+    pub fn add_boost(&mut self, booster: Pubkey, lamports: u64, now: i64) -> Result<()> {
+        use crate::errors::AstraError;
+
+        require!(lamports > 0, AstraError::InvalidBoostAmount);
+        require!(!self.graduated, AstraError::AlreadyGraduated);
+        require!(!self.refund_mode, AstraError::RefundModeActive);
+        // Pool size is fixed from the lock to graduate
+        require!(
+            !self.graduation_lock_active(now)?,
+            AstraError::GraduationLocked
+        );
+        require!(
+            self.booster.is_none_or(|existing| existing == booster),
+            AstraError::BoosterMismatch
+        );
+        require!(
+            self.booster.is_some() || lamports >= crate::constants::MIN_BOOST_LAMPORTS,
+            AstraError::InvalidBoostAmount
+        );
+
+        self.boosted_sol = self
+            .boosted_sol
+            .checked_add(lamports)
+            .ok_or(AstraError::MathOverflow)?;
+        self.booster = Some(booster);
+        Ok(())
+    }
+
+    /// Release the booster's SOL on a refunding launch; returns the lamports owed
+    pub fn take_boost_refund(&mut self, booster: &Pubkey) -> Result<u64> {
+        use crate::errors::AstraError;
+
+        require!(self.refund_mode, AstraError::RefundModeNotActive);
+        require!(self.booster == Some(*booster), AstraError::BoosterMismatch);
+        let amount = std::mem::take(&mut self.boosted_sol);
+        require!(amount > 0, AstraError::InvalidBoostAmount);
+        Ok(amount)
+    }
+
+    /// SOL side of the graduation pool: the curve's SOL plus any booster SOL
+    pub fn graduation_pool_sol(&self, curve_sol: u64) -> Result<u64> {
+        curve_sol
+            .checked_add(self.boosted_sol)
+            .ok_or_else(|| crate::errors::AstraError::MathOverflow.into())
+    }
+
+    /// Settle a refund-mode position against the curve and count it processed
+    ///
+    /// The full entitlement (creator seed included) leaves total_sol, so
+    /// total_shares/total_sol only reach zero - and the launch only becomes
+    /// closable - once the creator has been refunded too.
+    ///
+    /// Returns the quote paid and whether this was the last outstanding position
+    pub fn settle_refund(
+        &mut self,
+        position: &crate::state::Position,
+        available_lamports: u64,
+    ) -> Result<(RefundQuote, bool)> {
+        require!(
+            self.refund_mode,
+            crate::errors::AstraError::RefundModeNotActive
+        );
+        let quote = self.refund_quote(position, available_lamports)?;
+        let shares = position
+            .shares
+            .checked_add(position.locked_shares)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+
+        self.total_sol = self.total_sol.saturating_sub(quote.entitlement);
+        self.total_shares = self.total_shares.saturating_sub(shares);
+        if position.is_holder() {
+            self.holder_count = self.holder_count.saturating_sub(1);
+        }
+
+        let complete = self
+            .record_refund(quote.amount)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        Ok((quote, complete))
+    }
+
+    /// Check a refunded launch can be closed
+    /// Every position (creator included) settled and the curve fully drained
+    pub fn can_close(&self) -> bool {
+        self.refund_mode
+            && self.positions_remaining == 0
+            && self.total_shares == 0
+            && self.total_sol == 0
+            && self.boosted_sol == 0
+    }
+
+    /// Transition Active -> Refunding
+    /// Rejects once graduated (graduation took precedence) or already refunding
+    pub fn enter_refund_mode(&mut self, now: i64) -> Result<()> {
+        require!(!self.graduated, crate::errors::AstraError::AlreadyGraduated);
+        require!(
+            !self.refund_mode,
+            crate::errors::AstraError::RefundModeAlreadyActive
+        );
+
+        self.refund_mode = true;
+        self.refund_enabled_at = Some(now);
+        self.positions_remaining = self.position_count;
+        Ok(())
+    }
+
+    /// Record graduation state (Active -> Graduated)
+    /// Snapshots total_shares for proportional token distribution and starts vesting
+    /// Rejects once refunding (refund took precedence) or already graduated
+    pub fn mark_graduated(
+        &mut self,
+        now: i64,
+        token_mint: Pubkey,
+        pool_address: Pubkey,
+        vault: Pubkey,
+    ) -> Result<()> {
+        require!(!self.graduated, crate::errors::AstraError::AlreadyGraduated);
+        require!(!self.refund_mode, crate::errors::AstraError::RefundModeActive);
+
+        self.graduated = true;
+        self.graduated_at = Some(now);
+        self.vesting_start = Some(now);
+        self.token_mint = Some(token_mint);
+        self.pool_address = Some(pool_address);
+        self.vault = Some(vault);
+        self.total_shares_at_graduation = self.total_shares;
+        self.holders_at_graduation = self.holder_count;
+        Ok(())
+    }
+
+    /// Store the opening pool reserves (once, after graduation)
+    pub fn record_graduation_outcome(&mut self, record: GraduationRecord) -> Result<()> {
+        require!(self.graduated, crate::errors::AstraError::NotGraduated);
+        require!(
+            self.graduation_record.is_none(),
+            crate::errors::AstraError::GraduationAlreadyRecorded
+        );
+        self.graduation_record = Some(record);
+        Ok(())
+    }
+
+    /// Migration: set holder_count on a launch that predates holder tracking
+    /// Can only run once per launch; returns the previous (untracked) count
+    pub fn backfill_holder_count(&mut self, holder_count: u64) -> Result<u64> {
+        require!(
+            !self.holder_count_backfilled,
+            crate::errors::AstraError::HolderCountAlreadyBackfilled
+        );
+        require!(!self.graduated, crate::errors::AstraError::AlreadyGraduated);
+
+        let previous = self.holder_count;
+        self.holder_count = holder_count;
+        self.holder_count_backfilled = true;
+        Ok(previous)
+    }
+
+    /// Claim a fee-free slot for a buyer's first buy (new position)
+    /// Returns true if this buy's fees are waived
+    pub fn take_fee_free_slot(&mut self, is_new_position: bool) -> bool {
+        if !is_new_position || self.fee_free_buys_used >= self.free_fee_buyer_count {
+            return false;
+        }
+        self.fee_free_buys_used += 1;
+        true
+    }
+
+    /// Count a position that is becoming a holder, enforcing max_holders
+    /// Existing holders topping up never call this, so they can always buy more
+    pub fn admit_holder(&mut self) -> Result<()> {
+        if let Some(max_holders) = self.max_holders {
+            require!(
+                self.holder_count < max_holders,
+                crate::errors::AstraError::HolderCapReached
+            );
+        }
+        self.holder_count = self
+            .holder_count
+            .checked_add(1)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Check the holder-count graduation gate
+    pub fn meets_holder_minimum(&self, min_holders: u64) -> bool {
+        self.holder_count >= min_holders
+    }
+
+    /// Check the creator concentration graduation gate
+    /// creator_shares (seed + bought) must be <= max_bps of total_shares; 0 disables
+    pub fn creator_within_concentration_cap(&self, creator_shares: u64, max_bps: u64) -> bool {
+        self.shares_within_cap(creator_shares, max_bps)
+    }
+
+    /// Raise largest_holder_shares to a buyer's new position size
+    /// The creator's position is skipped - it has its own cap
+    pub fn record_holder_shares(&mut self, holder: &Pubkey, position_shares: u64) {
+        if *holder != self.creator {
+            self.largest_holder_shares = self.largest_holder_shares.max(position_shares);
+        }
+    }
+
+    /// Reset largest_holder_shares to the largest buyer position right now
+    ///
+    /// `positions` must be every position of the launch (position_count of
+    /// them, none is closed before graduation) sorted by user, which also rules
+    /// out duplicates. Lets a launch whose whale sold down prove it is back
+    /// under the cap; the largest position still counts, never the mark alone.
+    pub fn prove_largest_holder(
+        &mut self,
+        launch: &Pubkey,
+        positions: &[crate::state::Position],
+    ) -> Result<u64> {
+        self.check_position_set(launch, positions)?;
+        let largest = positions
+            .iter()
+            .filter(|position| position.user != self.creator)
+            .map(|position| position.shares)
+            .max()
+            .unwrap_or(0);
+
+        self.largest_holder_shares = largest;
+        Ok(largest)
+    }
+
+    /// Migration: backfill holder_count by counting every position of this launch
+    /// Same one-time latch as backfill_holder_count; returns (previous, counted)
+    pub fn recount_holders(
+        &mut self,
+        launch: &Pubkey,
+        positions: &[crate::state::Position],
+    ) -> Result<(u64, u64)> {
+        self.check_position_set(launch, positions)?;
+        let holders = positions
+            .iter()
+            .filter(|position| position.is_holder())
+            .count() as u64;
+
+        let previous = self.backfill_holder_count(holders)?;
+        Ok((previous, holders))
+    }
+
+    /// Every position of this launch, once each (sorted by user, so duplicates show)
+    fn check_position_set(
+        &self,
+        launch: &Pubkey,
+        positions: &[crate::state::Position],
+    ) -> Result<()> {
+        use crate::errors::AstraError;
+
+        require!(
+            positions.len() as u64 == self.position_count,
+            AstraError::IncompleteHolderSet
+        );
+        let mut previous: Option<Pubkey> = None;
+        for position in positions {
+            require!(
+                position.launch == *launch && previous.is_none_or(|user| user < position.user),
+                AstraError::InvalidReconciliationAccounts
+            );
+            previous = Some(position.user);
+        }
+        Ok(())
+    }
+
+    /// Check the whale graduation gate
+    /// largest_holder_shares must be <= max_bps of total_shares; 0 disables
+    pub fn largest_holder_within_cap(&self, max_bps: u64) -> bool {
+        self.shares_within_cap(self.largest_holder_shares, max_bps)
+    }
+
+    fn shares_within_cap(&self, shares: u64, max_bps: u64) -> bool {
+        if max_bps == 0 {
+            return true;
+        }
+
+        (shares as u128) * crate::constants::BPS_DENOMINATOR as u128
+            <= (self.total_shares as u128) * max_bps as u128
+    }
+
+    /// Check if a third party (janitor) may claim tokens on a holder's behalf
+    /// Only allowed once THIRD_PARTY_CLAIM_GRACE_SECONDS have passed since graduation
+    pub fn third_party_claim_open(&self, now: i64) -> Result<bool> {
+        match self.graduated_at {
+            Some(graduated_at) => Ok(safe_elapsed(now, graduated_at)?
+                >= crate::constants::THIRD_PARTY_CLAIM_GRACE_SECONDS),
+            None => Ok(false),
+        }
+    }
+
+    /// Upper bound on shares `net_sol` can buy at any reachable curve state
+    /// Supply never drops below the locked creator seed pre-graduation, and the
+    /// curve is cheapest at the lowest supply, so this is the best possible fill
+    pub fn max_shares_for(&self, net_sol: u64) -> Result<u64> {
+        crate::curve::buy_return(net_sol, self.creator_seed_shares)
+    }
+
+    /// Check a buy's slippage bound against the actual fill
+    /// Unreachable bounds get a distinct error so clients can tell them from price movement
+    pub fn check_min_shares_out(
+        &self,
+        net_sol: u64,
+        shares: u64,
+        min_shares_out: u64,
+    ) -> Result<()> {
+        use crate::errors::AstraError;
+
+        require!(
+            min_shares_out <= self.max_shares_for(net_sol)?,
+            AstraError::MinSharesOutUnreachable
+        );
+        require!(shares >= min_shares_out, AstraError::SlippageExceeded);
+        Ok(())
+    }
+
+    /// Creator lockup terms (None until graduation starts vesting)
+    pub fn vesting_schedule(&self) -> Option<VestingSchedule> {
+        let duration_seconds = crate::constants::VESTING_DURATION_SECONDS;
+        let cliff_seconds = match self.vesting_mode {
+            VestingMode::Linear => self.vesting_cliff_seconds,
+            VestingMode::Cliff => duration_seconds,
+        };
+
+        Some(VestingSchedule {
+            start: self.vesting_start?,
+            cliff_seconds,
+            duration_seconds,
+            mode: self.vesting_mode,
+        })
+    }
+
+    /// Total seed shares vested at `now` (including already-claimed shares)
+    /// Deterministic integer math: seed_shares * elapsed / duration, zero before the cliff
+    pub fn vested_seed_shares(&self, now: i64) -> Result<u64> {
+        let schedule = self
+            .vesting_schedule()
+            .ok_or(crate::errors::AstraError::NotGraduated)?;
+        let elapsed = safe_elapsed(now, schedule.start)?;
+
+        if elapsed < schedule.cliff_seconds {
+            return Ok(0);
+        }
+
+        // Using u128 to prevent overflow
+        let capped_elapsed = elapsed.min(schedule.duration_seconds);
+        let vested = (self.creator_seed_shares as u128)
+            .checked_mul(capped_elapsed as u128)
+            .ok_or(crate::errors::AstraError::MathOverflow)?
+            .checked_div(schedule.duration_seconds as u128)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+
+        Ok(vested as u64)
+    }
+
+    /// Spend one metadata update; returns how many remain
+    /// WHY: Bounds bait-and-switch renames while leaving room for genuine fixes
+    pub fn consume_metadata_update(&mut self) -> Result<u8> {
+        use crate::constants::MAX_METADATA_UPDATES;
+
+        require!(
+            self.metadata_updates_used < MAX_METADATA_UPDATES,
+            crate::errors::AstraError::MetadataUpdatesExhausted
+        );
+        self.metadata_updates_used += 1;
+        Ok(MAX_METADATA_UPDATES - self.metadata_updates_used)
+    }
+
+    /// Replace the URI (and optionally the name) before graduation
+    /// The symbol stays locked so a launch can't be re-labelled as another
+    /// ticker. The hash and size commitments describe the old content, so they
+    /// are replaced too. Spends one metadata update; returns the previous URI.
+    pub fn update_metadata(
+        &mut self,
+        name: Option<String>,
+        uri: String,
+        metadata_hash: Option<[u8; 32]>,
+        max_metadata_bytes: Option<u32>,
+    ) -> Result<String> {
+        require!(!self.graduated, crate::errors::AstraError::AlreadyGraduated);
+        if let Some(name) = &name {
+            Self::validate_name(name)?;
+        }
+        Self::validate_uri(&uri)?;
+        Self::validate_max_metadata_bytes(max_metadata_bytes)?;
+        self.consume_metadata_update()?;
+
+        if let Some(name) = name {
+            self.name = name;
+        }
+        self.metadata_hash = metadata_hash;
+        self.max_metadata_bytes = max_metadata_bytes;
+        Ok(std::mem::replace(&mut self.uri, uri))
+    }
+
+    /// Check fetched metadata against the committed hash
+    /// None if the launch made no commitment (content can't be verified)
+    pub fn metadata_matches(&self, content_hash: &[u8; 32]) -> Option<bool> {
+        self.metadata_hash.map(|hash| hash == *content_hash)
+    }
+
+    /// Seed shares claim_vesting can release at `now`
+    ///
+    /// Rejects claims below MIN_VESTING_CLAIM_BPS of the seed with ClaimTooSmall,
+    /// except the one that releases everything still outstanding.
+    pub fn claimable_seed_shares(&self, now: i64) -> Result<u64> {
+        use crate::constants::{BPS_DENOMINATOR, MIN_VESTING_CLAIM_BPS};
+        use crate::errors::AstraError;
+
+        let remaining_seed = self.unvested_seed_shares();
+        require!(remaining_seed > 0, AstraError::NoSharesToClaim);
+
+        let claimable = self
+            .vested_seed_shares(now)?
+            .checked_sub(self.creator_claimed_shares)
+            .ok_or(AstraError::MathOverflow)?;
+        require!(claimable > 0, AstraError::NoSharesToClaim);
+
+        let min_claim = (self.creator_seed_shares as u128 * MIN_VESTING_CLAIM_BPS as u128
+            / BPS_DENOMINATOR as u128) as u64;
+        require!(
+            claimable >= min_claim || claimable == remaining_seed,
+            AstraError::ClaimTooSmall
+        );
+
+        Ok(claimable)
+    }
+
+    /// Move the seed shares vested at `now` from locked_shares to shares
+    /// Shares only change bucket - position and launch totals are unchanged
+    pub fn release_vested_shares(
+        &mut self,
+        position: &mut crate::state::Position,
+        now: i64,
+    ) -> Result<u64> {
+        use crate::errors::AstraError;
+
+        let claimable = self.claimable_seed_shares(now)?;
+
+        // Never release more than is actually locked (safety check)
+        require!(
+            claimable <= position.locked_shares,
+            AstraError::InvalidCalculation
+        );
+
+        position.locked_shares -= claimable;
+        position.shares = position
+            .shares
+            .checked_add(claimable)
+            .ok_or(AstraError::MathOverflow)?;
+        position.vested_shares_claimed = position
+            .vested_shares_claimed
+            .checked_add(claimable)
+            .ok_or(AstraError::MathOverflow)?;
+        position.last_updated_at = now;
+
+        self.creator_claimed_shares = self
+            .creator_claimed_shares
+            .checked_add(claimable)
+            .ok_or(AstraError::MathOverflow)?;
+        Ok(claimable)
+    }
+
+    /// Seed shares still locked: neither vested into the position nor slashed
+    pub fn unvested_seed_shares(&self) -> u64 {
+        self.creator_seed_shares
+            .saturating_sub(self.creator_claimed_shares)
+            .saturating_sub(self.creator_slashed_shares)
+    }
+
+    /// Slash the creator's unvested seed (anti-rug enforcement, graduated launches)
+    ///
+    /// INVARIANT: only locked_shares are touched. Shares already vested into
+    /// position.shares (and vested_shares_claimed) are earned and can never be
+    /// taken back, however late the slash lands.
+    ///
+    /// The slashed shares are settled against the graduation snapshot like a
+    /// claim, so per-share payouts don't move and the last holder claim still
+    /// takes the remainder; slash_creator_bond burns the returned tokens.
+    /// Returns (shares slashed, holder tokens to burn).
+    pub fn slash_unvested_seed(
+        &mut self,
+        position: &mut crate::state::Position,
+    ) -> Result<(u64, u64)> {
+        use crate::errors::AstraError;
+
+        require!(self.graduated, AstraError::NotGraduated);
+        let slashed = self.unvested_seed_shares();
+        require!(slashed > 0, AstraError::NoSharesToClaim);
+        require!(
+            position.locked_shares == slashed,
+            AstraError::InvalidCalculation
+        );
+
+        let tokens = self.take_token_claim(slashed, None)?;
+        position.locked_shares = 0;
+        self.creator_slashed_shares = self
+            .creator_slashed_shares
+            .checked_add(slashed)
+            .ok_or(AstraError::MathOverflow)?;
+        Ok((slashed, tokens))
+    }
+
+    /// Check if the creator seed has fully vested (vesting_start + duration reached)
+    pub fn seed_fully_vested(&self, now: i64) -> Result<bool> {
+        match self.vesting_start {
+            Some(vesting_start) => Ok(safe_elapsed(now, vesting_start)?
+                >= crate::constants::VESTING_DURATION_SECONDS),
+            None => Ok(false),
+        }
+    }
+
+    /// Validate a graduation burn: 0..=MAX_BURN_BPS
+    pub fn validate_burn_bps(burn_bps: u64) -> Result<()> {
+        require!(
+            burn_bps <= crate::constants::MAX_BURN_BPS,
+            crate::errors::AstraError::InvalidBurnBps
+        );
+        Ok(())
+    }
+
+    /// Split of the minted supply at graduation, after the optional burn
+    /// burn_bps comes off the holder and LP allocations alike, so the pool
+    /// price per share is unchanged and holders keep their 80/20 ratio
+    pub fn token_allocation(&self) -> Result<TokenAllocation> {
+        use crate::constants::{
+            BPS_DENOMINATOR, TOKENS_FOR_HOLDERS, TOKENS_FOR_LP, TOKEN_DECIMALS_SCALE,
+            TOTAL_SUPPLY_WITH_DECIMALS,
+        };
+
+        let after_burn = |tokens: u64| -> Result<u64> {
+            let burned = (tokens as u128 * self.burn_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+            tokens
+                .checked_sub(burned)
+                .ok_or_else(|| crate::errors::AstraError::MathOverflow.into())
+        };
+        let holders = after_burn(TOKENS_FOR_HOLDERS * TOKEN_DECIMALS_SCALE)?;
+        let lp = after_burn(TOKENS_FOR_LP * TOKEN_DECIMALS_SCALE)?;
+
+        Ok(TokenAllocation {
+            holders,
+            lp,
+            burned: TOTAL_SUPPLY_WITH_DECIMALS - holders - lp,
+        })
+    }
+
+    /// Calculate the token allocation (with decimals) for a share balance
+    /// Formula: tokens = shares * holder pool / total_shares_at_graduation
+    /// (holder pool = TOKENS_FOR_HOLDERS less any graduation burn)
+    /// Returns None before graduation (no snapshot) or on overflow
+    pub fn holder_token_amount(&self, shares: u64) -> Option<u64> {
+        if self.total_shares_at_graduation == 0 {
             return None;
         }
-        
-        // market_cap = total_sol * sol_price_usd / 1e9 (lamports to SOL conversion)
-        let market_cap = (self.total_sol as u128)
+
+        let tokens_for_holders = self.token_allocation().ok()?.holders as u128;
+        let amount = (shares as u128)
+            .checked_mul(tokens_for_holders)?
+            .checked_div(self.total_shares_at_graduation as u128)?;
+
+        u64::try_from(amount).ok()
+    }
+
+    /// Token base units each graduation share is worth (holder pool / snapshot)
+    /// Rounded down; a claim multiplies before dividing, so it can exceed
+    /// shares * tokens_per_share by less than `shares` base units
+    /// Returns None before graduation
+    pub fn tokens_per_share(&self) -> Option<u64> {
+        if !self.graduated || self.total_shares_at_graduation == 0 {
+            return None;
+        }
+        let holder_pool = self.token_allocation().ok()?.holders;
+        holder_pool.checked_div(self.total_shares_at_graduation)
+    }
+
+    /// Token amount claim_tokens pays for `shares`, checked against `min_tokens_out`
+    /// Guards a claimant against the share snapshot changing after simulation
+    ///
+    /// Per-claim amounts round down, so the claim that brings the claimed
+    /// shares up to the snapshot takes whatever is left of the holder
+    /// allocation - the claims always sum to exactly the holder pool.
+    pub fn claim_token_amount(&self, shares: u64, min_tokens_out: Option<u64>) -> Result<u64> {
+        use crate::errors::AstraError;
+
+        require!(self.graduated, AstraError::NotGraduated);
+
+        let claimed_after = self
+            .holder_shares_claimed
+            .checked_add(shares)
+            .ok_or(AstraError::MathOverflow)?;
+        require!(
+            claimed_after <= self.total_shares_at_graduation,
+            AstraError::InvalidCalculation
+        );
+
+        let amount = if claimed_after == self.total_shares_at_graduation && shares > 0 {
+            self.token_allocation()?
+                .holders
+                .checked_sub(self.holder_tokens_claimed)
+                .ok_or(AstraError::MathOverflow)?
+        } else {
+            self.holder_token_amount(shares)
+                .ok_or(AstraError::InvalidCalculation)?
+        };
+        require!(amount > 0, AstraError::NoSharesToClaim);
+        if let Some(min_tokens_out) = min_tokens_out {
+            require!(amount >= min_tokens_out, AstraError::SlippageExceeded);
+        }
+        Ok(amount)
+    }
+
+    /// Pay out a token claim for `shares` and record it against the snapshot
+    /// Returns the token amount to transfer (see claim_token_amount)
+    pub fn take_token_claim(&mut self, shares: u64, min_tokens_out: Option<u64>) -> Result<u64> {
+        let amount = self.claim_token_amount(shares, min_tokens_out)?;
+        self.holder_shares_claimed += shares;
+        self.holder_tokens_claimed = self
+            .holder_tokens_claimed
+            .checked_add(amount)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        Ok(amount)
+    }
+
+    /// Calculate current market cap in USD
+    /// Returns None if price is not available (0)
+    ///
+    /// NOTE: This is the SOL raised valued in USD, not a token market cap.
+    /// See `fully_diluted_market_cap_usd` for the curve-implied value.
+    pub fn market_cap_usd(&self, sol_price_usd: u64) -> Option<u64> {
+        if sol_price_usd == 0 {
+            return None;
+        }
+        
+        // market_cap = total_sol * sol_price_usd / 1e9 (lamports to SOL conversion)
+        let market_cap = (self.total_sol as u128)
             .checked_mul(sol_price_usd as u128)?
-            .checked_div(1_000_000_000)?;
+            .checked_div(crate::constants::LAMPORTS_PER_SOL as u128)?;
             
         Some(market_cap as u64)
     }
+
+    /// Calculate fully-diluted market cap in USD
+    /// Values all issued shares at the curve's current spot price
+    /// Returns None if price is not available (0) or on overflow
+    ///
+    /// On the quadratic curve this is ~2× `market_cap_usd`, since SOL raised
+    /// is the area under the price line while FDV is the current price × supply.
+    pub fn fully_diluted_market_cap_usd(&self, sol_price_usd: u64) -> Option<u64> {
+        if sol_price_usd == 0 {
+            return None;
+        }
+
+        let spot_price = crate::curve::spot_price(self.total_shares).ok()?;
+
+        // fdv = spot_price * total_shares * sol_price_usd / 1e9
+        let market_cap = (spot_price as u128)
+            .checked_mul(self.total_shares as u128)?
+            .checked_mul(sol_price_usd as u128)?
+            .checked_div(crate::constants::LAMPORTS_PER_SOL as u128)?;
+
+        market_cap.try_into().ok()
+    }
+
+    /// Market cap used for graduation readiness
+    /// Selected by GRADUATION_USES_FULLY_DILUTED_MARKET_CAP
+    pub fn graduation_market_cap_usd(&self, sol_price_usd: u64) -> Option<u64> {
+        if sol_price_usd == 0 {
+            return None;
+        }
+
+        // Degenerate launch: no progress toward graduation, whatever the metric
+        if !self.has_liquidity() {
+            return Some(0);
+        }
+
+        if crate::constants::GRADUATION_USES_FULLY_DILUTED_MARKET_CAP {
+            self.fully_diluted_market_cap_usd(sol_price_usd)
+        } else {
+            self.market_cap_usd(sol_price_usd)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{
+        GRADUATION_LOCK_SECONDS, GRADUATION_MAX_CONCENTRATION_BPS, GRADUATION_MIN_HOLDERS,
+        GRADUATION_PRICE_SCALE, GRADUATION_RELOCK_COOLDOWN_SECONDS,
+        SELL_PRESSURE_WINDOW_SECONDS, THIRD_PARTY_CLAIM_GRACE_SECONDS, TOKENS_FOR_HOLDERS,
+        TOKENS_FOR_LP, TOKEN_DECIMALS_SCALE, VESTING_DURATION_SECONDS, VOLUME_WINDOW_SECONDS,
+    };
+    use crate::errors::AstraError;
+    use crate::state::Position;
+
+    const GRADUATED_AT: i64 = 1_700_000_000;
+
+    fn graduated_launch() -> Launch {
+        Launch {
+            graduated: true,
+            graduated_at: Some(GRADUATED_AT),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_third_party_claim_blocked_in_grace() {
+        let launch = graduated_launch();
+        assert!(!launch.third_party_claim_open(GRADUATED_AT).unwrap());
+        assert!(!launch
+            .third_party_claim_open(GRADUATED_AT + THIRD_PARTY_CLAIM_GRACE_SECONDS - 1)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_third_party_claim_open_after_grace() {
+        let launch = graduated_launch();
+        assert!(launch
+            .third_party_claim_open(GRADUATED_AT + THIRD_PARTY_CLAIM_GRACE_SECONDS)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_fully_diluted_vs_sol_raised_market_cap() {
+        let supply = 500_000_000u64;
+        let launch = Launch {
+            total_shares: supply,
+            total_sol: crate::curve::buy_quote(supply, 0).unwrap(),
+            ..Default::default()
+        };
+
+        let sol_raised = launch.market_cap_usd(200).unwrap();
+        let fdv = launch.fully_diluted_market_cap_usd(200).unwrap();
+
+        // FDV on a quadratic curve is ~2x the SOL raised (within rounding)
+        assert!(fdv > sol_raised);
+        assert!(fdv.abs_diff(sol_raised * 2) <= fdv / 100);
+    }
+
+    #[test]
+    fn test_market_caps_need_price() {
+        let launch = Launch {
+            total_shares: 1_000_000,
+            total_sol: 1_000_000_000,
+            ..Default::default()
+        };
+        assert!(launch.market_cap_usd(0).is_none());
+        assert!(launch.fully_diluted_market_cap_usd(0).is_none());
+    }
+
+    #[test]
+    fn test_oversized_creator_seed_blocks_until_diluted() {
+        let cap_bps = crate::constants::DEFAULT_MAX_CREATOR_CONCENTRATION_BPS;
+        let seed = 1_000_000;
+        let mut launch = Launch {
+            creator_seed_shares: seed,
+            total_shares: seed * 2,
+            ..Default::default()
+        };
+
+        // Creator holds 50% - blocked
+        assert!(!launch.creator_within_concentration_cap(seed, cap_bps));
+
+        // Others buy until the creator is diluted to exactly the cap (20%)
+        launch.total_shares = seed * 5;
+        assert!(launch.creator_within_concentration_cap(seed, cap_bps));
+
+        // Creator's own buys count toward the cap
+        assert!(!launch.creator_within_concentration_cap(seed + 1, cap_bps));
+
+        // 0 disables the gate
+        assert!(launch.creator_within_concentration_cap(seed * 5, 0));
+    }
+
+    #[test]
+    fn test_refund_eligibility_boundary() {
+        use crate::constants::LAUNCH_DURATION_SECONDS;
+
+        let launch = Launch {
+            created_at: GRADUATED_AT,
+            ..Default::default()
+        };
+        let deadline = GRADUATED_AT + LAUNCH_DURATION_SECONDS;
+
+        // A late buy crossing the threshold can still graduate normally just before...
+        assert!(!launch.refund_eligible(deadline - 1).unwrap());
+        // ...but from the refund deadline on, graduate needs the force path
+        assert!(launch.refund_eligible(deadline).unwrap());
+        assert!(launch.refund_eligible(deadline + 1).unwrap());
+    }
+
+    #[test]
+    fn test_refund_first_blocks_graduation() {
+        let mut launch = Launch {
+            total_shares: 1_000_000,
+            total_sol: 1_000_000_000,
+            ..Default::default()
+        };
+
+        launch.enter_refund_mode(GRADUATED_AT).unwrap();
+        let err = launch
+            .mark_graduated(
+                GRADUATED_AT,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            )
+            .unwrap_err();
+
+        assert_eq!(err, AstraError::RefundModeActive.into());
+        assert!(launch.refund_mode && !launch.graduated);
+    }
+
+    #[test]
+    fn test_graduation_first_blocks_refund() {
+        let mut launch = Launch {
+            total_shares: 1_000_000,
+            total_sol: 1_000_000_000,
+            ..Default::default()
+        };
+
+        launch
+            .mark_graduated(
+                GRADUATED_AT,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            )
+            .unwrap();
+        let err = launch.enter_refund_mode(GRADUATED_AT).unwrap_err();
+
+        assert_eq!(err, AstraError::AlreadyGraduated.into());
+        assert!(launch.graduated && !launch.refund_mode);
+    }
+
+    #[test]
+    fn test_zero_supply_launch_cannot_graduate() {
+        // Shares without SOL (or SOL without shares) is an inconsistent curve state
+        let zero_sol = Launch {
+            total_shares: 100_000_000,
+            ..Default::default()
+        };
+        let zero_shares = Launch {
+            total_sol: 1_000_000_000,
+            ..Default::default()
+        };
+
+        for launch in [&zero_sol, &zero_shares, &Launch::default()] {
+            assert!(!launch.has_liquidity());
+            assert!(!launch.can_graduate());
+            assert_eq!(launch.graduation_market_cap_usd(200), Some(0));
+            assert_eq!(launch.graduation_market_cap_usd(0), None);
+        }
+
+        // FDV of a zero-SOL launch would otherwise look non-zero
+        assert!(zero_sol.fully_diluted_market_cap_usd(200).unwrap() > 0);
+    }
+
+    #[test]
+    fn test_mark_graduated_blocks_second_graduation() {
+        let mut launch = Launch {
+            total_shares: 1_000_000,
+            total_sol: 1_000_000_000,
+            ..Default::default()
+        };
+        assert!(launch.can_graduate());
+
+        launch
+            .mark_graduated(
+                GRADUATED_AT,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            )
+            .unwrap();
+
+        // A second (re-entrant or repeated) graduation must be rejected
+        assert!(!launch.can_graduate());
+        assert_eq!(launch.total_shares_at_graduation, 1_000_000);
+        assert_eq!(launch.vesting_start, Some(GRADUATED_AT));
+    }
+
+    #[test]
+    fn test_graduation_record_matches_pool_vaults() {
+        // Reserves as Raydium's vaults hold them after a 100 SOL graduation
+        let vault_sol = 100_000_000_000;
+        let vault_tokens = TOKENS_FOR_LP * TOKEN_DECIMALS_SCALE;
+        let lp_minted = ((vault_sol as u128 * vault_tokens as u128).isqrt() - 100) as u64;
+
+        let mut launch = Launch {
+            total_shares: 1_000_000,
+            total_sol: vault_sol,
+            ..Default::default()
+        };
+        launch
+            .mark_graduated(
+                GRADUATED_AT,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            )
+            .unwrap();
+        let record = GraduationRecord::from_reserves(vault_sol, vault_tokens, lp_minted).unwrap();
+        launch.record_graduation_outcome(record).unwrap();
+
+        let stored = launch.graduation_record.unwrap();
+        assert_eq!(stored.init_sol, vault_sol);
+        assert_eq!(stored.init_tokens, vault_tokens);
+        assert_eq!(stored.lp_minted, lp_minted);
+        // 100 SOL / 200M tokens = 500 lamports per token
+        assert_eq!(stored.initial_price, 500 * GRADUATION_PRICE_SCALE as u64);
+    }
+
+    #[test]
+    fn test_graduation_record_is_immutable() {
+        let record = GraduationRecord::from_reserves(1_000, 1_000, 1_000).unwrap();
+        let mut launch = Launch::default();
+        assert_eq!(
+            launch.record_graduation_outcome(record).unwrap_err(),
+            AstraError::NotGraduated.into()
+        );
+
+        launch.graduated = true;
+        launch.record_graduation_outcome(record).unwrap();
+        assert_eq!(
+            launch.record_graduation_outcome(record).unwrap_err(),
+            AstraError::GraduationAlreadyRecorded.into()
+        );
+    }
+
+    #[test]
+    fn test_graduation_record_rejects_empty_reserves() {
+        assert_eq!(
+            GraduationRecord::from_reserves(0, 1_000, 0).unwrap_err(),
+            AstraError::InsufficientPoolAmounts.into()
+        );
+        assert!(GraduationRecord::from_reserves(1_000, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_padded_name_and_symbol_rejected() {
+        for name in ["  SCAM  ", " Astra", "Astra ", "Astra\n", "\tAstra"] {
+            assert_eq!(
+                Launch::validate_name(name).unwrap_err(),
+                AstraError::InvalidLaunchName.into()
+            );
+        }
+        for symbol in [" AST", "AST ", "\tAST"] {
+            assert_eq!(
+                Launch::validate_symbol(symbol).unwrap_err(),
+                AstraError::InvalidLaunchSymbol.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_whitespace_only_name_and_symbol_rejected() {
+        assert!(Launch::validate_name(" ").is_err());
+        assert!(Launch::validate_name("\u{3000}\u{a0}").is_err());
+        assert!(Launch::validate_name("").is_err());
+        assert!(Launch::validate_symbol("   ").is_err());
+        assert!(Launch::validate_symbol("").is_err());
+    }
+
+    #[test]
+    fn test_internal_whitespace() {
+        // Names may contain spaces; symbols may not
+        assert!(Launch::validate_name("Astra Protocol").is_ok());
+        assert!(Launch::validate_symbol("AS TR").is_err());
+        assert!(Launch::validate_symbol("AS\u{a0}TR").is_err());
+        assert!(Launch::validate_symbol("ASTRA").is_ok());
+    }
+
+    #[test]
+    fn test_name_and_symbol_length_limits() {
+        assert!(Launch::validate_name(&"a".repeat(50)).is_ok());
+        assert!(Launch::validate_name(&"a".repeat(51)).is_err());
+        assert!(Launch::validate_symbol(&"A".repeat(10)).is_ok());
+        assert!(Launch::validate_symbol(&"A".repeat(11)).is_err());
+    }
+
+    #[test]
+    fn test_symbol_normalized_to_uppercase() {
+        assert_eq!(Launch::normalize_symbol("sol").unwrap(), "SOL");
+        assert_eq!(Launch::normalize_symbol("Sol").unwrap(), "SOL");
+        assert_eq!(Launch::normalize_symbol("ast2").unwrap(), "AST2");
+        // Non-ASCII is left alone (no byte-length change)
+        assert_eq!(Launch::normalize_symbol("ßol").unwrap(), "ßOL");
+    }
+
+    #[test]
+    fn test_symbol_limits_apply_to_normalized_form() {
+        assert_eq!(
+            Launch::normalize_symbol(&"a".repeat(10)).unwrap(),
+            "A".repeat(10)
+        );
+        assert_eq!(
+            Launch::normalize_symbol(&"a".repeat(11)).unwrap_err(),
+            AstraError::InvalidLaunchSymbol.into()
+        );
+        assert!(Launch::normalize_symbol("s ol").is_err());
+        assert!(Launch::normalize_symbol("").is_err());
+    }
+
+    /// Buy the way buy.rs does: only non-holders are admitted against the cap
+    fn buy_as(launch: &mut Launch, position: &mut Position, shares: u64) -> Result<()> {
+        if !position.is_holder() {
+            launch.admit_holder()?;
+        }
+        position.shares += shares;
+        Ok(())
+    }
+
+    #[test]
+    fn test_holder_cap_rejects_new_holder_but_not_top_up() {
+        let mut launch = Launch {
+            holder_count: 1, // creator
+            max_holders: Some(3),
+            ..Default::default()
+        };
+        let mut alice = Position::default();
+        let mut bob = Position::default();
+        let mut carol = Position::default();
+
+        buy_as(&mut launch, &mut alice, 100).unwrap();
+        buy_as(&mut launch, &mut bob, 100).unwrap();
+        assert_eq!(launch.holder_count, 3);
+
+        // Cap reached: a new entrant is rejected...
+        assert_eq!(
+            buy_as(&mut launch, &mut carol, 100).unwrap_err(),
+            AstraError::HolderCapReached.into()
+        );
+        assert_eq!(launch.holder_count, 3);
+
+        // ...while an existing holder can still add
+        buy_as(&mut launch, &mut alice, 50).unwrap();
+        assert_eq!(alice.shares, 150);
+        assert_eq!(launch.holder_count, 3);
+
+        // A holder selling out frees a slot
+        bob.shares = 0;
+        assert!(!bob.is_holder());
+        launch.holder_count -= 1;
+        buy_as(&mut launch, &mut carol, 100).unwrap();
+        assert_eq!(launch.holder_count, 3);
+    }
+
+    #[test]
+    fn test_largest_holder_is_a_high_water_mark() {
+        let creator = Pubkey::new_unique();
+        let whale = Pubkey::new_unique();
+        let mut launch = Launch {
+            creator,
+            total_shares: 10_000,
+            ..Default::default()
+        };
+
+        launch.record_holder_shares(&whale, 900);
+        launch.record_holder_shares(&Pubkey::new_unique(), 400);
+        assert_eq!(launch.largest_holder_shares, 900);
+        assert!(launch.largest_holder_within_cap(GRADUATION_MAX_CONCENTRATION_BPS));
+
+        // Crossing 10% fails the gate, and selling back down doesn't clear it
+        launch.record_holder_shares(&whale, 1_001);
+        assert!(!launch.largest_holder_within_cap(GRADUATION_MAX_CONCENTRATION_BPS));
+        launch.record_holder_shares(&whale, 500);
+        assert_eq!(launch.largest_holder_shares, 1_001);
+
+        // More supply dilutes the whale back under the cap
+        launch.total_shares = 10_010;
+        assert!(launch.largest_holder_within_cap(GRADUATION_MAX_CONCENTRATION_BPS));
+
+        // The creator is capped separately and never counted here
+        launch.record_holder_shares(&creator, 5_000);
+        assert_eq!(launch.largest_holder_shares, 1_001);
+        assert!(launch.largest_holder_within_cap(0));
+    }
+
+    /// Positions of `launch` for (user, shares) pairs, sorted by user
+    fn holder_positions(launch: &Pubkey, holders: &[(Pubkey, u64)]) -> Vec<Position> {
+        let mut positions: Vec<Position> = holders
+            .iter()
+            .map(|(user, shares)| Position {
+                launch: *launch,
+                user: *user,
+                shares: *shares,
+                ..Default::default()
+            })
+            .collect();
+        positions.sort_by_key(|position| position.user);
+        positions
+    }
+
+    #[test]
+    fn test_two_holder_sell_down_keeps_the_whale_gate() {
+        let key = Pubkey::new_unique();
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut launch = Launch {
+            creator: Pubkey::new_unique(),
+            total_shares: 500,
+            position_count: 2,
+            ..Default::default()
+        };
+
+        // A holds 100, B holds 90
+        launch.record_holder_shares(&a, 100);
+        launch.record_holder_shares(&b, 90);
+
+        // A sells down to 10: the mark doesn't drop to A's new size
+        launch.total_shares -= 90;
+        launch.record_holder_shares(&a, 10);
+        assert_eq!(launch.largest_holder_shares, 100);
+        assert!(!launch.largest_holder_within_cap(GRADUATION_MAX_CONCENTRATION_BPS));
+
+        // The proven maximum is B's 90, still over 10% of 410
+        let positions = holder_positions(&key, &[(a, 10), (b, 90)]);
+        assert_eq!(launch.prove_largest_holder(&key, &positions).unwrap(), 90);
+        assert!(!launch.largest_holder_within_cap(GRADUATION_MAX_CONCENTRATION_BPS));
+    }
+
+    #[test]
+    fn test_proven_sell_back_clears_the_whale_gate() {
+        let key = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let griefer = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+        let mut launch = Launch {
+            creator,
+            total_shares: 10_000,
+            position_count: 3,
+            ..Default::default()
+        };
+        launch.record_holder_shares(&holder, 800);
+
+        // Buy half the supply, then sell it all back for the buy fee
+        launch.record_holder_shares(&griefer, 10_000);
+        launch.record_holder_shares(&griefer, 0);
+        assert!(!launch.largest_holder_within_cap(GRADUATION_MAX_CONCENTRATION_BPS));
+
+        // Every position proves the real maximum; the creator isn't counted
+        let positions = holder_positions(&key, &[(creator, 5_000), (griefer, 0), (holder, 800)]);
+        assert_eq!(launch.prove_largest_holder(&key, &positions).unwrap(), 800);
+        assert!(launch.largest_holder_within_cap(GRADUATION_MAX_CONCENTRATION_BPS));
+    }
+
+    #[test]
+    fn test_largest_holder_proof_needs_every_position() {
+        let key = Pubkey::new_unique();
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut launch = Launch {
+            total_shares: 1_000,
+            position_count: 2,
+            largest_holder_shares: 900,
+            ..Default::default()
+        };
+
+        // Leaving the whale out doesn't prove anything
+        let partial = holder_positions(&key, &[(a, 10)]);
+        assert_eq!(
+            launch.prove_largest_holder(&key, &partial).unwrap_err(),
+            crate::errors::AstraError::IncompleteHolderSet.into()
+        );
+
+        // Nor does passing a small holder twice, or another launch's position
+        let small = holder_positions(&key, &[(a, 10)]).remove(0);
+        assert_eq!(
+            launch
+                .prove_largest_holder(&key, &[small.clone(), small.clone()])
+                .unwrap_err(),
+            crate::errors::AstraError::InvalidReconciliationAccounts.into()
+        );
+        let foreign = holder_positions(&Pubkey::new_unique(), &[(a, 10), (b, 10)]);
+        assert_eq!(
+            launch.prove_largest_holder(&key, &foreign).unwrap_err(),
+            crate::errors::AstraError::InvalidReconciliationAccounts.into()
+        );
+        assert_eq!(launch.largest_holder_shares, 900);
+    }
+
+    #[test]
+    fn test_uncapped_launch_admits_holders() {
+        let mut launch = Launch {
+            holder_count: 1_000,
+            ..Default::default()
+        };
+        launch.admit_holder().unwrap();
+        assert_eq!(launch.holder_count, 1_001);
+    }
+
+    #[test]
+    fn test_backfill_holder_count_unblocks_gate() {
+        // Pre-tracking launch: real holders, but holder_count never incremented
+        let mut launch = Launch::default();
+        assert!(!launch.meets_holder_minimum(GRADUATION_MIN_HOLDERS));
+
+        let previous = launch.backfill_holder_count(150).unwrap();
+        assert_eq!(previous, 0);
+        assert_eq!(launch.holder_count, 150);
+        assert!(launch.holder_count_backfilled);
+        assert!(launch.meets_holder_minimum(GRADUATION_MIN_HOLDERS));
+    }
+
+    #[test]
+    fn test_backfill_holder_count_cannot_rerun() {
+        let mut launch = Launch::default();
+        launch.backfill_holder_count(150).unwrap();
+
+        assert_eq!(
+            launch.backfill_holder_count(500).unwrap_err(),
+            AstraError::HolderCountAlreadyBackfilled.into()
+        );
+        assert_eq!(launch.holder_count, 150);
+
+        // Launches tracked from creation are latched from the start
+        let mut tracked = Launch {
+            holder_count_backfilled: true,
+            ..Default::default()
+        };
+        assert!(tracked.backfill_holder_count(1).is_err());
+    }
+
+    #[test]
+    fn test_backfill_holder_count_rejects_graduated() {
+        let mut launch = Launch {
+            graduated: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            launch.backfill_holder_count(150).unwrap_err(),
+            AstraError::AlreadyGraduated.into()
+        );
+        assert!(!launch.holder_count_backfilled);
+    }
+
+    #[test]
+    fn test_recount_holders_counts_every_position_once() {
+        let key = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let mut launch = Launch {
+            creator,
+            position_count: 4,
+            ..Default::default()
+        };
+
+        // A sold-out position stays open but isn't a holder
+        let mut positions = holder_positions(
+            &key,
+            &[
+                (creator, 0),
+                (Pubkey::new_unique(), 10),
+                (Pubkey::new_unique(), 0),
+                (Pubkey::new_unique(), 25),
+            ],
+        );
+        positions
+            .iter_mut()
+            .find(|position| position.user == creator)
+            .unwrap()
+            .locked_shares = 1_000;
+
+        // A partial set can't be counted
+        assert_eq!(
+            launch.recount_holders(&key, &positions[..3]).unwrap_err(),
+            AstraError::IncompleteHolderSet.into()
+        );
+        assert!(!launch.holder_count_backfilled);
+
+        assert_eq!(launch.recount_holders(&key, &positions).unwrap(), (0, 3));
+        assert_eq!(launch.holder_count, 3);
+
+        // Shares the backfill latch
+        assert_eq!(
+            launch.recount_holders(&key, &positions).unwrap_err(),
+            AstraError::HolderCountAlreadyBackfilled.into()
+        );
+        assert_eq!(
+            launch.backfill_holder_count(500).unwrap_err(),
+            AstraError::HolderCountAlreadyBackfilled.into()
+        );
+    }
+
+    /// A refund-mode launch: creator seed plus one buyer, with the creator also buying
+    fn refunding_launch() -> (Launch, Position, Position) {
+        let creator = Pubkey::new_unique();
+        let creator_position = Position {
+            user: creator,
+            shares: 20_000,
+            sol_basis: 2_000_000,
+            locked_shares: 100_000,
+            ..Default::default()
+        };
+        let buyer = Position {
+            user: Pubkey::new_unique(),
+            shares: 50_000,
+            sol_basis: 5_000_000,
+            ..Default::default()
+        };
+        let mut launch = Launch {
+            creator,
+            creator_seed_shares: 100_000,
+            creator_seed_sol: 10_000_000,
+            total_shares: 170_000,
+            total_sol: 17_000_000,
+            position_count: 2,
+            holder_count: 2,
+            ..Default::default()
+        };
+        launch.enter_refund_mode(GRADUATED_AT).unwrap();
+        (launch, creator_position, buyer)
+    }
+
+    #[test]
+    fn test_unrefunded_creator_seed_blocks_close() {
+        let (mut launch, creator_position, buyer) = refunding_launch();
+
+        let (quote, complete) = launch.settle_refund(&buyer, u64::MAX).unwrap();
+        assert_eq!(quote.amount, 5_000_000);
+        assert!(!complete);
+
+        // Only the creator's seed (and later buys) remain - not closable
+        assert_eq!(launch.total_shares, 120_000);
+        assert_eq!(launch.total_sol, 12_000_000);
+        assert!(!launch.can_close());
+
+        // Creator refund covers bought basis + seed SOL, locked shares included
+        let (quote, complete) = launch.settle_refund(&creator_position, u64::MAX).unwrap();
+        assert_eq!(quote.amount, 12_000_000);
+        assert!(complete);
+        assert_eq!(launch.total_shares, 0);
+        assert_eq!(launch.total_sol, 0);
+        assert_eq!(launch.holder_count, 0);
+        assert!(launch.can_close());
+    }
+
+    #[test]
+    fn test_preview_matches_claim_when_fully_funded() {
+        let (mut launch, creator_position, buyer) = refunding_launch();
+        let available = launch.total_sol;
+
+        let preview = launch.refund_quote(&buyer, available).unwrap();
+        assert_eq!(preview.amount, buyer.sol_basis);
+        assert!(!preview.is_proportional);
+
+        let (claimed, _) = launch.settle_refund(&buyer, available).unwrap();
+        assert_eq!(claimed, preview);
+
+        // Creator preview includes the seed
+        let available = available - claimed.amount;
+        let preview = launch.refund_quote(&creator_position, available).unwrap();
+        assert_eq!(preview.amount, 12_000_000);
+        assert_eq!(launch.settle_refund(&creator_position, available).unwrap().0, preview);
+    }
+
+    #[test]
+    fn test_preview_matches_claim_when_under_funded() {
+        let (mut launch, creator_position, buyer) = refunding_launch();
+        // PDA holds 80% of what's owed
+        let mut available = launch.total_sol * 8 / 10;
+
+        let preview = launch.refund_quote(&buyer, available).unwrap();
+        assert!(preview.is_proportional);
+        assert_eq!(preview.entitlement, 5_000_000);
+        assert_eq!(preview.amount, 4_000_000);
+
+        let (claimed, _) = launch.settle_refund(&buyer, available).unwrap();
+        assert_eq!(claimed, preview);
+        available -= claimed.amount;
+
+        // The next claimant takes the same 20% haircut, not the whole shortfall
+        let preview = launch.refund_quote(&creator_position, available).unwrap();
+        assert_eq!(preview.amount, 9_600_000);
+        let (claimed, complete) = launch.settle_refund(&creator_position, available).unwrap();
+        assert_eq!(claimed, preview);
+        assert!(complete);
+        assert_eq!(available - claimed.amount, 0);
+        assert!(launch.can_close());
+    }
+
+    /// Refunds every position in `order` against an under-funded PDA
+    /// Returns (entitlement, amount) per position index
+    fn refund_in_order(
+        launch: &mut Launch,
+        positions: &[Position],
+        mut available: u64,
+        order: &[usize],
+    ) -> Vec<(u64, u64)> {
+        let mut paid = vec![(0, 0); positions.len()];
+        for &index in order {
+            let (quote, _) = launch.settle_refund(&positions[index], available).unwrap();
+            available -= quote.amount;
+            paid[index] = (quote.entitlement, quote.amount);
+        }
+        assert!(launch.can_close());
+        paid
+    }
+
+    #[test]
+    fn test_under_funded_creator_seed_refunds_pari_passu() {
+        let (launch, creator_position, buyer) = refunding_launch();
+        let positions = [creator_position, buyer];
+        // 75% funded
+        let available = launch.total_sol * 3 / 4;
+
+        // Creator first or last, everyone (seed included) takes the same 25% haircut
+        for order in [[0, 1], [1, 0]] {
+            let paid = refund_in_order(&mut launch.clone(), &positions, available, &order);
+            assert_eq!(paid[0], (12_000_000, 9_000_000), "creator, order {:?}", order);
+            assert_eq!(paid[1], (5_000_000, 3_750_000), "buyer, order {:?}", order);
+        }
+    }
+
+    #[test]
+    fn test_under_funded_haircut_equal_with_odd_amounts() {
+        let creator = Pubkey::new_unique();
+        let mut positions = vec![Position {
+            user: creator,
+            sol_basis: 333_333,
+            locked_shares: 90_000,
+            ..Default::default()
+        }];
+        for basis in [1_000_003u64, 7_777_777, 12_345, 999_999_999] {
+            positions.push(Position {
+                user: Pubkey::new_unique(),
+                shares: basis / 10,
+                sol_basis: basis,
+                ..Default::default()
+            });
+        }
+        let seed_sol = 4_444_441;
+        let total_sol = seed_sol + positions.iter().map(|p| p.sol_basis).sum::<u64>();
+        let mut launch = Launch {
+            creator,
+            creator_seed_shares: 90_000,
+            creator_seed_sol: seed_sol,
+            total_shares: positions.iter().map(|p| p.shares + p.locked_shares).sum(),
+            total_sol,
+            position_count: positions.len() as u64,
+            ..Default::default()
+        };
+        launch.enter_refund_mode(GRADUATED_AT).unwrap();
+        let available = total_sol / 3 + 17;
+
+        let forward: Vec<usize> = (0..positions.len()).collect();
+        let backward: Vec<usize> = forward.iter().rev().copied().collect();
+        for order in [forward, backward] {
+            let paid = refund_in_order(&mut launch.clone(), &positions, available, &order);
+            // Each payout is the same fraction of its entitlement; floor dust
+            // left by earlier claims (<= 1 lamport each) is the only drift
+            for (entitlement, amount) in paid {
+                let exact = entitlement as u128 * available as u128 / total_sol as u128;
+                assert!(
+                    (amount as u128).abs_diff(exact) <= positions.len() as u128,
+                    "entitlement {} paid {} expected {}",
+                    entitlement,
+                    amount,
+                    exact
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_reconcile_consistent_launch() {
+        let (launch, creator_position, buyer) = refunding_launch();
+        let key = Pubkey::new_unique();
+
+        let report = launch
+            .reconcile(key, &[creator_position, buyer], GRADUATED_AT)
+            .unwrap();
+        assert!(report.shares_match);
+        assert!(report.sol_match);
+        assert_eq!(report.positions_counted, report.position_count);
+        assert_eq!(report.summed_shares, 170_000);
+        assert_eq!(report.summed_sol, 17_000_000);
+    }
+
+    #[test]
+    fn test_reconcile_flags_discrepancy() {
+        let (mut launch, creator_position, buyer) = refunding_launch();
+        // Injected accounting bug: counters drift from the positions
+        launch.total_shares += 1;
+        launch.total_sol -= 500;
+
+        let report = launch
+            .reconcile(Pubkey::new_unique(), &[creator_position, buyer], 0)
+            .unwrap();
+        assert!(!report.shares_match);
+        assert!(!report.sol_match);
+
+        // A missing position shows up in the count as well as the sums
+        let (launch, _, buyer) = refunding_launch();
+        let report = launch.reconcile(Pubkey::new_unique(), &[buyer], 0).unwrap();
+        assert!(!report.shares_match);
+        assert_eq!(report.positions_counted, 1);
+        assert_eq!(report.position_count, 2);
+    }
+
+    #[test]
+    fn test_non_vesting_seed_is_immediately_sellable() {
+        let mut launch = Launch::default();
+        let mut position = Position::default();
+        launch.assign_seed(&mut position, 100_000, 10_000_000, false);
+
+        assert_eq!(launch.creator_seed_shares, 0);
+        assert_eq!(launch.creator_seed_sol, 0);
+        assert_eq!(position.locked_shares, 0);
+        assert_eq!(position.unlocked_shares(), 100_000);
+        // Full exit returns the whole seed basis
+        assert_eq!(position.sell_refund(100_000).unwrap(), 10_000_000);
+    }
+
+    #[test]
+    fn test_vesting_seed_is_locked() {
+        let mut launch = Launch::default();
+        let mut position = Position::default();
+        launch.assign_seed(&mut position, 100_000, 10_000_000, true);
+
+        assert_eq!(launch.creator_seed_shares, 100_000);
+        assert_eq!(launch.creator_seed_sol, 10_000_000);
+        assert_eq!(position.locked_shares, 100_000);
+        // Nothing sellable until claim_vesting moves shares across
+        assert_eq!(position.shares, 0);
+        assert_eq!(position.unlocked_shares(), 0);
+    }
+
+    #[test]
+    fn test_seed_refund_entitlement_same_either_way() {
+        for seed_vests in [true, false] {
+            let mut launch = Launch {
+                creator: Pubkey::new_unique(),
+                ..Launch::default()
+            };
+            let mut position = Position {
+                user: launch.creator,
+                ..Position::default()
+            };
+            launch.assign_seed(&mut position, 100_000, 10_000_000, seed_vests);
+            assert_eq!(launch.refund_entitlement(&position).unwrap(), 10_000_000);
+        }
+    }
+
+    #[test]
+    fn test_boosted_graduation_deepens_pool() {
+        use crate::constants::TOKENS_FOR_LP;
+
+        let booster = Pubkey::new_unique();
+        let mut launch = Launch {
+            total_sol: 80 * 1_000_000_000,
+            ..graduated_launch()
+        };
+        launch.graduated = false;
+        launch.add_boost(booster, 15_000_000_000, GRADUATED_AT).unwrap();
+        launch.add_boost(booster, 5_000_000_000, GRADUATED_AT).unwrap();
+        assert_eq!(launch.boosted_sol, 20_000_000_000);
+
+        // Only the first booster may add more
+        assert_eq!(
+            launch
+                .add_boost(Pubkey::new_unique(), 1, GRADUATED_AT)
+                .unwrap_err(),
+            AstraError::BoosterMismatch.into()
+        );
+
+        let pool_sol = launch.graduation_pool_sol(launch.total_sol).unwrap();
+        assert_eq!(pool_sol, 100 * 1_000_000_000);
+
+        // Same token side, 25% more SOL: larger pool, higher opening price
+        let tokens = TOKENS_FOR_LP * TOKEN_DECIMALS_SCALE;
+        let unboosted = GraduationRecord::from_reserves(launch.total_sol, tokens, 0).unwrap();
+        let boosted = GraduationRecord::from_reserves(pool_sol, tokens, 0).unwrap();
+        assert_eq!(boosted.initial_price, unboosted.initial_price * 5 / 4);
+    }
+
+    #[test]
+    fn test_booster_slot_requires_minimum_boost() {
+        use crate::constants::MIN_BOOST_LAMPORTS;
+
+        let mut launch = Launch {
+            total_sol: 1_000,
+            total_shares: 1_000,
+            ..Launch::default()
+        };
+        // 1 lamport can't take the slot
+        assert_eq!(
+            launch.add_boost(Pubkey::new_unique(), 1, 0).unwrap_err(),
+            AstraError::InvalidBoostAmount.into()
+        );
+        assert_eq!(
+            launch
+                .add_boost(Pubkey::new_unique(), MIN_BOOST_LAMPORTS - 1, 0)
+                .unwrap_err(),
+            AstraError::InvalidBoostAmount.into()
+        );
+        assert!(launch.booster.is_none());
+
+        // Once held, the booster may top up by any amount
+        let booster = Pubkey::new_unique();
+        launch.add_boost(booster, MIN_BOOST_LAMPORTS, 0).unwrap();
+        launch.add_boost(booster, 1, 0).unwrap();
+        assert_eq!(launch.boosted_sol, MIN_BOOST_LAMPORTS + 1);
+    }
+
+    #[test]
+    fn test_boost_rejected_while_locked_or_refunding() {
+        let mut launch = Launch {
+            total_sol: 1_000,
+            total_shares: 1_000,
+            ..Launch::default()
+        };
+        launch.lock_graduation(GRADUATED_AT).unwrap();
+        assert_eq!(
+            launch
+                .add_boost(Pubkey::new_unique(), 1, GRADUATED_AT + 1)
+                .unwrap_err(),
+            AstraError::GraduationLocked.into()
+        );
+
+        let (mut launch, _, _) = refunding_launch();
+        assert!(launch.add_boost(Pubkey::new_unique(), 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_refund_excludes_and_returns_boost() {
+        let (mut launch, creator_position, buyer) = refunding_launch();
+        let booster = Pubkey::new_unique();
+        let boost = crate::constants::MIN_BOOST_LAMPORTS;
+        // Boosted before the launch failed
+        launch.refund_mode = false;
+        launch.add_boost(booster, boost, 0).unwrap();
+        launch.refund_mode = true;
+
+        // PDA holds total_sol with the boost inside it - the boost must not paper over the gap
+        let available = launch.total_sol;
+        assert_eq!(launch.refund_shortfall(available), launch.total_sol);
+        assert!(launch.refund_quote(&buyer, available).unwrap().is_proportional);
+
+        // Fully funded: holders get their basis, the booster gets the boost back
+        let available = launch.total_sol + boost;
+        let (paid, _) = launch.settle_refund(&buyer, available).unwrap();
+        let (paid_creator, _) = launch
+            .settle_refund(&creator_position, available - paid.amount)
+            .unwrap();
+        assert_eq!(paid.amount + paid_creator.amount, 17_000_000);
+        assert!(!launch.can_close());
+
+        assert_eq!(
+            launch.take_boost_refund(&Pubkey::new_unique()).unwrap_err(),
+            AstraError::BoosterMismatch.into()
+        );
+        assert_eq!(launch.take_boost_refund(&booster).unwrap(), boost);
+        assert!(launch.can_close());
+    }
+
+    #[test]
+    fn test_top_up_restores_full_refunds() {
+        let (mut launch, creator_position, buyer) = refunding_launch();
+        // Fee residue drained the PDA: 3M short of the 17M owed
+        let mut available = launch.total_sol - 3_000_000;
+        assert_eq!(launch.refund_shortfall(available), 3_000_000);
+
+        // The buyer claims under-funded and takes the haircut
+        let (claimed, _) = launch.settle_refund(&buyer, available).unwrap();
+        assert!(claimed.is_proportional);
+        available -= claimed.amount;
+
+        // Authority tops up the remaining shortfall; the creator is made whole
+        let top_up = launch.refund_shortfall(available);
+        assert!(top_up > 0);
+        available += top_up;
+        assert_eq!(launch.refund_shortfall(available), 0);
+
+        let (claimed, complete) = launch.settle_refund(&creator_position, available).unwrap();
+        assert!(!claimed.is_proportional);
+        assert_eq!(claimed.amount, 12_000_000);
+        assert!(complete);
+        assert_eq!(available - claimed.amount, 0);
+        assert!(launch.can_close());
+    }
+
+    #[test]
+    fn test_can_close_requires_refund_mode() {
+        let launch = Launch::default();
+        assert!(!launch.can_close());
+    }
+
+    #[test]
+    fn test_first_n_buyers_are_fee_free() {
+        let mut launch = Launch {
+            free_fee_buyer_count: 3,
+            ..Default::default()
+        };
+
+        // Buyers 1..=3 get the waiver; the Nth is the last
+        assert!(launch.take_fee_free_slot(true));
+        assert!(launch.take_fee_free_slot(true));
+        assert!(launch.take_fee_free_slot(true));
+        assert_eq!(launch.fee_free_buys_used, 3);
+
+        // N+1th pays full fees
+        assert!(!launch.take_fee_free_slot(true));
+        assert_eq!(launch.fee_free_buys_used, 3);
+    }
+
+    #[test]
+    fn test_fee_free_slot_only_on_first_buy() {
+        let mut launch = Launch {
+            free_fee_buyer_count: 3,
+            ..Default::default()
+        };
+
+        // Top-ups by existing buyers don't consume (or get) a slot
+        assert!(!launch.take_fee_free_slot(false));
+        assert_eq!(launch.fee_free_buys_used, 0);
+
+        // Disabled by default
+        assert!(!Launch::default().take_fee_free_slot(true));
+    }
+
+    #[test]
+    fn test_volume_accumulates_within_window() {
+        let mut launch = Launch::default();
+        let start = 1_700_000_000;
+
+        launch.record_volume(start, 100).unwrap();
+        launch.record_volume(start + VOLUME_WINDOW_SECONDS - 1, 50).unwrap();
+
+        assert_eq!(launch.volume_window_start, start);
+        assert_eq!(launch.volume_last_hour, 150);
+        assert_eq!(
+            launch.current_volume(start + VOLUME_WINDOW_SECONDS - 1).unwrap(),
+            150
+        );
+    }
+
+    #[test]
+    fn test_volume_resets_across_window_boundary() {
+        let mut launch = Launch::default();
+        let start = 1_700_000_000;
+
+        launch.record_volume(start, 100).unwrap();
+        assert_eq!(launch.current_volume(start + VOLUME_WINDOW_SECONDS).unwrap(), 0);
+
+        launch.record_volume(start + VOLUME_WINDOW_SECONDS, 25).unwrap();
+        assert_eq!(launch.volume_window_start, start + VOLUME_WINDOW_SECONDS);
+        assert_eq!(launch.volume_last_hour, 25);
+    }
+
+    /// Buy the way buy.rs does, honouring the graduation lock
+    fn buy_into_curve(launch: &mut Launch, now: i64, lamports: u64) -> Result<()> {
+        launch.check_tradeable()?;
+        require!(
+            !launch.graduation_lock_active(now)?,
+            AstraError::GraduationLocked
+        );
+        launch.total_shares += lamports / 1_000;
+        launch.total_sol += lamports;
+        Ok(())
+    }
+
+    fn lockable_launch() -> Launch {
+        Launch {
+            total_shares: 1_000_000,
+            total_sol: 100_000_000_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_buy_ordered_after_lock_is_rejected() {
+        let mut launch = lockable_launch();
+
+        // Same block: lock lands first, then a buy
+        launch.lock_graduation(GRADUATED_AT).unwrap();
+        let err = buy_into_curve(&mut launch, GRADUATED_AT, 1_000_000_000).unwrap_err();
+        assert_eq!(err, AstraError::GraduationLocked.into());
+
+        // Graduation sizes the pool from the snapshot
+        assert_eq!(
+            launch.locked_graduation_sol(GRADUATED_AT).unwrap(),
+            100_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_buy_ordered_before_lock_is_snapshotted() {
+        let mut launch = lockable_launch();
+
+        // Same block: buy lands first, then the lock - the buy is in the pool
+        buy_into_curve(&mut launch, GRADUATED_AT, 1_000_000_000).unwrap();
+        launch.lock_graduation(GRADUATED_AT).unwrap();
+
+        assert_eq!(launch.graduation_sol_snapshot, 101_000_000_000);
+        assert_eq!(
+            launch.locked_graduation_sol(GRADUATED_AT).unwrap(),
+            101_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_graduate_requires_active_lock() {
+        let mut launch = lockable_launch();
+        assert_eq!(
+            launch.locked_graduation_sol(GRADUATED_AT).unwrap_err(),
+            AstraError::GraduationNotLocked.into()
+        );
+
+        // An expired lock reopens trading and must be re-taken
+        launch.lock_graduation(GRADUATED_AT).unwrap();
+        let expiry = GRADUATED_AT + GRADUATION_LOCK_SECONDS;
+        assert!(!launch.graduation_lock_active(expiry).unwrap());
+        assert_eq!(
+            launch.locked_graduation_sol(expiry).unwrap_err(),
+            AstraError::GraduationNotLocked.into()
+        );
+        buy_into_curve(&mut launch, expiry, 1_000_000_000).unwrap();
+
+        // Re-locking waits out the cooldown
+        assert_eq!(
+            launch.lock_graduation(expiry).unwrap_err(),
+            AstraError::GraduationLockCooldown.into()
+        );
+        let relock = expiry + GRADUATION_RELOCK_COOLDOWN_SECONDS;
+        assert_eq!(
+            launch.lock_graduation(relock - 1).unwrap_err(),
+            AstraError::GraduationLockCooldown.into()
+        );
+        launch.lock_graduation(relock).unwrap();
+        assert_eq!(launch.locked_graduation_sol(relock).unwrap(), 101_000_000_000);
+    }
+
+    #[test]
+    fn test_lock_requires_graduation_readiness() {
+        // 100 SOL: $42,000 at $420/SOL
+        let launch = Launch {
+            holder_count: GRADUATION_MIN_HOLDERS,
+            ..lockable_launch()
+        };
+        assert!(launch
+            .check_graduation_ready(420, GRADUATION_MIN_HOLDERS)
+            .is_ok());
+
+        // Below the market cap threshold, or with no price at all
+        for price in [419, 0] {
+            assert_eq!(
+                launch
+                    .check_graduation_ready(price, GRADUATION_MIN_HOLDERS)
+                    .unwrap_err(),
+                AstraError::GraduationThresholdNotMet.into()
+            );
+        }
+
+        // Threshold met but too few holders
+        let thin = Launch {
+            holder_count: GRADUATION_MIN_HOLDERS - 1,
+            ..lockable_launch()
+        };
+        assert_eq!(
+            thin.check_graduation_ready(420, GRADUATION_MIN_HOLDERS)
+                .unwrap_err(),
+            AstraError::NotEnoughHolders.into()
+        );
+    }
+
+    #[test]
+    fn test_graduation_lock_rejects_relock_and_drift() {
+        let mut launch = lockable_launch();
+        launch.lock_graduation(GRADUATED_AT).unwrap();
+
+        assert_eq!(
+            launch.lock_graduation(GRADUATED_AT + 1).unwrap_err(),
+            AstraError::GraduationLocked.into()
+        );
+
+        // Any curve movement after the snapshot invalidates it
+        launch.total_sol -= 1;
+        assert_eq!(
+            launch.locked_graduation_sol(GRADUATED_AT).unwrap_err(),
+            AstraError::GraduationSnapshotMismatch.into()
+        );
+    }
+
+    /// Apply a sell to the curve the way sell.rs does: record, then debit total_sol
+    fn sell_from_curve(launch: &mut Launch, now: i64, lamports: u64) -> bool {
+        let tripped = launch.record_outflow(now, lamports).unwrap();
+        launch.total_sol -= lamports;
+        tripped
+    }
+
+    #[test]
+    fn test_sell_wave_trips_circuit_breaker() {
+        let start = 1_700_000_000;
+        let mut launch = Launch {
+            total_sol: 100_000_000_000, // 100 SOL
+            sell_pressure_limit_bps: 2_000, // 20%
+            ..Default::default()
+        };
+
+        // A wave of 5 SOL sells - the 4th brings net outflow to exactly 20% (not over)
+        for i in 0..3 {
+            assert!(!sell_from_curve(&mut launch, start + i * 60, 5_000_000_000));
+        }
+        assert!(!sell_from_curve(&mut launch, start + 180, 5_000_000_000));
+        assert!(!launch.buys_frozen);
+
+        // Threshold is against the window-start reserve, not the shrinking one
+        assert_eq!(launch.flow_window_base_sol, 100_000_000_000);
+
+        // The next lamport over trips it
+        assert!(sell_from_curve(&mut launch, start + 300, 1));
+        assert!(launch.buys_frozen);
+        assert_eq!(launch.net_outflow(), 20_000_000_001);
+
+        // Further sells stay open but don't re-trip
+        assert!(!sell_from_curve(&mut launch, start + 360, 1_000_000_000));
+        assert!(launch.buys_frozen);
+    }
+
+    #[test]
+    fn test_buys_offset_sell_pressure() {
+        let start = 1_700_000_000;
+        let mut launch = Launch {
+            total_sol: 100_000_000_000,
+            sell_pressure_limit_bps: 2_000,
+            ..Default::default()
+        };
+
+        // 15 SOL out, 10 SOL in, 10 SOL out: net 15 SOL < 20 SOL
+        assert!(!sell_from_curve(&mut launch, start, 15_000_000_000));
+        launch.record_inflow(start + 60, 10_000_000_000).unwrap();
+        launch.total_sol += 10_000_000_000;
+        assert!(!sell_from_curve(&mut launch, start + 120, 10_000_000_000));
+
+        assert_eq!(launch.net_outflow(), 15_000_000_000);
+        assert!(!launch.buys_frozen);
+    }
+
+    #[test]
+    fn test_sell_pressure_resets_across_window_boundary() {
+        let start = 1_700_000_000;
+        let mut launch = Launch {
+            total_sol: 100_000_000_000,
+            sell_pressure_limit_bps: 2_000,
+            ..Default::default()
+        };
+
+        // 15 SOL out in one window, 15 SOL out in the next - never 20% in a single window
+        assert!(!sell_from_curve(&mut launch, start, 15_000_000_000));
+        assert!(!sell_from_curve(
+            &mut launch,
+            start + SELL_PRESSURE_WINDOW_SECONDS,
+            15_000_000_000
+        ));
+
+        assert_eq!(launch.flow_window_start, start + SELL_PRESSURE_WINDOW_SECONDS);
+        assert_eq!(launch.flow_window_base_sol, 85_000_000_000);
+        assert!(!launch.buys_frozen);
+    }
+
+    #[test]
+    fn test_circuit_breaker_opt_out() {
+        let mut launch = Launch {
+            total_sol: 100_000_000_000,
+            ..Default::default()
+        };
+
+        // Limit 0 = creator did not opt in; even a full drain never freezes buys
+        assert!(!sell_from_curve(&mut launch, 1_700_000_000, 100_000_000_000));
+        assert!(!launch.buys_frozen);
+    }
+
+    #[test]
+    fn test_reset_circuit_breaker_reopens_buys() {
+        let start = 1_700_000_000;
+        let mut launch = Launch {
+            total_sol: 100_000_000_000,
+            sell_pressure_limit_bps: 2_000,
+            ..Default::default()
+        };
+        assert!(sell_from_curve(&mut launch, start, 30_000_000_000));
+
+        assert!(launch.reset_circuit_breaker(start + 60));
+        assert!(!launch.buys_frozen);
+        assert_eq!(launch.net_outflow(), 0);
+        assert_eq!(launch.flow_window_start, start + 60);
+        assert_eq!(launch.flow_window_base_sol, 70_000_000_000);
+
+        // Resetting an untripped breaker reports it wasn't frozen
+        assert!(!launch.reset_circuit_breaker(start + 120));
+    }
+
+    #[test]
+    fn test_check_tradeable_refund_mode_error() {
+        let launch = Launch {
+            refund_mode: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            launch.check_tradeable().unwrap_err(),
+            AstraError::LaunchInRefundMode.into()
+        );
+    }
+
+    #[test]
+    fn test_check_tradeable_active_launch() {
+        assert!(Launch::default().check_tradeable().is_ok());
+        let graduated = Launch {
+            graduated: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            graduated.check_tradeable().unwrap_err(),
+            AstraError::AlreadyGraduated.into()
+        );
+    }
+
+    #[test]
+    fn test_record_refund_counts_down_to_complete() {
+        let mut launch = Launch {
+            refund_mode: true,
+            positions_remaining: 3,
+            ..Default::default()
+        };
+
+        assert_eq!(launch.record_refund(100), Some(false));
+        assert_eq!(launch.record_refund(0), Some(false));
+        assert_eq!(launch.record_refund(250), Some(true));
+
+        assert_eq!(launch.positions_remaining, 0);
+        assert_eq!(launch.total_refunded, 350);
+    }
+
+    #[test]
+    fn test_refunds_complete_reported_once() {
+        let mut launch = Launch {
+            refund_mode: true,
+            positions_remaining: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(launch.record_refund(100), Some(true));
+
+        // Stray refunds after the count hit zero don't re-report completion
+        assert_eq!(launch.record_refund(50), Some(false));
+        assert_eq!(launch.record_refund(0), Some(false));
+        assert_eq!(launch.positions_remaining, 0);
+        assert_eq!(launch.total_refunded, 150);
+    }
+
+    #[test]
+    fn test_claims_in_protocol_without_distributor() {
+        assert!(graduated_launch().claims_in_protocol());
+    }
+
+    #[test]
+    fn test_claims_external_with_distributor() {
+        let launch = Launch {
+            distributor: Some(Pubkey::new_unique()),
+            ..graduated_launch()
+        };
+        assert!(!launch.claims_in_protocol());
+    }
+
+    #[test]
+    fn test_third_party_claim_extreme_timestamp_overflows() {
+        let launch = Launch {
+            graduated_at: Some(i64::MAX),
+            ..graduated_launch()
+        };
+        assert!(launch.third_party_claim_open(i64::MIN).is_err());
+    }
+
+    #[test]
+    fn test_buys_rejected_before_trading_starts() {
+        let created_at = 1_700_000_000;
+        let launch = Launch {
+            created_at,
+            trading_starts_at: Launch::trading_start_after(created_at, 300).unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(launch.trading_starts_at, Some(created_at + 300));
+
+        for now in [created_at, created_at + 299] {
+            assert_eq!(
+                launch.check_trading_started(now).unwrap_err(),
+                AstraError::TradingNotStarted.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_buys_allowed_once_trading_starts() {
+        use crate::constants::MAX_TRADING_START_DELAY_SECONDS;
+
+        let created_at = 1_700_000_000;
+        let launch = Launch {
+            created_at,
+            trading_starts_at: Launch::trading_start_after(created_at, 300).unwrap(),
+            ..Default::default()
+        };
+        assert!(launch.check_trading_started(created_at + 300).is_ok());
+
+        // No delay: open from creation
+        assert_eq!(Launch::trading_start_after(created_at, 0).unwrap(), None);
+        assert!(Launch::default().check_trading_started(created_at).is_ok());
+
+        // Delay must stay within bounds
+        for delay in [-1, MAX_TRADING_START_DELAY_SECONDS + 1] {
+            assert_eq!(
+                Launch::trading_start_after(created_at, delay).unwrap_err(),
+                AstraError::InvalidTradingDelay.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_creator_trade_rejected_in_cooldown() {
+        let launch = Launch {
+            created_at: 1_700_000_000,
+            creator_trade_cooldown: 3_600,
+            ..Default::default()
+        };
+        assert!(!launch.creator_can_trade(1_700_000_000).unwrap());
+        assert!(!launch.creator_can_trade(1_700_003_599).unwrap());
+    }
+
+    #[test]
+    fn test_creator_trade_allowed_after_cooldown() {
+        let launch = Launch {
+            created_at: 1_700_000_000,
+            creator_trade_cooldown: 3_600,
+            ..Default::default()
+        };
+        assert!(launch.creator_can_trade(1_700_003_600).unwrap());
+
+        // No cooldown configured
+        let no_cooldown = Launch {
+            created_at: 1_700_000_000,
+            ..Default::default()
+        };
+        assert!(no_cooldown.creator_can_trade(1_700_000_000).unwrap());
+    }
+
+    #[test]
+    fn test_graduation_blocked_below_holder_minimum() {
+        let launch = Launch {
+            holder_count: 99,
+            ..Default::default()
+        };
+        assert!(!launch.meets_holder_minimum(GRADUATION_MIN_HOLDERS));
+    }
+
+    #[test]
+    fn test_graduation_allowed_at_holder_minimum() {
+        let mut launch = Launch {
+            holder_count: GRADUATION_MIN_HOLDERS,
+            total_shares: 1_000,
+            ..Default::default()
+        };
+        assert!(launch.meets_holder_minimum(GRADUATION_MIN_HOLDERS));
+
+        launch
+            .mark_graduated(
+                GRADUATED_AT,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            )
+            .unwrap();
+        assert_eq!(launch.holders_at_graduation, GRADUATION_MIN_HOLDERS);
+    }
+
+    #[test]
+    fn test_operation_guard_is_transaction_scoped() {
+        let mut launch = Launch {
+            total_sol: 10_000_000_000,
+            total_shares: 1_000,
+            ..Default::default()
+        };
+
+        // Tx 1: lock_graduation persists the freeze, not the reentrancy guard
+        launch.lock_graduation(GRADUATED_AT).unwrap();
+        assert!(!launch.operation_in_progress);
+        assert!(launch.graduation_lock_active(GRADUATED_AT + 1).unwrap());
+
+        // Tx 2: graduate takes the guard; re-entry in the same tx is rejected
+        let mut in_flight = launch.clone();
+        in_flight.begin_operation().unwrap();
+        assert_eq!(
+            in_flight.begin_operation().unwrap_err(),
+            AstraError::ReentrancyDetected.into()
+        );
+
+        // The Raydium CPI fails: the tx reverts, so `launch` is what persists.
+        // Trading stays frozen by the lock and a retry can take the guard.
+        drop(in_flight);
+        assert!(!launch.operation_in_progress);
+        assert!(launch.graduation_lock_active(GRADUATED_AT + 2).unwrap());
+        launch.begin_operation().unwrap();
+        launch.end_operation();
+        assert!(!launch.operation_in_progress);
+    }
+
+    #[test]
+    fn test_guarded_handlers_use_begin_and_end_operation() {
+        let guarded = [
+            ("buy", include_str!("../instructions/buy.rs")),
+            (
+                "claim_creator_fees",
+                include_str!("../instructions/claim_creator_fees.rs"),
+            ),
+            (
+                "claim_tokens",
+                include_str!("../instructions/claim_tokens.rs"),
+            ),
+            (
+                "claim_vesting",
+                include_str!("../instructions/claim_vesting.rs"),
+            ),
+            (
+                "force_graduate",
+                include_str!("../instructions/force_graduate.rs"),
+            ),
+            ("graduate", include_str!("../instructions/graduate.rs")),
+            (
+                "lock_graduation",
+                include_str!("../instructions/lock_graduation.rs"),
+            ),
+            ("sell", include_str!("../instructions/sell.rs")),
+        ];
+
+        for (name, source) in guarded {
+            assert!(
+                source.contains("launch.begin_operation()?"),
+                "{name} takes no guard"
+            );
+            assert!(
+                source.contains("launch.end_operation()"),
+                "{name} never releases it"
+            );
+            assert!(
+                !source.contains("operation_in_progress ="),
+                "{name} hand-rolls the guard"
+            );
+        }
+    }
+
+    #[test]
+    fn test_reset_stuck_operation_flag() {
+        let mut launch = Launch {
+            operation_in_progress: true,
+            ..Default::default()
+        };
+
+        assert!(launch.reset_operation_flag());
+        assert!(!launch.operation_in_progress);
+
+        // Normal operations can take the guard again
+        assert!(launch.check_tradeable().is_ok());
+        assert!(!launch.reset_operation_flag());
+    }
+
+    #[test]
+    fn test_third_party_claim_blocked_before_graduation() {
+        let launch = Launch::default();
+        assert!(!launch.third_party_claim_open(i64::MAX).unwrap());
+    }
+
+    #[test]
+    fn test_graduation_schedule_matches_configured_vesting() {
+        let mut launch = Launch {
+            total_shares: 1_000_000,
+            total_sol: 1_000_000_000,
+            vesting_mode: VestingMode::Linear,
+            vesting_cliff_seconds: 7 * 86_400,
+            ..Default::default()
+        };
+        assert_eq!(launch.vesting_schedule(), None);
+
+        launch
+            .mark_graduated(
+                GRADUATED_AT,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            launch.vesting_schedule(),
+            Some(VestingSchedule {
+                start: GRADUATED_AT,
+                cliff_seconds: 7 * 86_400,
+                duration_seconds: VESTING_DURATION_SECONDS,
+                mode: VestingMode::Linear,
+            })
+        );
+
+        // Cliff mode reports the full duration as its cliff
+        launch.vesting_mode = VestingMode::Cliff;
+        let schedule = launch.vesting_schedule().unwrap();
+        assert_eq!(schedule.cliff_seconds, VESTING_DURATION_SECONDS);
+        assert_eq!(schedule.mode, VestingMode::Cliff);
+    }
+
+    #[test]
+    fn test_vested_seed_shares_linear_and_cliff() {
+        let mut launch = Launch {
+            creator_seed_shares: 4_200,
+            vesting_start: Some(GRADUATED_AT),
+            vesting_cliff_seconds: VESTING_DURATION_SECONDS / 4,
+            ..graduated_launch()
+        };
+        let half = GRADUATED_AT + VESTING_DURATION_SECONDS / 2;
+        let end = GRADUATED_AT + VESTING_DURATION_SECONDS;
+
+        // Linear with cliff: zero before the cliff, linear from start after it
+        assert_eq!(launch.vested_seed_shares(GRADUATED_AT + 1).unwrap(), 0);
+        assert_eq!(launch.vested_seed_shares(half).unwrap(), 2_100);
+        assert_eq!(launch.vested_seed_shares(end + 1).unwrap(), 4_200);
+
+        // Cliff: all or nothing at the full duration
+        launch.vesting_mode = VestingMode::Cliff;
+        assert_eq!(launch.vested_seed_shares(end - 1).unwrap(), 0);
+        assert_eq!(launch.vested_seed_shares(end).unwrap(), 4_200);
+    }
+
+    #[test]
+    fn test_metadata_update_limit() {
+        use crate::constants::MAX_METADATA_UPDATES;
+
+        let mut launch = Launch::default();
+        for expected_remaining in (0..MAX_METADATA_UPDATES).rev() {
+            assert_eq!(launch.consume_metadata_update().unwrap(), expected_remaining);
+        }
+
+        // Exhausted: rejected without touching the counter
+        assert_eq!(
+            launch.consume_metadata_update().unwrap_err(),
+            AstraError::MetadataUpdatesExhausted.into()
+        );
+        assert_eq!(launch.metadata_updates_used, MAX_METADATA_UPDATES);
+    }
+
+    #[test]
+    fn test_update_metadata_replaces_uri_and_keeps_symbol() {
+        let mut launch = Launch {
+            name: "Astra".to_string(),
+            symbol: "ASTRA".to_string(),
+            uri: "ipfs://broken".to_string(),
+            metadata_hash: Some([1; 32]),
+            ..Default::default()
+        };
+
+        let old_uri = launch
+            .update_metadata(None, "ipfs://fixed".to_string(), Some([2; 32]), Some(4_096))
+            .unwrap();
+        assert_eq!(old_uri, "ipfs://broken");
+        assert_eq!(launch.uri, "ipfs://fixed");
+        assert_eq!(launch.name, "Astra");
+        assert_eq!(launch.metadata_hash, Some([2; 32]));
+        assert_eq!(launch.max_metadata_bytes, Some(4_096));
+        assert_eq!(launch.metadata_updates_used, 1);
+
+        launch
+            .update_metadata(Some("Astra Two".to_string()), "ipfs://v2".to_string(), None, None)
+            .unwrap();
+        assert_eq!(launch.name, "Astra Two");
+        assert_eq!(launch.symbol, "ASTRA");
+        assert_eq!(launch.metadata_hash, None);
+    }
+
+    #[test]
+    fn test_invalid_metadata_update_spends_nothing() {
+        let mut launch = Launch {
+            uri: "ipfs://a".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            launch
+                .update_metadata(None, String::new(), None, None)
+                .unwrap_err(),
+            AstraError::InvalidLaunchUri.into()
+        );
+        assert!(launch
+            .update_metadata(None, "x".repeat(201), None, None)
+            .is_err());
+        assert!(launch
+            .update_metadata(Some(" padded".to_string()), "ipfs://b".to_string(), None, None)
+            .is_err());
+        assert!(launch
+            .update_metadata(None, "ipfs://b".to_string(), None, Some(0))
+            .is_err());
+        assert_eq!(launch.metadata_updates_used, 0);
+        assert_eq!(launch.uri, "ipfs://a");
+
+        // Frozen at graduation - the token metadata is minted from it
+        launch.graduated = true;
+        assert_eq!(
+            launch
+                .update_metadata(None, "ipfs://b".to_string(), None, None)
+                .unwrap_err(),
+            AstraError::AlreadyGraduated.into()
+        );
+    }
+
+    #[test]
+    fn test_metadata_hash_commitment() {
+        use solana_sha256_hasher::hash;
+
+        let metadata = br#"{"name":"Astra","image":"ipfs://abc"}"#;
+        let launch = Launch {
+            metadata_hash: Some(hash(metadata).to_bytes()),
+            ..Launch::default()
+        };
+
+        assert_eq!(
+            launch.metadata_matches(&hash(metadata).to_bytes()),
+            Some(true)
+        );
+        let swapped = br#"{"name":"Astra","image":"ipfs://evil"}"#;
+        assert_eq!(
+            launch.metadata_matches(&hash(swapped).to_bytes()),
+            Some(false)
+        );
+
+        // No commitment: nothing to verify against
+        assert_eq!(
+            Launch::default().metadata_matches(&hash(metadata).to_bytes()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_metadata_fetch_size_commitment() {
+        use crate::constants::MAX_METADATA_FETCH_BYTES;
+
+        let launch = Launch {
+            max_metadata_bytes: Some(16 * 1024),
+            ..Launch::default()
+        };
+        assert_eq!(launch.metadata_fetch_limit(), 16 * 1024);
+        // No commitment: clients still bound the fetch
+        assert_eq!(Launch::default().metadata_fetch_limit(), MAX_METADATA_FETCH_BYTES);
+
+        for ok in [None, Some(1), Some(MAX_METADATA_FETCH_BYTES)] {
+            assert!(Launch::validate_max_metadata_bytes(ok).is_ok());
+        }
+        for bad in [Some(0), Some(MAX_METADATA_FETCH_BYTES + 1), Some(u32::MAX)] {
+            assert_eq!(
+                Launch::validate_max_metadata_bytes(bad).unwrap_err(),
+                AstraError::InvalidMaxMetadataBytes.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_vesting_claim_minimum_and_final_claim() {
+        let mut launch = Launch {
+            creator_seed_shares: 10_000,
+            vesting_start: Some(GRADUATED_AT),
+            ..graduated_launch()
+        };
+        // 1% of the seed vests every VESTING_DURATION_SECONDS / 100
+        let one_percent = VESTING_DURATION_SECONDS / 100;
+
+        // Below the 100-share minimum
+        assert_eq!(
+            launch
+                .claimable_seed_shares(GRADUATED_AT + one_percent - 1)
+                .unwrap_err(),
+            AstraError::ClaimTooSmall.into()
+        );
+        assert_eq!(
+            launch.claimable_seed_shares(GRADUATED_AT).unwrap_err(),
+            AstraError::NoSharesToClaim.into()
+        );
+        assert_eq!(
+            launch
+                .claimable_seed_shares(GRADUATED_AT + one_percent)
+                .unwrap(),
+            100
+        );
+
+        // Creator has claimed all but 10 shares; the final dust claim is allowed
+        launch.creator_claimed_shares = 9_990;
+        let end = GRADUATED_AT + VESTING_DURATION_SECONDS;
+        assert_eq!(launch.claimable_seed_shares(end).unwrap(), 10);
+
+        launch.creator_claimed_shares = 10_000;
+        assert_eq!(
+            launch.claimable_seed_shares(end).unwrap_err(),
+            AstraError::NoSharesToClaim.into()
+        );
+    }
+
+    #[test]
+    fn test_seed_fully_vested_boundary() {
+        let launch = Launch {
+            vesting_start: Some(GRADUATED_AT),
+            ..graduated_launch()
+        };
+
+        assert!(!launch
+            .seed_fully_vested(GRADUATED_AT + VESTING_DURATION_SECONDS - 1)
+            .unwrap());
+        assert!(launch
+            .seed_fully_vested(GRADUATED_AT + VESTING_DURATION_SECONDS)
+            .unwrap());
+        assert!(!Launch::default().seed_fully_vested(i64::MAX).unwrap());
+    }
+
+    #[test]
+    fn test_combined_vest_and_claim_allocation() {
+        // Creator holds 1_000 seed shares plus 3_000 bought shares out of 10_000
+        let launch = Launch {
+            creator_seed_shares: 1_000,
+            total_shares_at_graduation: 10_000,
+            vesting_start: Some(GRADUATED_AT),
+            ..graduated_launch()
+        };
+
+        // Fully vested: all seed shares unlock, then the whole balance is claimed
+        let now = GRADUATED_AT + VESTING_DURATION_SECONDS;
+        assert!(launch.seed_fully_vested(now).unwrap());
+
+        let tokens = launch.holder_token_amount(4_000).unwrap();
+        assert_eq!(
+            tokens,
+            TOKENS_FOR_HOLDERS * TOKEN_DECIMALS_SCALE / 10 * 4,
+            "40% of shares should receive 40% of holder tokens"
+        );
+
+        // All shares together receive exactly the holder allocation
+        assert_eq!(
+            launch.holder_token_amount(10_000).unwrap(),
+            TOKENS_FOR_HOLDERS * TOKEN_DECIMALS_SCALE
+        );
+    }
+
+    #[test]
+    fn test_min_shares_out_slippage_vs_impossible() {
+        let seed = 1_000_000;
+        let mut launch = Launch {
+            creator_seed_shares: seed,
+            total_shares: seed,
+            ..Default::default()
+        };
+        let net_sol = 1_000_000_000;
+
+        // Client quotes at the seed-only supply, then someone else buys first
+        let quoted = crate::curve::buy_return(net_sol, launch.total_shares).unwrap();
+        launch.total_shares += 5_000_000;
+        let filled = crate::curve::buy_return(net_sol, launch.total_shares).unwrap();
+        assert!(filled < quoted);
+
+        assert!(launch.check_min_shares_out(net_sol, filled, filled).is_ok());
+
+        // Realistic slippage: the quote was achievable, the price moved
+        assert_eq!(
+            launch
+                .check_min_shares_out(net_sol, filled, quoted)
+                .unwrap_err(),
+            AstraError::SlippageExceeded.into()
+        );
+
+        // Impossible request: more than the curve could ever give
+        let max = launch.max_shares_for(net_sol).unwrap();
+        assert_eq!(
+            launch
+                .check_min_shares_out(net_sol, filled, max + 1)
+                .unwrap_err(),
+            AstraError::MinSharesOutUnreachable.into()
+        );
+    }
+
+    #[test]
+    fn test_graduation_burn_shrinks_supply_and_holder_pool() {
+        use crate::constants::{MAX_BURN_BPS, TOTAL_SUPPLY_WITH_DECIMALS};
+
+        let unburned = Launch::default().token_allocation().unwrap();
+        assert_eq!(unburned.holders, TOKENS_FOR_HOLDERS * TOKEN_DECIMALS_SCALE);
+        assert_eq!(unburned.lp, TOKENS_FOR_LP * TOKEN_DECIMALS_SCALE);
+        assert_eq!(unburned.burned, 0);
+
+        // 25% burn: circulating supply drops to 750M, split 600M/150M
+        let mut launch = Launch {
+            burn_bps: 2_500,
+            total_shares_at_graduation: 10_000,
+            ..graduated_launch()
+        };
+        let allocation = launch.token_allocation().unwrap();
+        assert_eq!(allocation.holders, 600_000_000 * TOKEN_DECIMALS_SCALE);
+        assert_eq!(allocation.lp, 150_000_000 * TOKEN_DECIMALS_SCALE);
+        assert_eq!(allocation.burned, 250_000_000 * TOKEN_DECIMALS_SCALE);
+        assert_eq!(
+            allocation.holders + allocation.lp + allocation.burned,
+            TOTAL_SUPPLY_WITH_DECIMALS
+        );
+
+        // Claims scale against the reduced holder pool and still sum to it
+        assert_eq!(
+            launch.holder_token_amount(4_000).unwrap(),
+            allocation.holders / 10 * 4
+        );
+        let paid: u64 = [3_333, 3_333, 3_334]
+            .iter()
+            .map(|&shares| launch.take_token_claim(shares, None).unwrap())
+            .sum();
+        assert_eq!(paid, allocation.holders);
+
+        assert!(Launch::validate_burn_bps(MAX_BURN_BPS).is_ok());
+        assert_eq!(
+            Launch::validate_burn_bps(MAX_BURN_BPS + 1).unwrap_err(),
+            AstraError::InvalidBurnBps.into()
+        );
+    }
+
+    #[test]
+    fn test_tokens_per_share_matches_claims() {
+        assert_eq!(Launch::default().tokens_per_share(), None);
+        assert_eq!(graduated_launch().tokens_per_share(), None);
+
+        // Snapshot divides the pool: a claim is exactly shares * ratio
+        let launch = Launch {
+            total_shares_at_graduation: 10_000,
+            ..graduated_launch()
+        };
+        let ratio = launch.tokens_per_share().unwrap();
+        assert_eq!(ratio, TOKENS_FOR_HOLDERS * TOKEN_DECIMALS_SCALE / 10_000);
+        assert_eq!(launch.claim_token_amount(2_500, None).unwrap(), 2_500 * ratio);
+
+        // Uneven snapshot: the claim is within one base unit per share
+        let launch = Launch {
+            total_shares_at_graduation: 7_777,
+            ..graduated_launch()
+        };
+        let ratio = launch.tokens_per_share().unwrap();
+        let claim = launch.claim_token_amount(1_234, None).unwrap();
+        assert!(claim >= 1_234 * ratio && claim - 1_234 * ratio < 1_234);
+
+        // A graduation burn shrinks the ratio with the holder pool
+        let burned = Launch {
+            burn_bps: 2_500,
+            total_shares_at_graduation: 10_000,
+            ..graduated_launch()
+        };
+        assert_eq!(
+            burned.tokens_per_share().unwrap(),
+            600_000_000 * TOKEN_DECIMALS_SCALE / 10_000
+        );
+    }
+
+    #[test]
+    fn test_holder_token_amount_requires_snapshot() {
+        assert_eq!(Launch::default().holder_token_amount(100), None);
+    }
+
+    #[test]
+    fn test_claim_token_amount_min_tokens_out() {
+        let mut launch = Launch {
+            total_shares_at_graduation: 10_000,
+            ..graduated_launch()
+        };
+
+        // Claimant simulates a 10% claim and sets the quote as the floor
+        let quoted = launch.claim_token_amount(1_000, None).unwrap();
+        assert_eq!(launch.claim_token_amount(1_000, Some(quoted)).unwrap(), quoted);
+
+        // Snapshot reduced mid-flight: the ratio only improves, claim succeeds
+        launch.total_shares_at_graduation = 8_000;
+        assert!(launch.claim_token_amount(1_000, Some(quoted)).unwrap() > quoted);
+
+        // Snapshot grows mid-flight: the protected claim is rejected
+        launch.total_shares_at_graduation = 12_000;
+        assert_eq!(
+            launch.claim_token_amount(1_000, Some(quoted)).unwrap_err(),
+            AstraError::SlippageExceeded.into()
+        );
+        // Unprotected claim takes whatever the new ratio gives
+        assert!(launch.claim_token_amount(1_000, None).unwrap() < quoted);
+    }
+
+    #[test]
+    fn test_claim_token_amount_rejects_empty_claims() {
+        assert_eq!(
+            Launch::default().claim_token_amount(100, None).unwrap_err(),
+            AstraError::NotGraduated.into()
+        );
+        assert_eq!(
+            graduated_launch().claim_token_amount(100, None).unwrap_err(),
+            AstraError::InvalidCalculation.into()
+        );
+        let launch = Launch {
+            total_shares_at_graduation: 10_000,
+            ..graduated_launch()
+        };
+        assert_eq!(
+            launch.claim_token_amount(0, None).unwrap_err(),
+            AstraError::NoSharesToClaim.into()
+        );
+    }
 }
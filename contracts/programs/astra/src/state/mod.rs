@@ -1,11 +1,21 @@
 pub mod config;
+pub mod creator_bond;
+pub mod creator_launches;
 pub mod creator_stats;
+pub mod fee_escrow;
+pub mod keeper_rewards;
 pub mod launch;
 pub mod position;
+#[cfg(test)]
+mod share_invariants;
 pub mod vault;
 
 pub use config::*;
+pub use creator_bond::*;
+pub use creator_launches::*;
 pub use creator_stats::*;
+pub use fee_escrow::*;
+pub use keeper_rewards::*;
 pub use launch::*;
 pub use position::*;
 pub use vault::*;
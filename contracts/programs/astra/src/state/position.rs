@@ -8,7 +8,7 @@ use anchor_lang::prelude::*;
 /// - Single shares and basis tracking
 /// - Creator vesting uses separate locked_shares field
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Position {
     /// The launch this position is for
     pub launch: Pubkey,
@@ -32,6 +32,13 @@ pub struct Position {
     /// Shares already claimed via vesting
     pub vested_shares_claimed: u64,
 
+    /// ------ REALIZED PNL ------
+    /// Total SOL paid out to the user (sells net of fees, refunds)
+    pub total_sol_withdrawn: u64,
+
+    /// Portion of sol_basis consumed by those withdrawals
+    pub total_basis_withdrawn: u64,
+
     /// ------ CLAIM TRACKING ------
     /// Whether user has claimed their tokens post-graduation
     pub has_claimed_tokens: bool,
@@ -43,6 +50,10 @@ pub struct Position {
     /// When user first bought into this launch
     pub first_buy_at: i64,
 
+    /// Curve spot price (lamports/share) at the first buy, before its own shares
+    /// Entry price for analytics - distinct from the running average (sol_basis / shares)
+    pub entry_price_lamports_per_share: u64,
+
     /// Last activity timestamp
     pub last_updated_at: i64,
 
@@ -51,6 +62,25 @@ pub struct Position {
 }
 
 impl Position {
+    /// Check if this position has never been bought into
+    /// A fresh (init_if_needed) position has first_buy_at == 0
+    pub fn is_new(&self) -> bool {
+        self.first_buy_at == 0
+    }
+
+    /// Stamp the first buy: time and the spot price at the pre-buy supply
+    /// Returns the entry price
+    pub fn record_entry(&mut self, supply_before: u64, now: i64) -> Result<u64> {
+        self.first_buy_at = now;
+        self.entry_price_lamports_per_share = crate::curve::spot_price(supply_before)?;
+        Ok(self.entry_price_lamports_per_share)
+    }
+
+    /// Check if this position holds any shares (unlocked or locked)
+    pub fn is_holder(&self) -> bool {
+        self.shares > 0 || self.locked_shares > 0
+    }
+
     /// Get sellable shares (unlocked only)
     pub fn sellable_shares(&self) -> u64 {
         self.shares
@@ -70,19 +100,133 @@ impl Position {
         Some(refund as u64)
     }
 
+    /// Refund for a pre-graduation sell, guarded against rounding drift
+    /// The final sale returns the whole remaining basis so a full sell-down
+    /// always drains sol_basis to exactly zero
+    pub fn sell_refund(&self, shares_to_sell: u64) -> Result<u64> {
+        let refund = if shares_to_sell == self.shares {
+            self.sol_basis
+        } else {
+            crate::curve::sell_return(shares_to_sell, self.shares, self.sol_basis)?
+        };
+
+        require!(
+            refund <= self.sol_basis,
+            crate::errors::AstraError::InvalidCalculation
+        );
+        Ok(refund)
+    }
+
+    /// Record SOL paid out and the basis it consumed
+    pub fn record_withdrawal(&mut self, sol_out: u64, basis: u64) -> Option<()> {
+        self.total_sol_withdrawn = self.total_sol_withdrawn.checked_add(sol_out)?;
+        self.total_basis_withdrawn = self.total_basis_withdrawn.checked_add(basis)?;
+        Some(())
+    }
+
+    /// Realized PnL in lamports: SOL withdrawn minus the basis it consumed
+    /// Pre-graduation exits are at basis, so this is <= 0 (sell fees) until graduation
+    pub fn realized_pnl(&self) -> i128 {
+        self.total_sol_withdrawn as i128 - self.total_basis_withdrawn as i128
+    }
+
     /// Check if this is the creator's position
     pub fn is_creator(&self, creator: &Pubkey) -> bool {
         self.user == *creator
     }
 
     /// Get unlocked shares (available for claiming tokens)
-    /// For regular users: all shares
-    /// For creator: shares minus locked portion
-    pub fn unlocked_shares(&self, is_creator: bool) -> u64 {
-        if is_creator {
-            self.shares.saturating_sub(self.locked_shares)
-        } else {
-            self.shares
+    /// The creator's unvested seed sits in locked_shares, never in shares,
+    /// so this is `shares` for every position (creator included)
+    pub fn unlocked_shares(&self) -> u64 {
+        self.shares
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_new_on_first_buy_only() {
+        let mut position = Position::default();
+        assert!(position.is_new(), "Fresh position should be new");
+
+        // First buy stamps first_buy_at
+        position.first_buy_at = 1_700_000_000;
+        assert!(!position.is_new(), "Topped-up position should not be new");
+    }
+
+    #[test]
+    fn test_entry_price_is_spot_at_first_buy() {
+        let mut position = Position::default();
+        let mut supply = 250_000_000u64;
+
+        // First buy captures the spot price at the supply it bought into
+        let entry = position.record_entry(supply, 1_700_000_000).unwrap();
+        assert_eq!(entry, crate::curve::spot_price(supply).unwrap());
+        position.shares += crate::curve::buy_return(10_000_000_000, supply).unwrap();
+        supply += position.shares;
+
+        // Top-ups skip record_entry (position is no longer new): entry price stays
+        assert!(!position.is_new());
+        supply += crate::curve::buy_return(5_000_000_000, supply).unwrap();
+        assert_eq!(position.entry_price_lamports_per_share, entry);
+        assert!(crate::curve::spot_price(supply).unwrap() > entry);
+    }
+
+    #[test]
+    fn test_withdrawals_across_buy_sell_buy() {
+        // Buy: 100 shares for 10 SOL
+        let mut position = Position {
+            shares: 100,
+            sol_basis: 10_000_000_000,
+            ..Default::default()
+        };
+
+        // Partial sell: 40 shares at basis
+        let refund = crate::curve::sell_return(40, position.shares, position.sol_basis).unwrap();
+        position.shares -= 40;
+        position.sol_basis -= refund;
+        position.record_withdrawal(refund, refund).unwrap();
+
+        // Buy again: 50 shares for 8 SOL
+        position.shares += 50;
+        position.sol_basis += 8_000_000_000;
+
+        assert_eq!(position.total_sol_withdrawn, 4_000_000_000);
+        assert_eq!(position.total_basis_withdrawn, 4_000_000_000);
+        assert_eq!(position.sol_basis, 14_000_000_000);
+        assert_eq!(position.realized_pnl(), 0);
+    }
+
+    #[test]
+    fn test_realized_pnl_negative_with_sell_fee() {
+        let mut position = Position::default();
+        // 1 SOL of basis sold, 1% fee withheld
+        position.record_withdrawal(990_000_000, 1_000_000_000).unwrap();
+        assert_eq!(position.realized_pnl(), -10_000_000);
+    }
+
+    #[test]
+    fn test_share_by_share_sell_drains_basis() {
+        // 7 shares over 10 lamports: every partial refund rounds down
+        let mut position = Position {
+            shares: 7,
+            sol_basis: 10,
+            ..Default::default()
+        };
+        let mut refunded = 0;
+
+        while position.shares > 0 {
+            let refund = position.sell_refund(1).unwrap();
+            assert!(refund <= position.sol_basis);
+            position.shares -= 1;
+            position.sol_basis -= refund;
+            refunded += refund;
         }
+
+        assert_eq!(position.sol_basis, 0, "Full sell-down must drain the basis");
+        assert_eq!(refunded, 10, "Refunds must sum to the original basis");
     }
 }
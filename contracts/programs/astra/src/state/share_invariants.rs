@@ -0,0 +1,382 @@
+//! Invariant tests for the V7 locked/unlocked share model
+//!
+//! Drives create -> buy/sell -> graduate -> vest -> claim sequences through the
+//! same state methods the handlers use and checks, after every step, that:
+//! - shares are never created or destroyed
+//!   (shares + locked_shares + claimed == total_shares; a slashed seed is
+//!   settled as claimed, its tokens burned)
+//! - total_sol matches the summed basis plus the creator seed SOL
+//! - token claims over the graduation snapshot sum to exactly TOKENS_FOR_HOLDERS
+
+use crate::constants::{TOKENS_FOR_HOLDERS, TOKEN_DECIMALS_SCALE, VESTING_DURATION_SECONDS};
+use crate::curve;
+use crate::state::{Launch, Position};
+use anchor_lang::prelude::*;
+
+const CREATED_AT: i64 = 1_700_000_000;
+const GRADUATED_AT: i64 = CREATED_AT + 86_400;
+const SOL: u64 = crate::constants::LAMPORTS_PER_SOL;
+const HOLDER_TOKENS: u64 = TOKENS_FOR_HOLDERS * TOKEN_DECIMALS_SCALE;
+
+struct Market {
+    launch: Launch,
+    positions: Vec<Position>,
+}
+
+impl Market {
+    /// create_launch: position 0 is the creator
+    fn create(seed_sol: u64, seed_vests: bool) -> Self {
+        let creator = Pubkey::new_unique();
+        let shares = curve::buy_return(seed_sol, 0).unwrap();
+        let mut launch = Launch {
+            creator,
+            total_shares: shares,
+            total_sol: seed_sol,
+            position_count: 1,
+            holder_count: 1,
+            created_at: CREATED_AT,
+            ..Default::default()
+        };
+        let mut position = Position {
+            user: creator,
+            first_buy_at: CREATED_AT,
+            ..Default::default()
+        };
+        launch.assign_seed(&mut position, shares, seed_sol, seed_vests);
+
+        let market = Market {
+            launch,
+            positions: vec![position],
+        };
+        market.assert_conserved();
+        market
+    }
+
+    /// buy: mirrors the handler's position and launch updates (net of fees)
+    fn buy(&mut self, index: usize, net_sol: u64) -> u64 {
+        if index == self.positions.len() {
+            self.positions.push(Position {
+                user: Pubkey::new_unique(),
+                first_buy_at: CREATED_AT,
+                ..Default::default()
+            });
+            self.launch.position_count += 1;
+        }
+        let shares = curve::buy_return(net_sol, self.launch.total_shares).unwrap();
+        let position = &mut self.positions[index];
+        position.shares += shares;
+        position.sol_basis += net_sol;
+        self.launch.total_shares += shares;
+        self.launch.total_sol += net_sol;
+        self.assert_conserved();
+        shares
+    }
+
+    /// sell: position refund at basis, totals drop by the same shares/SOL
+    fn sell(&mut self, index: usize, shares: u64) {
+        let position = &mut self.positions[index];
+        let refund = position.sell_refund(shares).unwrap();
+        position.shares -= shares;
+        position.sol_basis -= refund;
+        self.launch.total_shares -= shares;
+        self.launch.total_sol -= refund;
+        self.assert_conserved();
+    }
+
+    fn graduate(&mut self) {
+        self.launch
+            .mark_graduated(
+                GRADUATED_AT,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            )
+            .unwrap();
+        assert_eq!(self.launch.total_shares_at_graduation, self.summed_shares());
+        self.assert_conserved();
+    }
+
+    fn vest(&mut self, now: i64) -> u64 {
+        let released = self
+            .launch
+            .release_vested_shares(&mut self.positions[0], now)
+            .unwrap();
+        self.assert_conserved();
+        released
+    }
+
+    /// claim_tokens: pays position.shares and zeroes the position
+    fn claim(&mut self, index: usize) -> u64 {
+        let shares = self.positions[index].shares;
+        let amount = self.launch.take_token_claim(shares, None).unwrap();
+        self.positions[index].shares = 0;
+        self.positions[index].has_claimed_tokens = true;
+        self.assert_conserved();
+        amount
+    }
+
+    fn summed_shares(&self) -> u64 {
+        self.positions
+            .iter()
+            .map(|p| p.shares + p.locked_shares)
+            .sum()
+    }
+
+    /// Claimed positions (and a slashed seed) hand their shares to
+    /// holder_shares_claimed
+    fn assert_conserved(&self) {
+        assert_eq!(
+            self.summed_shares() + self.launch.holder_shares_claimed,
+            self.launch.total_shares,
+            "shares created or destroyed"
+        );
+        let report = self
+            .launch
+            .reconcile(Pubkey::default(), &self.positions, CREATED_AT)
+            .unwrap();
+        assert!(
+            report.sol_match,
+            "sol: positions {} vs launch {}",
+            report.summed_sol, report.total_sol
+        );
+    }
+}
+
+/// Creator seed plus a spread of buyers, one of whom trims their position
+fn busy_market(seed_vests: bool) -> Market {
+    let mut market = Market::create(SOL / 10, seed_vests);
+    market.buy(1, 3 * SOL);
+    market.buy(2, SOL / 3);
+    market.buy(3, 7 * SOL + 12_345);
+    market.buy(0, SOL / 7); // creator buys on top of the seed
+    market.buy(2, SOL / 11);
+    let trimmed = market.positions[3].shares / 3;
+    market.sell(3, trimmed);
+    market.buy(4, 1);
+    market.buy(4, 999_999_937);
+    market
+}
+
+#[test]
+fn test_vesting_seed_lifecycle_conserves_shares_and_tokens() {
+    let mut market = busy_market(true);
+    let seed = market.launch.creator_seed_shares;
+    assert!(seed > 0);
+    assert_eq!(market.positions[0].locked_shares, seed);
+
+    market.graduate();
+    let snapshot = market.launch.total_shares_at_graduation;
+
+    // Buyers claim while the seed is still vesting
+    let mut paid = 0;
+    for index in 1..market.positions.len() {
+        paid += market.claim(index);
+    }
+
+    // Partial then final vest: shares only change bucket
+    let half = market.vest(GRADUATED_AT + VESTING_DURATION_SECONDS / 2);
+    assert!(half > 0 && half < seed);
+    let rest = market.vest(GRADUATED_AT + VESTING_DURATION_SECONDS);
+    assert_eq!(half + rest, seed);
+    assert_eq!(market.positions[0].locked_shares, 0);
+    assert_eq!(market.positions[0].vested_shares_claimed, seed);
+    assert_eq!(market.launch.total_shares_at_graduation, snapshot);
+
+    // Creator claims seed + bought shares last and takes the remainder
+    paid += market.claim(0);
+    assert_eq!(paid, HOLDER_TOKENS);
+    assert_eq!(market.launch.holder_shares_claimed, snapshot);
+    assert_eq!(market.launch.holder_tokens_claimed, HOLDER_TOKENS);
+}
+
+#[test]
+fn test_slash_after_partial_vest_only_removes_locked_shares() {
+    let mut market = busy_market(true);
+    market.graduate();
+    let seed = market.launch.creator_seed_shares;
+    let bought = market.positions[0].shares;
+
+    let vested = market.vest(GRADUATED_AT + VESTING_DURATION_SECONDS / 3);
+    assert!(vested > 0 && vested < seed);
+
+    let (slashed, burned) = market
+        .launch
+        .slash_unvested_seed(&mut market.positions[0])
+        .unwrap();
+    market.assert_conserved();
+
+    // The vested portion is earned: shares and the claim record are untouched
+    assert_eq!(slashed, seed - vested);
+    assert_eq!(burned, market.launch.holder_token_amount(slashed).unwrap());
+    assert_eq!(market.positions[0].locked_shares, 0);
+    assert_eq!(market.positions[0].shares, bought + vested);
+    assert_eq!(market.positions[0].vested_shares_claimed, vested);
+
+    // Nothing more vests, and a second slash has nothing to take
+    assert!(market
+        .launch
+        .release_vested_shares(&mut market.positions[0], GRADUATED_AT + VESTING_DURATION_SECONDS)
+        .is_err());
+    assert!(market
+        .launch
+        .slash_unvested_seed(&mut market.positions[0])
+        .is_err());
+
+    // The creator still claims tokens for everything vested before the slash
+    let claimed = market.claim(0);
+    assert_eq!(
+        claimed,
+        market.launch.holder_token_amount(bought + vested).unwrap()
+    );
+}
+
+#[test]
+fn test_claims_sum_to_holder_allocation_in_any_order() {
+    let reference = {
+        let mut market = busy_market(true);
+        market.graduate();
+        market.vest(GRADUATED_AT + VESTING_DURATION_SECONDS);
+        market
+    };
+    let count = reference.positions.len();
+
+    for first in 0..count {
+        let mut market = Market {
+            launch: reference.launch.clone(),
+            positions: reference.positions.clone(),
+        };
+        let order = (0..count).map(|i| (first + i) % count);
+        let paid: u64 = order.map(|index| market.claim(index)).sum();
+        assert_eq!(paid, HOLDER_TOKENS, "claim order starting at {}", first);
+    }
+}
+
+#[test]
+fn test_rounding_dust_goes_to_the_last_claim() {
+    // Three equal holders of an indivisible allocation
+    let mut launch = Launch {
+        graduated: true,
+        total_shares_at_graduation: 3,
+        ..Default::default()
+    };
+    let floor = launch.holder_token_amount(1).unwrap();
+    assert_ne!(floor * 3, HOLDER_TOKENS, "test needs a non-divisible split");
+
+    assert_eq!(launch.take_token_claim(1, None).unwrap(), floor);
+    assert_eq!(launch.take_token_claim(1, None).unwrap(), floor);
+    assert_eq!(
+        launch.take_token_claim(1, None).unwrap(),
+        HOLDER_TOKENS - 2 * floor
+    );
+
+    // Nothing left to claim against the snapshot
+    assert!(launch.take_token_claim(1, None).is_err());
+}
+
+#[test]
+fn test_unvested_seed_stays_reserved() {
+    let mut market = busy_market(true);
+    market.graduate();
+    let seed = market.launch.creator_seed_shares;
+    let bought = market.positions[0].shares;
+
+    // Unlocked shares are only what the creator bought - the seed is not lost
+    assert_eq!(market.positions[0].unlocked_shares(), bought);
+    assert_eq!(market.positions[0].locked_shares, seed);
+
+    // Tokens for the locked seed stay in the launch after everyone else claims
+    let mut paid = 0;
+    for index in 1..market.positions.len() {
+        paid += market.claim(index);
+    }
+    let reserved = HOLDER_TOKENS - paid;
+    let creator_share = market.launch.holder_token_amount(seed + bought).unwrap();
+    assert!(reserved >= creator_share);
+
+    market.vest(GRADUATED_AT + VESTING_DURATION_SECONDS);
+    assert_eq!(market.claim(0), reserved);
+}
+
+#[test]
+fn test_non_vesting_seed_is_plain_shares() {
+    let mut market = busy_market(false);
+    assert_eq!(market.launch.creator_seed_shares, 0);
+    assert_eq!(market.positions[0].locked_shares, 0);
+
+    // Seed can be sold like any other shares before graduation
+    let half = market.positions[0].shares / 2;
+    market.sell(0, half);
+
+    market.graduate();
+    let paid: u64 = (0..market.positions.len())
+        .map(|index| market.claim(index))
+        .sum();
+    assert_eq!(paid, HOLDER_TOKENS);
+}
+
+#[test]
+fn test_full_sell_down_drains_position() {
+    let mut market = busy_market(true);
+    let shares = market.positions[1].shares;
+    market.sell(1, shares);
+    assert_eq!(market.positions[1].shares, 0);
+    assert_eq!(market.positions[1].sol_basis, 0);
+
+    // Creator can only sell bought shares; the locked seed stays counted
+    let bought = market.positions[0].sellable_shares();
+    market.sell(0, bought);
+    assert_eq!(
+        market.positions[0].locked_shares,
+        market.launch.creator_seed_shares
+    );
+}
+
+#[test]
+fn test_refunds_drain_every_share_including_the_seed() {
+    let mut market = busy_market(true);
+    market.launch.enter_refund_mode(GRADUATED_AT).unwrap();
+
+    for position in &market.positions {
+        let available = market.launch.total_sol;
+        market.launch.settle_refund(position, available).unwrap();
+    }
+    assert_eq!(market.launch.total_shares, 0);
+    assert_eq!(market.launch.total_sol, 0);
+}
+
+#[test]
+fn test_claims_after_a_seed_slash_still_sum_to_holder_allocation() {
+    let mut market = busy_market(true);
+    market.graduate();
+    market.vest(GRADUATED_AT + VESTING_DURATION_SECONDS / 4);
+
+    // Slash first, then every holder (creator last) claims
+    let (_, burned) = market
+        .launch
+        .slash_unvested_seed(&mut market.positions[0])
+        .unwrap();
+    let mut paid = burned;
+    for index in (0..market.positions.len()).rev() {
+        paid += market.claim(index);
+    }
+
+    // The final claim takes the rounding remainder: nothing is stranded
+    assert_eq!(
+        market.launch.holder_shares_claimed,
+        market.launch.total_shares_at_graduation
+    );
+    assert_eq!(paid, HOLDER_TOKENS);
+    assert_eq!(market.launch.holder_tokens_claimed, HOLDER_TOKENS);
+}
+
+#[test]
+fn test_seed_slash_needs_graduation() {
+    let mut market = busy_market(true);
+    assert_eq!(
+        market
+            .launch
+            .slash_unvested_seed(&mut market.positions[0])
+            .unwrap_err(),
+        crate::errors::AstraError::NotGraduated.into()
+    );
+}
@@ -15,21 +15,41 @@ pub struct Vault {
     /// Creator receiving 60% of yield
     pub creator: Pubkey,
 
+    /// Alternate wallet for the creator's yield share (DAO treasury, team multisig)
+    /// None = paid to creator; only the creator can change it
+    pub yield_recipient: Option<Pubkey>,
+
     /// LP token mint from Raydium
     pub lp_mint: Pubkey,
 
     /// LP tokens held by this vault
     pub lp_balance: u64,
 
+    /// Pool value per LP token (see Vault::lp_value) when yield was last collected
+    /// Trading fees grow it; poke withdraws the LP that represents the growth
+    pub lp_value_checkpoint: u128,
+
     /// Is vault activated?
     pub activated: bool,
 
+    /// Graduation booster credited with part of the LP (None = unboosted)
+    pub booster: Option<Pubkey>,
+
+    /// LP tokens attributable to the booster's SOL (included in lp_balance)
+    /// Shrinks as poke withdraws the booster's share of fee growth
+    pub booster_lp: u64,
+
+    /// Booster's collected yield held by the vault until claim_booster_yield
+    pub booster_yield_owed: u64,
+
     /// ------ YIELD TRACKING ------
-    pub total_yield_collected: u64,
-    pub total_creator_paid: u64,
-    pub total_protocol_paid: u64,
-    pub total_compounded: u64,
-    pub total_caller_paid: u64,
+    /// Lifetime totals are u128: per-poke amounts are u64, so these can't
+    /// overflow (and brick poke) over any realistic vault lifetime
+    pub total_yield_collected: u128,
+    pub total_creator_paid: u128,
+    pub total_protocol_paid: u128,
+    pub total_compounded: u128,
+    pub total_caller_paid: u128,
 
     /// Last poke timestamp
     pub last_poke_at: i64,
@@ -39,6 +59,31 @@ pub struct Vault {
 }
 
 impl Vault {
+    /// Wallet poke pays the creator's 60% to: the override if set, else the creator
+    pub fn creator_yield_recipient(&self) -> Pubkey {
+        self.yield_recipient.unwrap_or(self.creator)
+    }
+
+    /// Reject a yield recipient poke could never pay
+    /// Executable accounts can't be credited, so every poke would revert
+    pub fn check_yield_recipient(recipient: &Pubkey, executable: bool) -> Result<()> {
+        require!(
+            *recipient != Pubkey::default() && !executable,
+            crate::errors::AstraError::InvalidYieldRecipient
+        );
+        Ok(())
+    }
+
+    /// Set or clear the creator's yield override (None = back to the creator)
+    pub fn set_yield_recipient(&mut self, yield_recipient: Option<Pubkey>) -> Result<()> {
+        require!(
+            yield_recipient != Some(Pubkey::default()),
+            crate::errors::AstraError::InvalidYieldRecipient
+        );
+        self.yield_recipient = yield_recipient;
+        Ok(())
+    }
+
     /// Calculate yield distribution
     ///
     /// Distribution (ADR-001):
@@ -47,11 +92,431 @@ impl Vault {
     /// - Caller: 1%
     /// - Compounded back to LP: 29%
     pub fn calculate_yield_distribution(yield_amount: u64) -> (u64, u64, u64, u64) {
-        let creator = (yield_amount * 60) / 100;
-        let protocol = (yield_amount * 10) / 100;
-        let caller = (yield_amount * 1) / 100;
+        // u128 intermediates: each share is <= yield_amount, so the casts are lossless
+        let share = |percent: u128| ((yield_amount as u128 * percent) / 100) as u64;
+        let creator = share(60);
+        let protocol = share(10);
+        let caller = share(1);
         let compounded = yield_amount - creator - protocol - caller;
 
         (creator, protocol, caller, compounded)
     }
+
+    /// Pool value per LP token: sqrt(reserve_0 * reserve_1) / lp_supply, Q64.64
+    ///
+    /// Swaps keep sqrt(k) constant and deposits/withdrawals move it pro-rata
+    /// with the LP supply, so this only grows as trading fees stay in the pool.
+    pub fn lp_value(reserve_0: u64, reserve_1: u64, lp_supply: u64) -> Result<u128> {
+        require!(lp_supply > 0, crate::errors::AstraError::InvalidCalculation);
+        // sqrt of a u128 product fits in 64 bits, so the shift can't overflow
+        let root = (reserve_0 as u128 * reserve_1 as u128).isqrt();
+        Ok((root << 64) / lp_supply as u128)
+    }
+
+    /// LP tokens whose value is the fee growth since the last checkpoint
+    /// lp_balance * (lp_value - checkpoint) / lp_value, rounded down
+    pub fn fee_lp(&self, lp_value: u128) -> Result<u64> {
+        if self.lp_value_checkpoint == 0 || lp_value <= self.lp_value_checkpoint {
+            return Ok(0);
+        }
+        let growth = (self.lp_balance as u128)
+            .checked_mul(lp_value - self.lp_value_checkpoint)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        Ok((growth / lp_value) as u64)
+    }
+
+    /// `amount * part / whole`, rounded down (splits collected SOL by LP share)
+    pub fn pro_rata(amount: u64, part: u64, whole: u64) -> Result<u64> {
+        require!(whole > 0, crate::errors::AstraError::InvalidCalculation);
+        Ok((amount as u128 * part as u128 / whole as u128) as u64)
+    }
+
+    /// Add one poke's distribution to the lifetime totals
+    pub fn record_yield(
+        &mut self,
+        total_yield: u64,
+        creator_reward: u64,
+        protocol_reward: u64,
+        compounded: u64,
+        caller_reward: u64,
+    ) -> Result<()> {
+        let add = |total: u128, amount: u64| {
+            total
+                .checked_add(amount as u128)
+                .ok_or(crate::errors::AstraError::MathOverflow)
+        };
+        self.total_yield_collected = add(self.total_yield_collected, total_yield)?;
+        self.total_creator_paid = add(self.total_creator_paid, creator_reward)?;
+        self.total_protocol_paid = add(self.total_protocol_paid, protocol_reward)?;
+        self.total_compounded = add(self.total_compounded, compounded)?;
+        self.total_caller_paid = add(self.total_caller_paid, caller_reward)?;
+        Ok(())
+    }
+
+    /// Booster's share of the LP minted at graduation, pro-rata to its SOL
+    /// lp_minted * boosted_sol / (curve_sol + boosted_sol), rounded down
+    pub fn booster_lp_share(lp_minted: u64, curve_sol: u64, boosted_sol: u64) -> Result<u64> {
+        if boosted_sol == 0 {
+            return Ok(0);
+        }
+        let pool_sol = (curve_sol as u128)
+            .checked_add(boosted_sol as u128)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        let share = (lp_minted as u128)
+            .checked_mul(boosted_sol as u128)
+            .and_then(|v| v.checked_div(pool_sol))
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        Ok(share as u64)
+    }
+
+    /// Booster's pro-rata part of `amount` (fee LP or yield): amount * booster_lp / lp_balance
+    pub fn booster_part(&self, amount: u64) -> Result<u64> {
+        if self.booster_lp == 0 {
+            return Ok(0);
+        }
+        Self::pro_rata(amount, self.booster_lp, self.lp_balance)
+    }
+
+    /// Hold one poke's booster yield for claim_booster_yield
+    /// `booster_fee_lp` is the booster LP that poke withdrew to pay it
+    pub fn accrue_booster_yield(&mut self, amount: u64, booster_fee_lp: u64) -> Result<()> {
+        self.booster_lp = self
+            .booster_lp
+            .checked_sub(booster_fee_lp)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        self.booster_yield_owed = self
+            .booster_yield_owed
+            .checked_add(amount)
+            .ok_or(crate::errors::AstraError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Zero and return the booster's owed yield
+    pub fn take_booster_yield(&mut self, booster: &Pubkey) -> Result<u64> {
+        use crate::errors::AstraError;
+
+        require!(self.booster == Some(*booster), AstraError::BoosterMismatch);
+        let amount = std::mem::take(&mut self.booster_yield_owed);
+        require!(amount > 0, AstraError::NoFeesToClaim);
+        Ok(amount)
+    }
+
+    /// Reject a vault graduation hasn't finished setting up
+    /// Poke never distributes against a half-initialized vault
+    pub fn check_active(&self) -> Result<()> {
+        require!(self.activated, crate::errors::AstraError::VaultNotActivated);
+        Ok(())
+    }
+
+    /// Check the vault is wound down (no tracked LP and an empty LP token account)
+    /// Unclaimed booster yield keeps it open
+    pub fn can_close(&self, lp_token_amount: u64) -> bool {
+        self.lp_balance == 0 && lp_token_amount == 0 && self.booster_yield_owed == 0
+    }
+
+    /// Build the split preview for a pending yield amount (read-only)
+    /// The booster's part comes off the top; ADR-001 splits the rest
+    pub fn preview_poke(
+        vault: Pubkey,
+        pending_yield: u64,
+        booster_reward: u64,
+    ) -> crate::events::PokePreview {
+        let (creator_reward, protocol_reward, caller_reward, compound) =
+            Self::calculate_yield_distribution(pending_yield.saturating_sub(booster_reward));
+
+        crate::events::PokePreview {
+            vault,
+            pending_yield,
+            caller_reward,
+            creator_reward,
+            protocol_reward,
+            compound,
+            booster_reward,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault(lp_balance: u64) -> Vault {
+        Vault {
+            launch: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            yield_recipient: None,
+            lp_mint: Pubkey::new_unique(),
+            lp_balance,
+            lp_value_checkpoint: 0,
+            activated: true,
+            booster: None,
+            booster_lp: 0,
+            booster_yield_owed: 0,
+            total_yield_collected: 0,
+            total_creator_paid: 0,
+            total_protocol_paid: 0,
+            total_compounded: 0,
+            total_caller_paid: 0,
+            last_poke_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_creator_yield_routes_to_override() {
+        let mut vault = vault(1_000);
+        assert_eq!(vault.creator_yield_recipient(), vault.creator);
+
+        let dao = Pubkey::new_unique();
+        vault.set_yield_recipient(Some(dao)).unwrap();
+        assert_eq!(vault.creator_yield_recipient(), dao);
+
+        // Clearing the override routes yield back to the creator
+        vault.set_yield_recipient(None).unwrap();
+        assert_eq!(vault.creator_yield_recipient(), vault.creator);
+
+        assert_eq!(
+            vault.set_yield_recipient(Some(Pubkey::default())).unwrap_err(),
+            crate::errors::AstraError::InvalidYieldRecipient.into()
+        );
+    }
+
+    #[test]
+    fn test_executable_yield_recipient_rejected() {
+        let wallet = Pubkey::new_unique();
+        assert!(Vault::check_yield_recipient(&wallet, false).is_ok());
+
+        // A program would brick poke: its lamports can't be credited
+        assert_eq!(
+            Vault::check_yield_recipient(&crate::ID, true).unwrap_err(),
+            crate::errors::AstraError::InvalidYieldRecipient.into()
+        );
+        assert_eq!(
+            Vault::check_yield_recipient(&Pubkey::default(), false).unwrap_err(),
+            crate::errors::AstraError::InvalidYieldRecipient.into()
+        );
+    }
+
+    #[test]
+    fn test_lp_value_tracks_fee_growth_only() {
+        // Fresh pool: Raydium mints sqrt(k) LP, so one LP is worth exactly 1.0
+        let opening = Vault::lp_value(100_000_000, 400_000_000, 200_000_000).unwrap();
+        assert_eq!(opening, 1 << 64);
+
+        // A fee-free swap keeps k (to rounding): no growth
+        let swapped = Vault::lp_value(200_000_000, 200_000_000, 200_000_000).unwrap();
+        assert_eq!(swapped, opening);
+
+        // A proportional withdrawal of half the LP doesn't move it either
+        let withdrawn = Vault::lp_value(50_000_000, 200_000_000, 100_000_000).unwrap();
+        assert_eq!(withdrawn, opening);
+
+        assert!(Vault::lp_value(1, 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_fee_lp_is_the_growth_since_checkpoint() {
+        let mut vault = vault(1_000_000);
+        let opening = Vault::lp_value(100_000_000, 400_000_000, 200_000_000).unwrap();
+
+        // No checkpoint yet (or no growth): nothing to collect
+        assert_eq!(vault.fee_lp(opening).unwrap(), 0);
+        vault.lp_value_checkpoint = opening;
+        assert_eq!(vault.fee_lp(opening).unwrap(), 0);
+
+        // Fees grew sqrt(k) by 1%: ~1/101 of the vault's LP is yield
+        let grown = Vault::lp_value(101_000_000, 404_000_000, 200_000_000).unwrap();
+        assert_eq!(vault.fee_lp(grown).unwrap(), 9_900);
+
+        // Remaining LP at the new value is worth what the vault held at the checkpoint
+        let remaining = (1_000_000 - 9_900) as u128 * grown;
+        assert!(remaining >= 1_000_000 * opening);
+    }
+
+    #[test]
+    fn test_pro_rata_split() {
+        assert_eq!(Vault::pro_rata(710, 600, 710).unwrap(), 600);
+        assert_eq!(Vault::pro_rata(u64::MAX, 1, 2).unwrap(), u64::MAX / 2);
+        assert!(Vault::pro_rata(1, 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_booster_lp_attribution() {
+        // 80 SOL from the curve + 20 SOL boost: booster owns 20% of the LP
+        assert_eq!(
+            Vault::booster_lp_share(1_000_000, 80_000_000_000, 20_000_000_000).unwrap(),
+            200_000
+        );
+        assert_eq!(Vault::booster_lp_share(1_000_000, 80, 0).unwrap(), 0);
+        // Rounds down in the holders' favour
+        assert_eq!(Vault::booster_lp_share(10, 2, 1).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_pokes_past_u64_ceiling_do_not_brick() {
+        let mut vault = vault(1_000);
+        let near_max = u64::MAX as u128 - 10;
+        vault.total_yield_collected = near_max;
+        vault.total_creator_paid = near_max;
+        vault.total_protocol_paid = near_max;
+        vault.total_compounded = near_max;
+        vault.total_caller_paid = near_max;
+
+        // Two max-size pokes carry every total past u64::MAX
+        for _ in 0..2 {
+            let (creator, protocol, caller, compounded) =
+                Vault::calculate_yield_distribution(u64::MAX);
+            vault
+                .record_yield(u64::MAX, creator, protocol, compounded, caller)
+                .unwrap();
+        }
+
+        assert_eq!(vault.total_yield_collected, near_max + 2 * u64::MAX as u128);
+        assert!(vault.total_creator_paid > u64::MAX as u128);
+        assert!(vault.total_protocol_paid > u64::MAX as u128);
+        assert!(vault.total_compounded > u64::MAX as u128);
+        assert!(vault.total_caller_paid > u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_record_yield_accumulates_split() {
+        let mut vault = vault(1_000);
+        let (creator, protocol, caller, compounded) =
+            Vault::calculate_yield_distribution(1_000_000);
+        vault
+            .record_yield(1_000_000, creator, protocol, compounded, caller)
+            .unwrap();
+
+        assert_eq!(vault.total_yield_collected, 1_000_000);
+        assert_eq!(vault.total_creator_paid, 600_000);
+        assert_eq!(vault.total_protocol_paid, 100_000);
+        assert_eq!(vault.total_caller_paid, 10_000);
+        assert_eq!(vault.total_compounded, 290_000);
+    }
+
+    #[test]
+    fn test_emptied_vault_can_close() {
+        assert!(vault(0).can_close(0));
+    }
+
+    #[test]
+    fn test_active_vault_cannot_close() {
+        // Tracked LP outstanding
+        assert!(!vault(1_000).can_close(0));
+        // LP tokens still in the account (e.g. sent in after migration)
+        assert!(!vault(0).can_close(1));
+    }
+
+    #[test]
+    fn test_poke_rejects_inactive_vault() {
+        let mut v = vault(1_000);
+        assert!(v.check_active().is_ok());
+
+        v.activated = false;
+        assert_eq!(
+            v.check_active().unwrap_err(),
+            crate::errors::AstraError::VaultNotActivated.into()
+        );
+
+        // Both poke and its preview run the check before touching the pool
+        for source in [
+            include_str!("../instructions/poke.rs"),
+            include_str!("../instructions/preview_poke.rs"),
+        ] {
+            let handler = source.split("pub fn handler").nth(1).unwrap();
+            assert!(handler.contains("vault.check_active()?"));
+        }
+    }
+
+    #[test]
+    fn test_preview_split_for_mocked_yield() {
+        let key = Pubkey::new_unique();
+        let preview = Vault::preview_poke(key, 1_000_000, 0);
+
+        assert_eq!(preview.vault, key);
+        assert_eq!(preview.pending_yield, 1_000_000);
+        assert_eq!(preview.caller_reward, 10_000);
+        assert_eq!(preview.creator_reward, 600_000);
+        assert_eq!(preview.protocol_reward, 100_000);
+        assert_eq!(preview.compound, 290_000);
+        assert_eq!(preview.booster_reward, 0);
+    }
+
+    #[test]
+    fn test_booster_paid_pro_rata_before_split() {
+        // Booster owns 20% of the vault's LP
+        let mut v = Vault {
+            booster: Some(Pubkey::new_unique()),
+            booster_lp: 200_000,
+            ..vault(1_000_000)
+        };
+        assert_eq!(v.booster_part(10_000).unwrap(), 2_000);
+
+        let preview = Vault::preview_poke(Pubkey::new_unique(), 1_000_000, 200_000);
+        assert_eq!(preview.booster_reward, 200_000);
+        assert_eq!(preview.creator_reward, 480_000);
+        assert_eq!(
+            preview.caller_reward
+                + preview.creator_reward
+                + preview.protocol_reward
+                + preview.compound
+                + preview.booster_reward,
+            1_000_000
+        );
+
+        // The withdrawn booster LP leaves the attribution; the SOL is held for claim
+        v.accrue_booster_yield(5_000, 2_000).unwrap();
+        assert_eq!(v.booster_lp, 198_000);
+        assert_eq!(v.booster_yield_owed, 5_000);
+        assert!(v.accrue_booster_yield(0, 198_001).is_err());
+    }
+
+    #[test]
+    fn test_unboosted_vault_pays_no_booster() {
+        assert_eq!(vault(1_000_000).booster_part(10_000).unwrap(), 0);
+        assert_eq!(vault(0).booster_part(10_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_only_booster_claims_owed_yield() {
+        let booster = Pubkey::new_unique();
+        let mut v = Vault {
+            booster: Some(booster),
+            booster_yield_owed: 7_000,
+            ..vault(0)
+        };
+        // Owed yield keeps the vault open
+        assert!(!v.can_close(0));
+        assert_eq!(
+            v.take_booster_yield(&Pubkey::new_unique()).unwrap_err(),
+            crate::errors::AstraError::BoosterMismatch.into()
+        );
+        assert_eq!(v.take_booster_yield(&booster).unwrap(), 7_000);
+        assert_eq!(
+            v.take_booster_yield(&booster).unwrap_err(),
+            crate::errors::AstraError::NoFeesToClaim.into()
+        );
+        assert!(v.can_close(0));
+    }
+
+    #[test]
+    fn test_preview_dust_and_extreme_yield() {
+        // Dust: caller reward rounds to zero, the remainder compounds
+        let dust = Vault::preview_poke(Pubkey::new_unique(), 99, 0);
+        assert_eq!(dust.caller_reward, 0);
+        assert_eq!(
+            dust.caller_reward + dust.creator_reward + dust.protocol_reward + dust.compound,
+            99
+        );
+
+        // No overflow at the top of the range, and nothing is lost
+        let max = Vault::preview_poke(Pubkey::new_unique(), u64::MAX, 0);
+        assert_eq!(
+            max.caller_reward as u128
+                + max.creator_reward as u128
+                + max.protocol_reward as u128
+                + max.compound as u128,
+            u64::MAX as u128
+        );
+    }
 }
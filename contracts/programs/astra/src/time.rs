@@ -0,0 +1,75 @@
+//! Checked timestamp arithmetic
+//!
+//! All i64 `unix_timestamp` math goes through these helpers so time
+//! calculations meet the same overflow standard as the rest of the program.
+
+use crate::errors::AstraError;
+use anchor_lang::prelude::*;
+
+/// Seconds elapsed from `start` to `now`
+///
+/// # Errors
+/// * `MathOverflow` - If the subtraction overflows i64
+pub fn safe_elapsed(now: i64, start: i64) -> Result<i64> {
+    now.checked_sub(start)
+        .ok_or_else(|| AstraError::MathOverflow.into())
+}
+
+/// Timestamp `duration` seconds after `start`
+///
+/// # Errors
+/// * `MathOverflow` - If the addition overflows i64
+pub fn safe_deadline(start: i64, duration: i64) -> Result<i64> {
+    start
+        .checked_add(duration)
+        .ok_or_else(|| AstraError::MathOverflow.into())
+}
+
+/// Reject a transaction submitted with a `deadline` that has passed
+/// None = no deadline; the deadline second itself is still valid
+///
+/// # Errors
+/// * `DeadlineExceeded` - If `now` is after `deadline`
+pub fn check_deadline(deadline: Option<i64>, now: i64) -> Result<()> {
+    require!(
+        deadline.is_none_or(|deadline| now <= deadline),
+        AstraError::DeadlineExceeded
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_elapsed() {
+        assert_eq!(safe_elapsed(1_000, 400).unwrap(), 600);
+        assert_eq!(safe_elapsed(400, 1_000).unwrap(), -600);
+    }
+
+    #[test]
+    fn test_safe_elapsed_extreme_timestamps() {
+        assert!(safe_elapsed(i64::MAX, -1).is_err());
+        assert!(safe_elapsed(i64::MIN, 1).is_err());
+        assert_eq!(safe_elapsed(i64::MAX, 0).unwrap(), i64::MAX);
+    }
+
+    #[test]
+    fn test_safe_deadline_extreme_timestamps() {
+        assert_eq!(safe_deadline(1_000, 600).unwrap(), 1_600);
+        assert!(safe_deadline(i64::MAX, 1).is_err());
+        assert!(safe_deadline(i64::MIN, -1).is_err());
+    }
+
+    #[test]
+    fn test_expired_deadline_rejected() {
+        assert!(check_deadline(None, i64::MAX).is_ok());
+        assert!(check_deadline(Some(1_000), 999).is_ok());
+        assert!(check_deadline(Some(1_000), 1_000).is_ok());
+        assert_eq!(
+            check_deadline(Some(1_000), 1_001).unwrap_err(),
+            AstraError::DeadlineExceeded.into()
+        );
+    }
+}
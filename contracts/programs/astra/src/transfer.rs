@@ -0,0 +1,157 @@
+//! Outbound SOL transfers from program-owned PDAs
+//!
+//! The system program can only debit system-owned accounts, so SOL leaving a
+//! data-carrying PDA (launch, fee escrow) has to move by adjusting lamports
+//! directly. Inbound transfers from wallets keep using `system_program::transfer`.
+//! Every outbound transfer goes through here so none can dip below rent.
+
+use crate::errors::AstraError;
+use anchor_lang::prelude::*;
+
+/// Balance left after withdrawing `amount`, rejecting anything below `rent_floor`
+///
+/// # Errors
+/// * `InsufficientFunds` - If the withdrawal exceeds the balance or breaches rent
+pub fn balance_after_withdrawal(balance: u64, amount: u64, rent_floor: u64) -> Result<u64> {
+    let remaining = balance
+        .checked_sub(amount)
+        .ok_or(AstraError::InsufficientFunds)?;
+    require!(remaining >= rent_floor, AstraError::InsufficientFunds);
+    Ok(remaining)
+}
+
+/// Split a closing account's balance into (dust above rent, rent)
+///
+/// The dust is residual value (refund rounding, stray transfers) and goes to
+/// the treasury; only the rent itself is the janitor's reward for closing.
+pub fn split_close_balance(balance: u64, rent_floor: u64) -> (u64, u64) {
+    let dust = balance.saturating_sub(rent_floor);
+    (dust, balance - dust)
+}
+
+/// Lamports a program-owned PDA can pay out while staying rent-exempt
+pub fn withdrawable_lamports(account: &AccountInfo) -> Result<u64> {
+    let rent_floor = Rent::get()?.minimum_balance(account.data_len());
+    Ok(split_close_balance(account.lamports(), rent_floor).0)
+}
+
+/// Reject a payout of `amount` that would take `balance` below `rent_floor`
+///
+/// Same bound transfer_from_pda enforces, checked up front so a handler
+/// fails with a clear error before it touches any state.
+///
+/// # Errors
+/// * `InsufficientLiquidity` - If the payout would breach the rent floor
+pub fn ensure_withdrawable(balance: u64, amount: u64, rent_floor: u64) -> Result<()> {
+    require!(
+        amount <= split_close_balance(balance, rent_floor).0,
+        AstraError::InsufficientLiquidity
+    );
+    Ok(())
+}
+
+/// Move `amount` lamports out of a program-owned PDA (launch, fee escrow, bonds, keeper rewards)
+///
+/// The source keeps at least the rent-exempt minimum for its data length.
+///
+/// # Errors
+/// * `InsufficientFunds` - If the transfer would leave the source below rent
+/// * `MathOverflow` - If crediting the recipient overflows
+pub fn transfer_from_pda<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let rent_floor = Rent::get()?.minimum_balance(from.data_len());
+    let remaining = balance_after_withdrawal(from.lamports(), amount, rent_floor)?;
+    let credited = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(AstraError::MathOverflow)?;
+
+    **from.try_borrow_mut_lamports()? = remaining;
+    **to.try_borrow_mut_lamports()? = credited;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RENT: u64 = 2_000_000;
+
+    #[test]
+    fn test_withdrawal_down_to_rent_floor() {
+        assert_eq!(
+            balance_after_withdrawal(RENT + 500, 500, RENT).unwrap(),
+            RENT
+        );
+    }
+
+    #[test]
+    fn test_withdrawal_cannot_dip_below_rent() {
+        assert_eq!(
+            balance_after_withdrawal(RENT + 500, 501, RENT).unwrap_err(),
+            AstraError::InsufficientFunds.into()
+        );
+    }
+
+    #[test]
+    fn test_withdrawal_exceeding_balance() {
+        assert_eq!(
+            balance_after_withdrawal(RENT, RENT + 1, 0).unwrap_err(),
+            AstraError::InsufficientFunds.into()
+        );
+    }
+
+    #[test]
+    fn test_payout_down_to_rent_floor_is_allowed() {
+        assert!(ensure_withdrawable(RENT + 500, 500, RENT).is_ok());
+        assert!(ensure_withdrawable(RENT, 0, RENT).is_ok());
+    }
+
+    #[test]
+    fn test_payout_below_rent_floor_is_a_clean_error() {
+        assert_eq!(
+            ensure_withdrawable(RENT + 500, 501, RENT).unwrap_err(),
+            AstraError::InsufficientLiquidity.into()
+        );
+        // Already at the floor: nothing can leave
+        assert_eq!(
+            ensure_withdrawable(RENT, 1, RENT).unwrap_err(),
+            AstraError::InsufficientLiquidity.into()
+        );
+    }
+
+    #[test]
+    fn test_close_dust_above_rent_goes_to_treasury() {
+        // Rounding dust left after the last refund
+        let (dust, rent) = split_close_balance(RENT + 37, RENT);
+        assert_eq!(dust, 37, "treasury takes everything above rent");
+        assert_eq!(rent, RENT, "janitor gets exactly the rent");
+
+        // After sweeping, the account closes with only rent left
+        let after_sweep = balance_after_withdrawal(RENT + 37, dust, RENT).unwrap();
+        assert_eq!(after_sweep, rent);
+
+        // Pure rent: nothing to sweep
+        assert_eq!(split_close_balance(RENT, RENT), (0, RENT));
+        // Under-funded rent (shouldn't happen): nothing invented
+        assert_eq!(split_close_balance(RENT - 1, RENT), (0, RENT - 1));
+    }
+
+    #[test]
+    fn test_sequential_withdrawals_respect_rent() {
+        // Sell pays the seller then the fee wallet - the pair can't drain rent either
+        let after_refund = balance_after_withdrawal(RENT + 1_000, 900, RENT).unwrap();
+        assert!(balance_after_withdrawal(after_refund, 101, RENT).is_err());
+        assert_eq!(
+            balance_after_withdrawal(after_refund, 100, RENT).unwrap(),
+            RENT
+        );
+    }
+}
@@ -0,0 +1,616 @@
+//! State-machine harness for the launch lifecycle
+//!
+//! Active -> {Graduated | Refunding} -> Closed, with the graduation lock as a
+//! sub-state of Active. Launches are written into in-memory accounts and
+//! driven through the program's entrypoint, so every move runs the real
+//! instruction: account constraints, handler checks and state writes.
+//!
+//! A syscall stub supplies the clock and rent. CPIs only run on-chain, so
+//! moves that reach one (buy, boost) are driven up to their rejection, and
+//! Graduated is reached through `Launch::mark_graduated` (what graduate
+//! calls after the pool CPI); graduate's own gates are covered by the unit
+//! tests in state/launch.rs.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::SUCCESS;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_lang::{system_program, InstructionData, Space};
+use astra::constants::{LAMPORTS_PER_SOL, LAUNCH_DURATION_SECONDS};
+use astra::errors::AstraError;
+use astra::instructions::{BuyArgs, SellArgs};
+use astra::state::{CreatorStats, FeeEscrow, GlobalConfig, Launch, PauseReason, Position};
+use solana_sysvar::program_stubs::{set_syscall_stubs, SyscallStubs};
+use std::sync::Once;
+
+/// The clock every instruction sees: the launch window has just expired
+const CREATED_AT: i64 = 1_700_000_000;
+const NOW: i64 = CREATED_AT + LAUNCH_DURATION_SECONDS;
+
+/// Prices the harness launch above GRADUATION_MARKET_CAP_USD
+const SOL_PRICE_USD: u64 = 500;
+
+struct Sysvars;
+
+impl SyscallStubs for Sysvars {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW,
+            ..Clock::default()
+        };
+        unsafe { std::ptr::write_unaligned(var_addr as *mut Clock, clock) };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { std::ptr::write_unaligned(var_addr as *mut Rent, Rent::default()) };
+        SUCCESS
+    }
+}
+
+fn install_sysvars() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        set_syscall_stubs(Box::new(Sysvars));
+    });
+}
+
+/// Bytes before an account's data in the runtime's input layout
+/// (AccountInfo::resize writes the new length there)
+const DATA_HEADER: usize = 8;
+
+/// Key with the original data length in the 4 bytes before it, where
+/// AccountInfo::original_data_len reads it
+#[repr(C)]
+struct KeySlot {
+    original_data_len: u32,
+    key: Pubkey,
+}
+
+struct TestAccount {
+    slot: KeySlot,
+    owner: Pubkey,
+    lamports: u64,
+    buffer: Vec<u8>,
+    data_len: usize,
+    executable: bool,
+}
+
+impl TestAccount {
+    fn new(key: Pubkey, owner: Pubkey, lamports: u64, data: &[u8]) -> Self {
+        let mut buffer = vec![0; DATA_HEADER];
+        buffer.extend_from_slice(data);
+        TestAccount {
+            slot: KeySlot {
+                original_data_len: data.len() as u32,
+                key,
+            },
+            owner,
+            lamports,
+            buffer,
+            data_len: data.len(),
+            executable: false,
+        }
+    }
+
+    fn wallet(key: Pubkey) -> Self {
+        Self::new(key, system_program::ID, 100 * LAMPORTS_PER_SOL, &[])
+    }
+
+    /// A rent-exempt program account holding `value`, plus `extra` lamports
+    fn program<T: AccountSerialize + Space>(key: Pubkey, value: &T, extra: u64) -> Self {
+        let mut data = vec![0; 8 + T::INIT_SPACE];
+        value.try_serialize(&mut data.as_mut_slice()).unwrap();
+        let rent = Rent::default().minimum_balance(data.len());
+        Self::new(key, astra::ID, rent + extra, &data)
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.buffer[DATA_HEADER..DATA_HEADER + self.data_len]
+    }
+}
+
+/// A launch (creator seed plus one buyer) and every account its instructions touch
+struct Harness {
+    accounts: Vec<TestAccount>,
+    config: Pubkey,
+    launch: Pubkey,
+    authority: Pubkey,
+    operator: Pubkey,
+    fee_wallet: Pubkey,
+    creator: Pubkey,
+    buyer: Pubkey,
+    booster: Pubkey,
+}
+
+impl Harness {
+    /// Active: the creator seed plus one buyer, graduation-ready but unlocked
+    fn active() -> Self {
+        let (authority, operator, fee_wallet) = (key(), key(), key());
+        let (creator, buyer, booster) = (key(), key(), key());
+        let launch_key = key();
+        let (config_key, config_bump) = pda(&[b"config"]);
+
+        let config = GlobalConfig {
+            authority,
+            operator_wallet: operator,
+            protocol_fee_wallet: fee_wallet,
+            sol_price_usd: SOL_PRICE_USD,
+            min_holders_for_graduation: 1,
+            bump: config_bump,
+            ..Default::default()
+        };
+
+        let seed_sol = LAMPORTS_PER_SOL / 10;
+        let seed_shares = astra::curve::buy_return(seed_sol, 0).unwrap();
+        let mut launch = Launch {
+            creator,
+            total_shares: seed_shares,
+            total_sol: seed_sol,
+            position_count: 1,
+            holder_count: 1,
+            holder_count_backfilled: true,
+            created_at: CREATED_AT,
+            ..Default::default()
+        };
+        let mut seed = Position {
+            user: creator,
+            ..Default::default()
+        };
+        launch.assign_seed(&mut seed, seed_shares, seed_sol, true);
+
+        let buy_sol = 100 * LAMPORTS_PER_SOL;
+        let bought = astra::curve::buy_return(buy_sol, launch.total_shares).unwrap();
+        let bought_position = Position {
+            user: buyer,
+            shares: bought,
+            sol_basis: buy_sol,
+            ..Default::default()
+        };
+        launch.total_shares += bought;
+        launch.total_sol += buy_sol;
+        launch.position_count += 1;
+        launch.holder_count += 1;
+        launch.record_holder_shares(&buyer, bought);
+
+        let (stats_key, stats_bump) = pda(&[b"creator_stats", creator.as_ref()]);
+        let (escrow_key, escrow_bump) = pda(&[b"fee_escrow", launch_key.as_ref()]);
+        let mut accounts = vec![
+            TestAccount::program(config_key, &config, 0),
+            TestAccount::program(launch_key, &launch, launch.total_sol),
+            TestAccount::program(
+                stats_key,
+                &CreatorStats {
+                    creator,
+                    bump: stats_bump,
+                    ..Default::default()
+                },
+                0,
+            ),
+            TestAccount::program(
+                escrow_key,
+                &FeeEscrow {
+                    launch: launch_key,
+                    bump: escrow_bump,
+                },
+                0,
+            ),
+            TestAccount {
+                executable: true,
+                ..TestAccount::new(system_program::ID, Pubkey::default(), 1, &[])
+            },
+        ];
+        for mut position in [seed, bought_position] {
+            let (position_key, bump) =
+                pda(&[b"position", launch_key.as_ref(), position.user.as_ref()]);
+            position.launch = launch_key;
+            position.bump = bump;
+            accounts.push(TestAccount::program(position_key, &position, 0));
+        }
+        for wallet in [authority, operator, fee_wallet, creator, buyer, booster] {
+            accounts.push(TestAccount::wallet(wallet));
+        }
+
+        Harness {
+            accounts,
+            config: config_key,
+            launch: launch_key,
+            authority,
+            operator,
+            fee_wallet,
+            creator,
+            buyer,
+            booster,
+        }
+    }
+
+    /// Locked: lock_graduation froze the pool for graduate
+    fn locked() -> Self {
+        let mut harness = Self::active();
+        harness.lock_graduation().unwrap();
+        harness
+    }
+
+    /// Graduated: the state graduate writes once the pool exists
+    fn graduated() -> Self {
+        let mut harness = Self::locked();
+        let mut launch = harness.launch();
+        launch.mark_graduated(NOW, key(), key(), key()).unwrap();
+        harness.write(harness.launch, &launch);
+        harness
+    }
+
+    /// Refunding: enable_refund after the launch window expired
+    fn refunding() -> Self {
+        let mut harness = Self::active();
+        harness.enable_refund().unwrap();
+        harness
+    }
+
+    fn account(&self, key: Pubkey) -> &TestAccount {
+        self.accounts
+            .iter()
+            .find(|account| account.slot.key == key)
+            .unwrap()
+    }
+
+    fn launch(&self) -> Launch {
+        Launch::try_deserialize(&mut self.account(self.launch).data()).unwrap()
+    }
+
+    fn position(&self, user: Pubkey) -> Position {
+        let key = self.position_key(user);
+        Position::try_deserialize(&mut self.account(key).data()).unwrap()
+    }
+
+    fn position_key(&self, user: Pubkey) -> Pubkey {
+        pda(&[b"position", self.launch.as_ref(), user.as_ref()]).0
+    }
+
+    fn write<T: AccountSerialize>(&mut self, key: Pubkey, value: &T) {
+        let account = self
+            .accounts
+            .iter_mut()
+            .find(|account| account.slot.key == key)
+            .unwrap();
+        let data = &mut account.buffer[DATA_HEADER..DATA_HEADER + account.data_len];
+        value.try_serialize(&mut &mut data[..]).unwrap();
+    }
+
+    fn set_config(&mut self, edit: impl FnOnce(&mut GlobalConfig)) {
+        let mut config =
+            GlobalConfig::try_deserialize(&mut self.account(self.config).data()).unwrap();
+        edit(&mut config);
+        self.write(self.config, &config);
+    }
+
+    /// Run one instruction through the program entrypoint; state persists on success
+    fn run(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        ix: impl InstructionData,
+    ) -> std::result::Result<(), ProgramError> {
+        install_sysvars();
+        let metas = accounts.to_account_metas(None);
+
+        let infos: Vec<AccountInfo> = self
+            .accounts
+            .iter_mut()
+            .map(|account| {
+                let TestAccount {
+                    slot,
+                    owner,
+                    lamports,
+                    buffer,
+                    data_len,
+                    executable,
+                } = account;
+                slot.original_data_len = *data_len as u32;
+                let key = &slot.key;
+                let is_signer = metas
+                    .iter()
+                    .any(|meta| meta.pubkey == *key && meta.is_signer);
+                let is_writable = metas
+                    .iter()
+                    .any(|meta| meta.pubkey == *key && meta.is_writable);
+                AccountInfo::new(
+                    key,
+                    is_signer,
+                    is_writable,
+                    lamports,
+                    &mut buffer[DATA_HEADER..DATA_HEADER + *data_len],
+                    owner,
+                    *executable,
+                    0,
+                )
+            })
+            .collect();
+        let ordered: Vec<AccountInfo> = metas
+            .iter()
+            .map(|meta| {
+                infos
+                    .iter()
+                    .find(|info| *info.key == meta.pubkey)
+                    .unwrap_or_else(|| panic!("no account {}", meta.pubkey))
+                    .clone()
+            })
+            .collect();
+
+        let result = astra::entry(&astra::ID, &ordered, &ix.data());
+        let data_lens: Vec<usize> = infos.iter().map(AccountInfo::data_len).collect();
+
+        // A closed account was resized to 0
+        for (account, data_len) in self.accounts.iter_mut().zip(data_lens) {
+            account.data_len = data_len;
+        }
+        result
+    }
+
+    fn buy(&mut self, sol_amount: u64) -> std::result::Result<(), ProgramError> {
+        let accounts = astra::accounts::Buy {
+            buyer: self.buyer,
+            config: self.config,
+            launch: self.launch,
+            position: self.position_key(self.buyer),
+            creator_stats: pda(&[b"creator_stats", self.creator.as_ref()]).0,
+            fee_escrow: self.fee_escrow(),
+            protocol_fee_wallet: self.fee_wallet,
+            system_program: system_program::ID,
+        };
+        let args = BuyArgs {
+            sol_amount,
+            min_shares_out: 1,
+        };
+        self.run(accounts, astra::instruction::Buy { args })
+    }
+
+    fn sell(&mut self, shares_to_sell: u64) -> std::result::Result<(), ProgramError> {
+        let accounts = astra::accounts::Sell {
+            seller: self.buyer,
+            config: self.config,
+            launch: self.launch,
+            position: self.position_key(self.buyer),
+            protocol_fee_wallet: self.fee_wallet,
+            system_program: system_program::ID,
+        };
+        let args = SellArgs {
+            shares_to_sell,
+            min_sol_out: 0,
+            deadline: None,
+        };
+        self.run(accounts, astra::instruction::Sell { args })
+    }
+
+    fn boost(&mut self, amount: u64) -> std::result::Result<(), ProgramError> {
+        let accounts = astra::accounts::BoostGraduation {
+            booster: self.booster,
+            config: self.config,
+            launch: self.launch,
+            system_program: system_program::ID,
+        };
+        self.run(accounts, astra::instruction::BoostGraduation { amount })
+    }
+
+    fn lock_graduation(&mut self) -> std::result::Result<(), ProgramError> {
+        let accounts = astra::accounts::LockGraduation {
+            operator: self.operator,
+            config: self.config,
+            launch: self.launch,
+        };
+        self.run(accounts, astra::instruction::LockGraduation {})
+    }
+
+    fn enable_refund(&mut self) -> std::result::Result<(), ProgramError> {
+        let accounts = astra::accounts::EnableRefund {
+            caller: self.buyer,
+            launch: self.launch,
+        };
+        self.run(accounts, astra::instruction::EnableRefund {})
+    }
+
+    fn claim_refund(&mut self, user: Pubkey) -> std::result::Result<(), ProgramError> {
+        let accounts = astra::accounts::ClaimRefund {
+            user,
+            config: self.config,
+            launch: self.launch,
+            position: self.position_key(user),
+        };
+        self.run(accounts, astra::instruction::ClaimRefund {})
+    }
+
+    fn sweep_fee_escrow(&mut self) -> std::result::Result<(), ProgramError> {
+        let accounts = astra::accounts::SweepFeeEscrow {
+            caller: self.buyer,
+            config: self.config,
+            launch: self.launch,
+            fee_escrow: self.fee_escrow(),
+            creator: self.creator,
+        };
+        self.run(accounts, astra::instruction::SweepFeeEscrow {})
+    }
+
+    fn close_launch(&mut self) -> std::result::Result<(), ProgramError> {
+        let accounts = astra::accounts::CloseLaunch {
+            caller: self.buyer,
+            config: self.config,
+            rent_recipient: self.buyer,
+            protocol_fee_wallet: self.fee_wallet,
+            launch: self.launch,
+            fee_escrow: self.fee_escrow(),
+        };
+        self.run(accounts, astra::instruction::CloseLaunch {})
+    }
+
+    fn backfill_holder_count(
+        &mut self,
+        holder_count: u64,
+    ) -> std::result::Result<(), ProgramError> {
+        let accounts = astra::accounts::BackfillHolderCount {
+            authority: self.authority,
+            config: self.config,
+            launch: self.launch,
+        };
+        self.run(
+            accounts,
+            astra::instruction::BackfillHolderCount { holder_count },
+        )
+    }
+
+    fn fee_escrow(&self) -> Pubkey {
+        pda(&[b"fee_escrow", self.launch.as_ref()]).0
+    }
+}
+
+fn key() -> Pubkey {
+    Pubkey::new_unique()
+}
+
+fn pda(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &astra::ID)
+}
+
+fn assert_err(result: std::result::Result<(), ProgramError>, expected: AstraError) {
+    assert_eq!(
+        result.unwrap_err(),
+        anchor_lang::error::Error::from(expected).into()
+    );
+}
+
+#[test]
+fn test_active_trades_and_rejects_exit_moves() {
+    let mut harness = Harness::active();
+    let before = harness.launch();
+
+    harness.sell(before.total_shares / 10).unwrap();
+    assert!(harness.launch().total_sol < before.total_sol);
+
+    // Refund-only moves wait for refund mode
+    assert_err(
+        harness.claim_refund(harness.buyer),
+        AstraError::RefundModeNotActive,
+    );
+    assert_err(harness.close_launch(), AstraError::RefundModeNotActive);
+    assert_err(harness.sweep_fee_escrow(), AstraError::RefundModeNotActive);
+
+    // Holder count was tracked from creation
+    assert_err(
+        harness.backfill_holder_count(10),
+        AstraError::HolderCountAlreadyBackfilled,
+    );
+}
+
+#[test]
+fn test_graduation_lock_freezes_the_pool() {
+    let mut harness = Harness::locked();
+    let locked = harness.launch();
+    assert_eq!(locked.graduation_locked_at, Some(NOW));
+
+    assert_err(harness.buy(LAMPORTS_PER_SOL), AstraError::GraduationLocked);
+    assert_err(harness.sell(1), AstraError::GraduationLocked);
+    assert_err(
+        harness.boost(LAMPORTS_PER_SOL),
+        AstraError::GraduationLocked,
+    );
+    assert_err(harness.lock_graduation(), AstraError::GraduationLocked);
+
+    // Nothing moved the snapshot graduate will pool
+    let launch = harness.launch();
+    assert_eq!(launch.total_sol, locked.graduation_sol_snapshot);
+    assert_eq!(launch.total_shares, locked.graduation_shares_snapshot);
+}
+
+#[test]
+fn test_graduated_is_terminal_for_the_curve() {
+    let mut harness = Harness::graduated();
+
+    assert_err(harness.buy(LAMPORTS_PER_SOL), AstraError::AlreadyGraduated);
+    assert_err(harness.sell(1), AstraError::AlreadyGraduated);
+    assert_err(
+        harness.boost(LAMPORTS_PER_SOL),
+        AstraError::AlreadyGraduated,
+    );
+    assert_err(harness.lock_graduation(), AstraError::AlreadyGraduated);
+    assert_err(harness.enable_refund(), AstraError::AlreadyGraduated);
+    assert_err(
+        harness.claim_refund(harness.buyer),
+        AstraError::RefundModeNotActive,
+    );
+    assert_err(harness.close_launch(), AstraError::RefundModeNotActive);
+}
+
+#[test]
+fn test_refunding_blocks_trading_and_graduation() {
+    let mut harness = Harness::refunding();
+    assert!(harness.launch().refund_mode);
+
+    assert_err(
+        harness.buy(LAMPORTS_PER_SOL),
+        AstraError::LaunchInRefundMode,
+    );
+    assert_err(harness.sell(1), AstraError::RefundModeActive);
+    assert_err(
+        harness.boost(LAMPORTS_PER_SOL),
+        AstraError::RefundModeActive,
+    );
+    assert_err(harness.lock_graduation(), AstraError::LaunchInRefundMode);
+    assert_err(harness.enable_refund(), AstraError::RefundModeAlreadyActive);
+    assert!(harness
+        .launch()
+        .mark_graduated(NOW, key(), key(), key())
+        .is_err());
+}
+
+#[test]
+fn test_refunding_drains_then_closes() {
+    let mut harness = Harness::refunding();
+    harness.sweep_fee_escrow().unwrap();
+
+    // close_launch waits for every position, creator seed included
+    harness.claim_refund(harness.buyer).unwrap();
+    assert!(harness.position(harness.buyer).has_claimed_refund);
+    assert_err(
+        harness.claim_refund(harness.buyer),
+        AstraError::AlreadyClaimed,
+    );
+    assert_err(harness.close_launch(), AstraError::LaunchNotEmpty);
+
+    harness.claim_refund(harness.creator).unwrap();
+    assert!(harness.launch().can_close());
+    harness.close_launch().unwrap();
+
+    // Closed: rent back to the janitor, nothing left to deserialize
+    let closed = harness.account(harness.launch);
+    assert_eq!(closed.lamports, 0);
+    assert_eq!(closed.owner, system_program::ID);
+    assert!(closed.data().is_empty());
+}
+
+#[test]
+fn test_trading_resumes_after_unpause() {
+    let mut harness = Harness::active();
+    let shares = harness.position(harness.buyer).shares;
+
+    for cycle in 0..3 {
+        let before = harness.launch();
+        harness.set_config(|config| {
+            config.set_paused(true, PauseReason::Maintenance, key(), NOW + cycle);
+        });
+        assert_err(harness.buy(LAMPORTS_PER_SOL), AstraError::ProtocolPaused);
+        assert_err(harness.sell(1), AstraError::ProtocolPaused);
+        assert_err(harness.boost(LAMPORTS_PER_SOL), AstraError::ProtocolPaused);
+        assert_eq!(harness.launch().total_shares, before.total_shares);
+
+        harness.set_config(|config| {
+            config.set_paused(false, PauseReason::Resolved, key(), NOW + cycle);
+        });
+        harness.sell(shares / 4).unwrap();
+        assert_eq!(
+            harness.launch().total_shares,
+            before.total_shares - shares / 4
+        );
+    }
+
+    // The whole position exits once trading is back
+    let shares = harness.position(harness.buyer).shares;
+    harness.sell(shares).unwrap();
+    assert_eq!(harness.position(harness.buyer).shares, 0);
+}